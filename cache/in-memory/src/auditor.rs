@@ -0,0 +1,746 @@
+//! Audit the cached state of a guild against the HTTP API.
+//!
+//! Missed events, such as those lost to a gap in a resumed session, can leave
+//! the cache out of date without any indication. The auditor retrieves a
+//! guild, its channels, its roles, and optionally its members via
+//! [`twilight_http`] and compares them to the cached state, producing an
+//! [`AuditReport`] of the differences. The cache can optionally be repaired to
+//! match the retrieved state.
+//!
+//! Threads aren't returned by the guild channels endpoint, so cached threads
+//! are never reported as stale.
+//!
+//! Only resources that the cache is configured to cache in the guild, via its
+//! [resource types] and [guild filter], are audited and repaired.
+//!
+//! # Examples
+//!
+//! Audit a guild and repair the cache if it drifted:
+//!
+//! ```no_run
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use std::env;
+//! use twilight_cache_inmemory::InMemoryCache;
+//! use twilight_http::Client;
+//! use twilight_model::id::GuildId;
+//!
+//! let cache = InMemoryCache::new();
+//! let http = Client::new(env::var("DISCORD_TOKEN")?);
+//!
+//! let guild_id = GuildId::new(1).expect("non zero");
+//! let report = cache.auditor(&http).repair(true).audit(guild_id).await?;
+//!
+//! if !report.is_consistent() {
+//!     println!("stale roles: {:?}", report.roles().stale());
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! [guild filter]: crate::InMemoryCacheBuilder::guild_filter
+//! [resource types]: crate::InMemoryCacheBuilder::resource_types
+
+use super::{config::ResourceType, event::guild::update_guild, InMemoryCache};
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::Hash,
+};
+use twilight_http::Client;
+use twilight_model::{
+    channel::GuildChannel,
+    guild::{Guild, Member, PartialGuild, Role},
+    id::{ChannelId, GuildId, RoleId, UserId},
+};
+
+/// Maximum number of members retrieved per request.
+const MEMBERS_PER_REQUEST: u64 = 1000;
+
+/// Auditing a guild failed.
+#[derive(Debug)]
+pub struct AuditError {
+    kind: AuditErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl AuditError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &AuditErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (AuditErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    fn deserializing(source: impl Error + Send + Sync + 'static) -> Self {
+        Self {
+            kind: AuditErrorType::Deserializing,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    fn requesting(source: impl Error + Send + Sync + 'static) -> Self {
+        Self {
+            kind: AuditErrorType::Requesting,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for AuditError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            AuditErrorType::Deserializing => {
+                f.write_str("failed to deserialize the retrieved resources")
+            }
+            AuditErrorType::Requesting => f.write_str("failed to retrieve the resources"),
+        }
+    }
+}
+
+impl Error for AuditError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`AuditError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AuditErrorType {
+    /// Deserializing the retrieved resources failed.
+    Deserializing,
+    /// Requesting the resources failed.
+    Requesting,
+}
+
+/// Differences between cached and retrieved resources of one type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResourceDrift<T> {
+    missing: Vec<T>,
+    outdated: Vec<T>,
+    stale: Vec<T>,
+}
+
+impl<T> ResourceDrift<T> {
+    const fn new() -> Self {
+        Self {
+            missing: Vec::new(),
+            outdated: Vec::new(),
+            stale: Vec::new(),
+        }
+    }
+
+    /// IDs of resources that are missing or outdated, and so need to be
+    /// cached.
+    fn changed(&self) -> HashSet<T>
+    where
+        T: Copy + Eq + Hash,
+    {
+        self.missing.iter().chain(&self.outdated).copied().collect()
+    }
+
+    /// Whether the cached resources match the retrieved resources.
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.outdated.is_empty() && self.stale.is_empty()
+    }
+
+    /// IDs of resources that exist but aren't cached.
+    pub fn missing(&self) -> &[T] {
+        &self.missing
+    }
+
+    /// IDs of resources that are cached but differ from the retrieved
+    /// resources.
+    pub fn outdated(&self) -> &[T] {
+        &self.outdated
+    }
+
+    /// IDs of resources that are cached but no longer exist.
+    pub fn stale(&self) -> &[T] {
+        &self.stale
+    }
+}
+
+/// Differences between the cached state of a guild and its retrieved state.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditReport {
+    channels: ResourceDrift<ChannelId>,
+    guild: bool,
+    guild_id: GuildId,
+    members: Option<ResourceDrift<UserId>>,
+    repaired: bool,
+    roles: ResourceDrift<RoleId>,
+}
+
+impl AuditReport {
+    /// Differences between the cached and retrieved channels of the guild.
+    pub const fn channels(&self) -> &ResourceDrift<ChannelId> {
+        &self.channels
+    }
+
+    /// Whether the guild itself is missing from the cache or differs from the
+    /// retrieved guild.
+    pub const fn guild_drifted(&self) -> bool {
+        self.guild
+    }
+
+    /// ID of the audited guild.
+    pub const fn guild_id(&self) -> GuildId {
+        self.guild_id
+    }
+
+    /// Whether the cached state of the guild matches its retrieved state.
+    pub fn is_consistent(&self) -> bool {
+        !self.guild
+            && self.channels.is_empty()
+            && self.roles.is_empty()
+            && self.members.as_ref().map_or(true, ResourceDrift::is_empty)
+    }
+
+    /// Differences between the cached and retrieved members of the guild.
+    ///
+    /// This is `None` if members weren't audited.
+    pub const fn members(&self) -> Option<&ResourceDrift<UserId>> {
+        self.members.as_ref()
+    }
+
+    /// Whether the cache was repaired to match the retrieved state.
+    pub const fn repaired(&self) -> bool {
+        self.repaired
+    }
+
+    /// Differences between the cached and retrieved roles of the guild.
+    pub const fn roles(&self) -> &ResourceDrift<RoleId> {
+        &self.roles
+    }
+}
+
+/// Audit the cached state of guilds against the HTTP API.
+///
+/// Refer to the [module-level] documentation for more information.
+///
+/// [module-level]: crate::auditor
+#[derive(Debug)]
+#[must_use = "an auditor does nothing unless a guild is audited"]
+pub struct InMemoryCacheAuditor<'a> {
+    cache: &'a InMemoryCache,
+    http: &'a Client,
+    members: bool,
+    repair: bool,
+}
+
+impl<'a> InMemoryCacheAuditor<'a> {
+    /// Create a new auditor with a cache and an HTTP client.
+    ///
+    /// Members aren't audited and the cache isn't repaired by default.
+    pub const fn new(cache: &'a InMemoryCache, http: &'a Client) -> Self {
+        Self {
+            cache,
+            http,
+            members: false,
+            repair: false,
+        }
+    }
+
+    /// Immutable reference to the underlying cache.
+    pub const fn cache_ref(&'a self) -> &'a InMemoryCache {
+        self.cache
+    }
+
+    /// Consume the auditor, returning the underlying cache reference.
+    #[must_use = "consuming the auditor and retrieving the cache has no effect if left unused"]
+    pub const fn into_cache(self) -> &'a InMemoryCache {
+        self.cache
+    }
+
+    /// Set whether to audit the members of guilds.
+    ///
+    /// Retrieving members requires the [`GUILD_MEMBERS`] intent and takes one
+    /// request per 1000 members.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`GUILD_MEMBERS`]: twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub const fn members(mut self, members: bool) -> Self {
+        self.members = members;
+
+        self
+    }
+
+    /// Set whether to repair the cache to match the retrieved state.
+    ///
+    /// Defaults to `false`.
+    pub const fn repair(mut self, repair: bool) -> Self {
+        self.repair = repair;
+
+        self
+    }
+
+    /// Audit the cached state of a guild.
+    ///
+    /// The report describes the cache as it was before any repair.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`AuditErrorType::Requesting`] if retrieving a
+    /// resource failed.
+    ///
+    /// Returns an error of type [`AuditErrorType::Deserializing`] if a
+    /// retrieved resource couldn't be deserialized.
+    pub async fn audit(&self, guild_id: GuildId) -> Result<AuditReport, AuditError> {
+        let guild = self
+            .http
+            .guild(guild_id)
+            .exec()
+            .await
+            .map_err(AuditError::requesting)?
+            .model()
+            .await
+            .map_err(AuditError::deserializing)?;
+
+        let channels = self
+            .http
+            .guild_channels(guild_id)
+            .exec()
+            .await
+            .map_err(AuditError::requesting)?
+            .models()
+            .await
+            .map_err(AuditError::deserializing)?;
+
+        let roles = self
+            .http
+            .roles(guild_id)
+            .exec()
+            .await
+            .map_err(AuditError::requesting)?
+            .models()
+            .await
+            .map_err(AuditError::deserializing)?;
+
+        let members = if self.members {
+            Some(self.fetch_members(guild_id).await?)
+        } else {
+            None
+        };
+
+        let report = AuditReport {
+            channels: self.diff_channels(guild_id, &channels),
+            guild: self.guild_drifted(&guild),
+            guild_id,
+            members: members
+                .as_ref()
+                .map(|members| self.diff_members(guild_id, members)),
+            repaired: self.repair,
+            roles: self.diff_roles(guild_id, &roles),
+        };
+
+        if self.repair {
+            self.repair_guild(guild, &report);
+            self.repair_channels(guild_id, channels, &report.channels);
+            self.repair_roles(guild_id, roles, &report.roles);
+
+            if let (Some(members), Some(drift)) = (members, &report.members) {
+                self.repair_members(guild_id, members, drift);
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Retrieve all members of a guild, page by page.
+    async fn fetch_members(&self, guild_id: GuildId) -> Result<Vec<Member>, AuditError> {
+        let mut members = Vec::new();
+        let mut after = None;
+
+        loop {
+            let mut request = self
+                .http
+                .guild_members(guild_id)
+                .limit(MEMBERS_PER_REQUEST)
+                .map_err(AuditError::requesting)?;
+
+            if let Some(after) = after {
+                request = request.after(after);
+            }
+
+            let page = request
+                .exec()
+                .await
+                .map_err(AuditError::requesting)?
+                .model()
+                .await
+                .map_err(AuditError::deserializing)?;
+
+            let exhausted = (page.len() as u64) < MEMBERS_PER_REQUEST;
+            after = page.last().map(|member| member.user.id);
+            members.extend(page);
+
+            if exhausted || after.is_none() {
+                return Ok(members);
+            }
+        }
+    }
+
+    fn guild_drifted(&self, guild: &Guild) -> bool {
+        if !self.cache.wants_in(Some(guild.id), ResourceType::GUILD) {
+            return false;
+        }
+
+        let cached = match self.cache.guilds.get(&guild.id) {
+            Some(cached) => cached,
+            None => return true,
+        };
+
+        let mut expected = cached.clone();
+        update_guild(&mut expected, &partial_guild(guild));
+
+        expected != *cached
+    }
+
+    fn diff_channels(
+        &self,
+        guild_id: GuildId,
+        channels: &[GuildChannel],
+    ) -> ResourceDrift<ChannelId> {
+        if !self.cache.wants_in(Some(guild_id), ResourceType::CHANNEL) {
+            return ResourceDrift::new();
+        }
+
+        let cached = self.cached_ids(&self.cache.guild_channels, guild_id, |id| {
+            // Threads aren't returned by the guild channels endpoint.
+            self.cache.channels_guild.get(id).map_or(false, |channel| {
+                crate::event::thread::thread_parts(channel.resource()).is_none()
+            })
+        });
+
+        diff(
+            cached,
+            channels.iter().map(|channel| (channel.id(), channel)),
+            |id, channel| {
+                self.cache
+                    .channels_guild
+                    .get(&id)
+                    .map_or(false, |cached| cached.resource() == channel)
+            },
+        )
+    }
+
+    fn diff_members(&self, guild_id: GuildId, members: &[Member]) -> ResourceDrift<UserId> {
+        if !self.cache.wants_in(Some(guild_id), ResourceType::MEMBER) {
+            return ResourceDrift::new();
+        }
+
+        let cached = self.cached_ids(&self.cache.guild_members, guild_id, |_| true);
+
+        diff(
+            cached,
+            members.iter().map(|member| (member.user.id, member)),
+            |id, member| {
+                self.cache
+                    .members
+                    .get(&(guild_id, id))
+                    .map_or(false, |cached| *cached == *member)
+            },
+        )
+    }
+
+    fn diff_roles(&self, guild_id: GuildId, roles: &[Role]) -> ResourceDrift<RoleId> {
+        if !self.cache.wants_in(Some(guild_id), ResourceType::ROLE) {
+            return ResourceDrift::new();
+        }
+
+        let cached = self.cached_ids(&self.cache.guild_roles, guild_id, |_| true);
+
+        diff(
+            cached,
+            roles.iter().map(|role| (role.id, role)),
+            |id, role| {
+                self.cache
+                    .roles
+                    .get(&id)
+                    .map_or(false, |cached| cached.resource() == role)
+            },
+        )
+    }
+
+    fn cached_ids<T: Copy + Eq + Hash>(
+        &self,
+        map: &dashmap::DashMap<GuildId, HashSet<T>>,
+        guild_id: GuildId,
+        filter: impl Fn(&T) -> bool,
+    ) -> HashSet<T> {
+        map.get(&guild_id)
+            .map(|ids| ids.iter().copied().filter(|id| filter(id)).collect())
+            .unwrap_or_default()
+    }
+
+    fn repair_guild(&self, guild: Guild, report: &AuditReport) {
+        if !report.guild || !self.cache.wants_in(Some(guild.id), ResourceType::GUILD) {
+            return;
+        }
+
+        if let Some(mut cached) = self.cache.guilds.get_mut(&guild.id) {
+            update_guild(&mut cached, &partial_guild(&guild));
+
+            return;
+        }
+
+        // The guild's channels, roles, and members are repaired afterwards,
+        // so only the guild itself is cached here. Its resources that are
+        // still cached, such as while it was unavailable, are kept.
+        self.cache.cache_guild_only(guild);
+    }
+
+    fn repair_channels(
+        &self,
+        guild_id: GuildId,
+        channels: Vec<GuildChannel>,
+        drift: &ResourceDrift<ChannelId>,
+    ) {
+        if !self.cache.wants_in(Some(guild_id), ResourceType::CHANNEL) {
+            return;
+        }
+
+        for channel_id in &drift.stale {
+            self.cache.delete_guild_channel(*channel_id);
+        }
+
+        let changed = drift.changed();
+
+        for channel in channels {
+            if changed.contains(&channel.id()) {
+                self.cache.cache_guild_channel(guild_id, channel);
+            }
+        }
+    }
+
+    fn repair_members(
+        &self,
+        guild_id: GuildId,
+        members: Vec<Member>,
+        drift: &ResourceDrift<UserId>,
+    ) {
+        if !self.cache.wants_in(Some(guild_id), ResourceType::MEMBER) {
+            return;
+        }
+
+        for user_id in &drift.stale {
+            self.cache.delete_member(guild_id, *user_id);
+        }
+
+        let changed = drift.changed();
+
+        for member in members {
+            if changed.contains(&member.user.id) {
+                self.cache.cache_member(guild_id, member);
+            }
+        }
+    }
+
+    fn repair_roles(&self, guild_id: GuildId, roles: Vec<Role>, drift: &ResourceDrift<RoleId>) {
+        if !self.cache.wants_in(Some(guild_id), ResourceType::ROLE) {
+            return;
+        }
+
+        for role_id in &drift.stale {
+            self.cache.delete_role(*role_id);
+        }
+
+        let changed = drift.changed();
+
+        self.cache.cache_roles(
+            guild_id,
+            roles.into_iter().filter(|role| changed.contains(&role.id)),
+        );
+    }
+}
+
+/// Compare the IDs of cached resources to retrieved resources.
+///
+/// `is_current` determines whether the cached resource with an ID matches the
+/// retrieved resource.
+fn diff<T: Copy + Eq + Hash + Ord, V>(
+    mut cached: HashSet<T>,
+    retrieved: impl IntoIterator<Item = (T, V)>,
+    is_current: impl Fn(T, V) -> bool,
+) -> ResourceDrift<T> {
+    let mut drift = ResourceDrift::new();
+
+    for (id, resource) in retrieved {
+        if !cached.remove(&id) {
+            drift.missing.push(id);
+        } else if !is_current(id, resource) {
+            drift.outdated.push(id);
+        }
+    }
+
+    drift.stale.extend(cached);
+    drift.missing.sort_unstable();
+    drift.outdated.sort_unstable();
+    drift.stale.sort_unstable();
+
+    drift
+}
+
+/// Convert a retrieved guild into the partial guild that guild updates are
+/// applied from.
+fn partial_guild(guild: &Guild) -> PartialGuild {
+    PartialGuild {
+        id: guild.id,
+        afk_channel_id: guild.afk_channel_id,
+        afk_timeout: guild.afk_timeout,
+        application_id: guild.application_id,
+        banner: guild.banner.clone(),
+        default_message_notifications: guild.default_message_notifications,
+        description: guild.description.clone(),
+        discovery_splash: guild.discovery_splash.clone(),
+        emojis: Vec::new(),
+        explicit_content_filter: guild.explicit_content_filter,
+        features: guild.features.clone(),
+        icon: guild.icon.clone(),
+        max_members: guild.max_members,
+        max_presences: guild.max_presences,
+        member_count: guild.member_count,
+        mfa_level: guild.mfa_level,
+        name: guild.name.clone(),
+        nsfw_level: guild.nsfw_level,
+        owner_id: guild.owner_id,
+        owner: guild.owner,
+        permissions: guild.permissions,
+        preferred_locale: guild.preferred_locale.clone(),
+        premium_subscription_count: guild.premium_subscription_count,
+        premium_tier: guild.premium_tier,
+        roles: Vec::new(),
+        rules_channel_id: guild.rules_channel_id,
+        splash: guild.splash.clone(),
+        system_channel_flags: guild.system_channel_flags,
+        system_channel_id: guild.system_channel_id,
+        verification_level: guild.verification_level,
+        vanity_url_code: guild.vanity_url_code.clone(),
+        widget_channel_id: guild.widget_channel_id,
+        widget_enabled: guild.widget_enabled,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuditError, AuditErrorType, AuditReport, InMemoryCacheAuditor, ResourceDrift};
+    use crate::{test, InMemoryCache, ResourceType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_http::Client;
+    use twilight_model::{
+        gateway::payload::incoming::{GuildCreate, UnavailableGuild},
+        id::{GuildId, RoleId, UserId},
+    };
+
+    assert_impl_all!(AuditError: Error, Send, Sync);
+    assert_impl_all!(AuditErrorType: Debug, Send, Sync);
+    assert_impl_all!(AuditReport: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(InMemoryCacheAuditor<'_>: Debug, Send, Sync);
+    assert_impl_all!(ResourceDrift<RoleId>: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_roles_drift_and_repair() {
+        let guild_id = GuildId::new(1).expect("non zero");
+        let cache = InMemoryCache::new();
+        let http = Client::new(String::new());
+        let auditor = cache.auditor(&http);
+
+        let stale = test::role(RoleId::new(2).expect("non zero"));
+        let outdated = test::role(RoleId::new(3).expect("non zero"));
+        let current = test::role(RoleId::new(4).expect("non zero"));
+        cache.cache_roles(
+            guild_id,
+            vec![stale.clone(), outdated.clone(), current.clone()],
+        );
+
+        let mut renamed = outdated;
+        renamed.name = "renamed".to_owned();
+        let missing = test::role(RoleId::new(5).expect("non zero"));
+        let roles = vec![renamed.clone(), current, missing.clone()];
+
+        let drift = auditor.diff_roles(guild_id, &roles);
+        assert_eq!(&[missing.id], drift.missing());
+        assert_eq!(&[renamed.id], drift.outdated());
+        assert_eq!(&[stale.id], drift.stale());
+
+        auditor.repair_roles(guild_id, roles.clone(), &drift);
+        assert!(auditor.diff_roles(guild_id, &roles).is_empty());
+        assert!(cache.role(stale.id).is_none());
+        assert_eq!("renamed", cache.role(renamed.id).unwrap().name);
+    }
+
+    #[test]
+    fn test_filtered_roles() {
+        let filtered = GuildId::new(1).expect("non zero");
+        let cache = InMemoryCache::builder()
+            .guild_filter(move |guild_id, resource_type| {
+                guild_id != filtered || resource_type != ResourceType::ROLE
+            })
+            .build();
+        let http = Client::new(String::new());
+        let auditor = cache.auditor(&http);
+
+        let roles = vec![test::role(RoleId::new(2).expect("non zero"))];
+
+        // Roles of the guild aren't cached, so they aren't missing.
+        let drift = auditor.diff_roles(filtered, &roles);
+        assert!(drift.is_empty());
+
+        let drift = ResourceDrift {
+            missing: vec![roles[0].id],
+            outdated: Vec::new(),
+            stale: Vec::new(),
+        };
+        auditor.repair_roles(filtered, roles, &drift);
+        assert!(cache.role(drift.missing[0]).is_none());
+    }
+
+    #[test]
+    fn test_repair_unavailable_guild() {
+        let guild_id = GuildId::new(1).expect("non zero");
+        let role_id = RoleId::new(2).expect("non zero");
+        let user_id = UserId::new(3).expect("non zero");
+        let cache = InMemoryCache::new();
+        let http = Client::new(String::new());
+        let auditor = cache.auditor(&http);
+
+        let mut guild = test::guild(guild_id);
+        guild.members = vec![test::member(user_id, guild_id)];
+        guild.roles = vec![test::role(role_id)];
+        cache.update(&GuildCreate(guild.clone()));
+        cache.update(&UnavailableGuild { id: guild_id });
+        assert!(cache.guild(guild_id).is_none());
+
+        let report = AuditReport {
+            channels: ResourceDrift::new(),
+            guild: true,
+            guild_id,
+            members: None,
+            repaired: true,
+            roles: ResourceDrift::new(),
+        };
+        auditor.repair_guild(test::guild(guild_id), &report);
+
+        assert!(cache.guild(guild_id).is_some());
+        assert_eq!(0, cache.stats().unavailable_guilds());
+        assert!(cache.guild_members(guild_id).unwrap().contains(&user_id));
+        assert!(cache.guild_roles(guild_id).unwrap().contains(&role_id));
+        assert!(auditor.diff_roles(guild_id, &guild.roles).is_empty());
+    }
+}
@@ -1,21 +1,73 @@
 use super::{
-    config::{Config, ResourceType},
+    config::{Config, GuildFilter, ResourceType},
     InMemoryCache,
 };
+use std::time::Duration;
+use twilight_model::id::GuildId;
 
 /// Builder to configure and construct an [`InMemoryCache`].
 #[derive(Debug, Default)]
-pub struct InMemoryCacheBuilder(Config);
+pub struct InMemoryCacheBuilder(Config, GuildFilter);
 
 impl InMemoryCacheBuilder {
     /// Creates a builder to configure and construct an [`InMemoryCache`].
     pub const fn new() -> Self {
-        Self(Config::new())
+        Self(Config::new(), GuildFilter::new())
     }
 
     /// Consume the builder, returning a configured cache.
     pub fn build(self) -> InMemoryCache {
-        InMemoryCache::new_with_config(self.0)
+        InMemoryCache::new_with_config(self.0, self.1)
+    }
+
+    /// Sets a predicate deciding which resource types to cache in each guild.
+    ///
+    /// The predicate is consulted in addition to the configured
+    /// [resource types] whenever an event would cache a guild's resources, so
+    /// that, for example, only some guilds have their members and messages
+    /// cached. Resources are still removed from the cache regardless of the
+    /// predicate.
+    ///
+    /// If the predicate depends on state that changes, such as a list of
+    /// guilds, call [`InMemoryCache::refilter_guild`] after the state changes
+    /// to remove the resources the predicate now rejects. Resources the
+    /// predicate now accepts are cached as events for them are received.
+    ///
+    /// Defaults to caching all resource types in all guilds.
+    ///
+    /// # Examples
+    ///
+    /// Only cache members and messages of some guilds:
+    ///
+    /// ```
+    /// use std::{collections::HashSet, sync::{Arc, RwLock}};
+    /// use twilight_cache_inmemory::{InMemoryCache, ResourceType};
+    /// use twilight_model::id::GuildId;
+    ///
+    /// let premium = Arc::new(RwLock::new(HashSet::<GuildId>::new()));
+    /// let premium_ref = Arc::clone(&premium);
+    ///
+    /// let cache = InMemoryCache::builder()
+    ///     .guild_filter(move |guild_id, resource_type| {
+    ///         !resource_type.intersects(ResourceType::MEMBER | ResourceType::MESSAGE)
+    ///             || premium_ref.read().unwrap().contains(&guild_id)
+    ///     })
+    ///     .build();
+    ///
+    /// // A guild is no longer premium.
+    /// let guild_id = GuildId::new(1).expect("non zero");
+    /// premium.write().unwrap().remove(&guild_id);
+    /// cache.refilter_guild(guild_id);
+    /// ```
+    ///
+    /// [resource types]: Self::resource_types
+    pub fn guild_filter(
+        mut self,
+        predicate: impl Fn(GuildId, ResourceType) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.1 = GuildFilter::from_predicate(predicate);
+
+        self
     }
 
     /// Sets the list of resource types for the cache to handle.
@@ -27,6 +79,35 @@ impl InMemoryCacheBuilder {
         self
     }
 
+    /// Sets the maximum number of tombstones of deleted messages to keep.
+    ///
+    /// Once the limit is reached the oldest tombstone is dropped when another
+    /// message is deleted.
+    ///
+    /// Defaults to 1000.
+    pub const fn deleted_message_limit(mut self, deleted_message_limit: usize) -> Self {
+        self.0.deleted_message_limit = deleted_message_limit;
+
+        self
+    }
+
+    /// Sets how long to keep tombstones of deleted messages.
+    ///
+    /// Tombstones record the last known state and revisions of messages
+    /// deleted individually or in bulk, and are retrieved via
+    /// [`InMemoryCache::deleted_message`]. Expired tombstones are removed when
+    /// they're looked up or when messages are created, updated, or deleted.
+    /// At most [`deleted_message_limit`] tombstones are kept.
+    ///
+    /// Defaults to `None`, not keeping tombstones.
+    ///
+    /// [`deleted_message_limit`]: Self::deleted_message_limit
+    pub const fn deleted_message_retention(mut self, retention: Option<Duration>) -> Self {
+        self.0.deleted_message_retention = retention;
+
+        self
+    }
+
     /// Sets the number of messages to cache per channel.
     ///
     /// Defaults to 100.
@@ -35,6 +116,37 @@ impl InMemoryCacheBuilder {
 
         self
     }
+
+    /// Sets the number of previous revisions to keep per cached message.
+    ///
+    /// When a cached message is edited its previous content, embeds, and
+    /// attachments are kept as a revision, retrieved via
+    /// [`InMemoryCache::message_revisions`]. Once the limit is reached the
+    /// oldest revision is dropped.
+    ///
+    /// Defaults to 0, not keeping revisions.
+    pub const fn message_revision_limit(mut self, message_revision_limit: usize) -> Self {
+        self.0.message_revision_limit = message_revision_limit;
+
+        self
+    }
+
+    /// Sets whether to maintain secondary indexes over members and private
+    /// channels.
+    ///
+    /// The indexes map roles to the members that have them, lowercased
+    /// usernames and nicknames to members, and users to their private
+    /// channels. They back [`InMemoryCache::role_members`],
+    /// [`InMemoryCache::members_by_name_prefix`], and
+    /// [`InMemoryCache::user_private_channel`] at the cost of extra memory
+    /// and work when members change.
+    ///
+    /// Defaults to false.
+    pub const fn secondary_indexes(mut self, secondary_indexes: bool) -> Self {
+        self.0.secondary_indexes = secondary_indexes;
+
+        self
+    }
 }
 
 #[cfg(test)]
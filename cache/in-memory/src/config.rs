@@ -1,4 +1,10 @@
 use bitflags::bitflags;
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::Arc,
+    time::Duration,
+};
+use twilight_model::id::GuildId;
 
 bitflags! {
     /// A set of bitflags which can be used to specify what resource to process
@@ -36,6 +42,45 @@ bitflags! {
         const INTEGRATION = 1 << 12;
         /// Information relating to guild stickers.
         const STICKER = 1 << 13;
+        /// Information relating to thread members.
+        const THREAD_MEMBER = 1 << 14;
+        /// Information relating to invites.
+        const INVITE = 1 << 15;
+    }
+}
+
+/// Predicate deciding whether to cache a resource type in a guild.
+///
+/// Guilds are cached in full if no predicate is set.
+#[derive(Clone, Default)]
+pub(crate) struct GuildFilter(Option<Arc<dyn Fn(GuildId, ResourceType) -> bool + Send + Sync>>);
+
+impl GuildFilter {
+    /// Create a new filter allowing all resource types in all guilds.
+    pub(crate) const fn new() -> Self {
+        Self(None)
+    }
+
+    /// Create a new filter from a predicate.
+    pub(crate) fn from_predicate(
+        predicate: impl Fn(GuildId, ResourceType) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self(Some(Arc::new(predicate)))
+    }
+
+    /// Whether a resource type may be cached in a guild.
+    pub(crate) fn allows(&self, guild_id: GuildId, resource_type: ResourceType) -> bool {
+        self.0
+            .as_ref()
+            .map_or(true, |predicate| predicate(guild_id, resource_type))
+    }
+}
+
+impl Debug for GuildFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("GuildFilter")
+            .field(&self.0.as_ref().map(|_| "<predicate>"))
+            .finish()
     }
 }
 
@@ -45,7 +90,11 @@ bitflags! {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Config {
     pub(super) resource_types: ResourceType,
+    pub(super) deleted_message_limit: usize,
+    pub(super) deleted_message_retention: Option<Duration>,
     pub(super) message_cache_size: usize,
+    pub(super) message_revision_limit: usize,
+    pub(super) secondary_indexes: bool,
 }
 
 impl Config {
@@ -55,10 +104,40 @@ impl Config {
     pub const fn new() -> Self {
         Self {
             resource_types: ResourceType::all(),
+            deleted_message_limit: 1000,
+            deleted_message_retention: None,
             message_cache_size: 100,
+            message_revision_limit: 0,
+            secondary_indexes: false,
         }
     }
 
+    /// Returns the maximum number of tombstones of deleted messages kept.
+    ///
+    /// Defaults to 1000.
+    pub const fn deleted_message_limit(&self) -> usize {
+        self.deleted_message_limit
+    }
+
+    /// Returns a mutable reference to the maximum number of tombstones of
+    /// deleted messages kept.
+    pub fn deleted_message_limit_mut(&mut self) -> &mut usize {
+        &mut self.deleted_message_limit
+    }
+
+    /// Returns how long tombstones of deleted messages are kept.
+    ///
+    /// Defaults to `None`, not keeping tombstones.
+    pub const fn deleted_message_retention(&self) -> Option<Duration> {
+        self.deleted_message_retention
+    }
+
+    /// Returns a mutable reference to how long tombstones of deleted messages
+    /// are kept.
+    pub fn deleted_message_retention_mut(&mut self) -> &mut Option<Duration> {
+        &mut self.deleted_message_retention
+    }
+
     /// Returns an immutable reference to the message cache size.
     ///
     /// Defaults to 100.
@@ -70,6 +149,20 @@ impl Config {
     pub fn message_cache_size_mut(&mut self) -> &mut usize {
         &mut self.message_cache_size
     }
+
+    /// Returns the number of previous revisions kept per cached message.
+    ///
+    /// Defaults to 0, not keeping revisions.
+    pub const fn message_revision_limit(&self) -> usize {
+        self.message_revision_limit
+    }
+
+    /// Returns a mutable reference to the number of previous revisions kept
+    /// per cached message.
+    pub fn message_revision_limit_mut(&mut self) -> &mut usize {
+        &mut self.message_revision_limit
+    }
+
     /// Returns an immutable reference to the resource types enabled.
     ///
     /// Defaults to all resource types.
@@ -81,6 +174,20 @@ impl Config {
     pub fn resource_types_mut(&mut self) -> &mut ResourceType {
         &mut self.resource_types
     }
+
+    /// Returns whether secondary indexes over members and private channels
+    /// are maintained.
+    ///
+    /// Defaults to false.
+    pub const fn secondary_indexes(&self) -> bool {
+        self.secondary_indexes
+    }
+
+    /// Returns a mutable reference to whether secondary indexes are
+    /// maintained.
+    pub fn secondary_indexes_mut(&mut self) -> &mut bool {
+        &mut self.secondary_indexes
+    }
 }
 
 impl Default for Config {
@@ -94,16 +201,34 @@ mod tests {
     use super::{Config, ResourceType};
     use static_assertions::assert_fields;
 
-    assert_fields!(Config: resource_types, message_cache_size);
+    assert_fields!(
+        Config: resource_types,
+        deleted_message_limit,
+        deleted_message_retention,
+        message_cache_size,
+        message_revision_limit,
+        secondary_indexes
+    );
 
     #[test]
     fn test_defaults() {
         let conf = Config {
             resource_types: ResourceType::all(),
+            deleted_message_limit: 1000,
+            deleted_message_retention: None,
             message_cache_size: 100,
+            message_revision_limit: 0,
+            secondary_indexes: false,
         };
         let default = Config::default();
         assert_eq!(conf.resource_types, default.resource_types);
+        assert_eq!(conf.deleted_message_limit, default.deleted_message_limit);
+        assert_eq!(
+            conf.deleted_message_retention,
+            default.deleted_message_retention
+        );
         assert_eq!(conf.message_cache_size, default.message_cache_size);
+        assert_eq!(conf.message_revision_limit, default.message_revision_limit);
+        assert_eq!(conf.secondary_indexes, default.secondary_indexes);
     }
 }
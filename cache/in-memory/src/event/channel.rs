@@ -44,14 +44,25 @@ impl InMemoryCache {
         let id = channel.id();
         self.guild_channels.entry(guild_id).or_default().insert(id);
 
+        if let Some((parent_id, metadata, member)) = super::thread::thread_parts(&channel) {
+            self.index_thread(guild_id, id, parent_id, metadata.archived);
+
+            if let Some(member) = member {
+                if self.wants_in(Some(guild_id), ResourceType::THREAD_MEMBER) {
+                    self.cache_thread_member(id, member);
+                }
+            }
+        }
+
         crate::upsert_guild_item(&self.channels_guild, guild_id, id, channel);
     }
 
-    fn cache_group(&self, group: Group) {
+    pub(crate) fn cache_group(&self, group: Group) {
         crate::upsert_item(&self.groups, group.id, group)
     }
 
-    fn cache_private_channel(&self, private_channel: PrivateChannel) {
+    pub(crate) fn cache_private_channel(&self, private_channel: PrivateChannel) {
+        self.index_private_channel(&private_channel);
         self.channels_private
             .insert(private_channel.id, private_channel);
     }
@@ -59,13 +70,21 @@ impl InMemoryCache {
     /// Delete a guild channel from the cache.
     ///
     /// The guild channel data itself and the channel entry in its guild's list
-    /// of channels will be deleted.
+    /// of channels will be deleted. If the channel is a thread then its members
+    /// are deleted as well.
     pub(crate) fn delete_guild_channel(&self, channel_id: ChannelId) {
         if let Some((_, item)) = self.channels_guild.remove(&channel_id) {
             if let Some(mut guild_channels) = self.guild_channels.get_mut(&item.guild_id) {
                 guild_channels.remove(&channel_id);
             }
+
+            if let Some((parent_id, _, _)) = super::thread::thread_parts(&item.value) {
+                self.unindex_thread(item.guild_id, channel_id, parent_id);
+                self.delete_thread_members(channel_id);
+            }
         }
+
+        self.channel_threads.remove(&channel_id);
     }
 
     fn delete_group(&self, channel_id: ChannelId) {
@@ -85,7 +104,9 @@ impl UpdateCache for ChannelCreate {
             }
             Channel::Guild(c) => {
                 if let Some(gid) = c.guild_id() {
-                    cache.cache_guild_channel(gid, c.clone());
+                    if cache.wants_in(Some(gid), ResourceType::CHANNEL) {
+                        cache.cache_guild_channel(gid, c.clone());
+                    }
                 }
             }
             Channel::Private(c) => {
@@ -109,7 +130,9 @@ impl UpdateCache for ChannelDelete {
                 cache.delete_guild_channel(c.id());
             }
             Channel::Private(ref c) => {
-                cache.channels_private.remove(&c.id);
+                if let Some((_, channel)) = cache.channels_private.remove(&c.id) {
+                    cache.unindex_private_channel(&channel);
+                }
             }
         }
     }
@@ -117,7 +140,7 @@ impl UpdateCache for ChannelDelete {
 
 impl UpdateCache for ChannelPinsUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::CHANNEL) {
+        if !cache.wants_in(self.guild_id, ResourceType::CHANNEL) {
             return;
         }
 
@@ -155,7 +178,9 @@ impl UpdateCache for ChannelUpdate {
             }
             Channel::Guild(c) => {
                 if let Some(gid) = c.guild_id() {
-                    cache.cache_guild_channel(gid, c);
+                    if cache.wants_in(Some(gid), ResourceType::CHANNEL) {
+                        cache.cache_guild_channel(gid, c);
+                    }
                 }
             }
             Channel::Private(c) => {
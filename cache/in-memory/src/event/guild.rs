@@ -4,61 +4,69 @@ use crate::{
     InMemoryCache, UpdateCache,
 };
 use dashmap::DashMap;
-use std::{collections::HashSet, hash::Hash};
+use std::{collections::HashSet, hash::Hash, mem};
 use twilight_model::{
     gateway::payload::incoming::{GuildCreate, GuildDelete, GuildUpdate},
-    guild::Guild,
+    guild::{Guild, PartialGuild},
     id::GuildId,
 };
 
 impl InMemoryCache {
-    fn cache_guild(&self, guild: Guild) {
+    pub(crate) fn cache_guild(&self, mut guild: Guild) {
         // The map and set creation needs to occur first, so caching states and
         // objects always has a place to put them.
-        if self.wants(ResourceType::CHANNEL) {
+        if self.wants_in(Some(guild.id), ResourceType::CHANNEL) {
             self.guild_channels.insert(guild.id, HashSet::new());
-            self.cache_guild_channels(guild.id, guild.channels);
-            self.cache_guild_channels(guild.id, guild.threads);
+            self.cache_guild_channels(guild.id, mem::take(&mut guild.channels));
+            self.cache_guild_channels(guild.id, mem::take(&mut guild.threads));
         }
 
-        if self.wants(ResourceType::EMOJI) {
+        if self.wants_in(Some(guild.id), ResourceType::EMOJI) {
             self.guild_emojis.insert(guild.id, HashSet::new());
-            self.cache_emojis(guild.id, guild.emojis);
+            self.cache_emojis(guild.id, mem::take(&mut guild.emojis));
         }
 
-        if self.wants(ResourceType::MEMBER) {
+        if self.wants_in(Some(guild.id), ResourceType::MEMBER) {
             self.guild_members.insert(guild.id, HashSet::new());
-            self.cache_members(guild.id, guild.members);
+            self.cache_members(guild.id, mem::take(&mut guild.members));
         }
 
-        if self.wants(ResourceType::PRESENCE) {
+        if self.wants_in(Some(guild.id), ResourceType::PRESENCE) {
             self.guild_presences.insert(guild.id, HashSet::new());
             self.cache_presences(
                 guild.id,
-                guild.presences.into_iter().map(CachedPresence::from),
+                mem::take(&mut guild.presences)
+                    .into_iter()
+                    .map(CachedPresence::from),
             );
         }
 
-        if self.wants(ResourceType::ROLE) {
+        if self.wants_in(Some(guild.id), ResourceType::ROLE) {
             self.guild_roles.insert(guild.id, HashSet::new());
-            self.cache_roles(guild.id, guild.roles);
+            self.cache_roles(guild.id, mem::take(&mut guild.roles));
         }
 
-        if self.wants(ResourceType::STICKER) {
+        if self.wants_in(Some(guild.id), ResourceType::STICKER) {
             self.guild_stage_instances.insert(guild.id, HashSet::new());
-            self.cache_stickers(guild.id, guild.stickers);
+            self.cache_stickers(guild.id, mem::take(&mut guild.stickers));
         }
 
-        if self.wants(ResourceType::VOICE_STATE) {
+        if self.wants_in(Some(guild.id), ResourceType::VOICE_STATE) {
             self.voice_state_guilds.insert(guild.id, HashSet::new());
-            self.cache_voice_states(guild.voice_states);
+            self.cache_voice_states(mem::take(&mut guild.voice_states));
         }
 
-        if self.wants(ResourceType::STAGE_INSTANCE) {
+        if self.wants_in(Some(guild.id), ResourceType::STAGE_INSTANCE) {
             self.guild_stage_instances.insert(guild.id, HashSet::new());
-            self.cache_stage_instances(guild.id, guild.stage_instances);
+            self.cache_stage_instances(guild.id, mem::take(&mut guild.stage_instances));
         }
 
+        self.cache_guild_only(guild);
+    }
+
+    /// Cache a guild without its channels, members, roles, or other
+    /// resources, leaving the sets of its cached resources as they are.
+    pub(crate) fn cache_guild_only(&self, guild: Guild) {
         let guild = CachedGuild {
             id: guild.id,
             afk_channel_id: guild.afk_channel_id,
@@ -99,20 +107,12 @@ impl InMemoryCache {
         self.unavailable_guilds.remove(&guild.id());
         self.guilds.insert(guild.id(), guild);
     }
-}
 
-impl UpdateCache for GuildCreate {
-    fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::GUILD) {
-            return;
-        }
-
-        cache.cache_guild(self.0.clone());
-    }
-}
-
-impl UpdateCache for GuildDelete {
-    fn update(&self, cache: &InMemoryCache) {
+    /// Delete the resources of a guild with the given resource types.
+    ///
+    /// Resource types that aren't scoped to guilds, such as users, are
+    /// ignored.
+    pub(crate) fn delete_guild_resources(&self, guild_id: GuildId, resource_types: ResourceType) {
         fn remove_ids<T: Eq + Hash, U>(
             guild_map: &DashMap<GuildId, HashSet<T>>,
             container: &DashMap<T, U>,
@@ -125,86 +125,186 @@ impl UpdateCache for GuildDelete {
             }
         }
 
-        if !cache.wants(ResourceType::GUILD) {
-            return;
+        if resource_types.contains(ResourceType::GUILD) {
+            self.guilds.remove(&guild_id);
         }
 
-        let id = self.id;
+        // Messages and thread members are found through the guild's channels,
+        // so they need to be deleted before the channels are.
+        if resource_types.intersects(ResourceType::MESSAGE | ResourceType::REACTION) {
+            let message_ids = self
+                .messages
+                .iter()
+                .filter(|message| message.guild_id() == Some(guild_id))
+                .map(|message| (message.channel_id(), message.id()))
+                .collect::<Vec<_>>();
+
+            for (channel_id, message_id) in message_ids {
+                if resource_types.contains(ResourceType::MESSAGE) {
+                    self.evict_message(message_id);
+
+                    if let Some(mut channel_messages) = self.channel_messages.get_mut(&channel_id) {
+                        channel_messages.retain(|id| *id != message_id);
+                    }
+                } else if let Some(mut message) = self.messages.get_mut(&message_id) {
+                    message.reactions.clear();
+                }
+            }
+        }
 
-        cache.guilds.remove(&id);
+        if resource_types.contains(ResourceType::THREAD_MEMBER) {
+            let channel_ids = self
+                .guild_channels
+                .get(&guild_id)
+                .map(|channel_ids| channel_ids.iter().copied().collect::<Vec<_>>())
+                .unwrap_or_default();
 
-        if cache.wants(ResourceType::CHANNEL) {
-            remove_ids(&cache.guild_channels, &cache.channels_guild, id);
+            for channel_id in channel_ids {
+                self.delete_thread_members(channel_id);
+            }
         }
 
-        if cache.wants(ResourceType::EMOJI) {
-            remove_ids(&cache.guild_emojis, &cache.emojis, id);
+        if resource_types.contains(ResourceType::CHANNEL) {
+            if let Some((_, ids)) = self.guild_channels.remove(&guild_id) {
+                for channel_id in ids {
+                    self.delete_guild_channel(channel_id);
+                }
+            }
+
+            self.guild_active_threads.remove(&guild_id);
         }
 
-        if cache.wants(ResourceType::ROLE) {
-            remove_ids(&cache.guild_roles, &cache.roles, id);
+        if resource_types.contains(ResourceType::EMOJI) {
+            remove_ids(&self.guild_emojis, &self.emojis, guild_id);
         }
 
-        if cache.wants(ResourceType::STICKER) {
-            remove_ids(&cache.guild_stickers, &cache.stickers, id);
+        if resource_types.contains(ResourceType::INTEGRATION) {
+            if let Some((_, ids)) = self.guild_integrations.remove(&guild_id) {
+                for integration_id in ids {
+                    self.integrations.remove(&(guild_id, integration_id));
+                }
+            }
         }
 
-        if cache.wants(ResourceType::VOICE_STATE) {
-            // Clear out a guilds voice states when a guild leaves
-            cache.voice_state_guilds.remove(&id);
+        if resource_types.contains(ResourceType::INVITE) {
+            self.delete_guild_invites(guild_id);
+            self.guild_vanity_urls.remove(&guild_id);
+        }
+
+        if resource_types.contains(ResourceType::ROLE) {
+            if let Some((_, ids)) = self.guild_roles.remove(&guild_id) {
+                for role_id in ids {
+                    self.roles.remove(&role_id);
+                    self.unindex_role(guild_id, role_id);
+                }
+            }
         }
 
-        if cache.wants(ResourceType::MEMBER) {
-            if let Some((_, ids)) = cache.guild_members.remove(&id) {
+        if resource_types.contains(ResourceType::STAGE_INSTANCE) {
+            remove_ids(&self.guild_stage_instances, &self.stage_instances, guild_id);
+        }
+
+        if resource_types.contains(ResourceType::STICKER) {
+            remove_ids(&self.guild_stickers, &self.stickers, guild_id);
+        }
+
+        if resource_types.contains(ResourceType::VOICE_STATE) {
+            if let Some((_, user_ids)) = self.voice_state_guilds.remove(&guild_id) {
+                for user_id in user_ids {
+                    let channel_id = self
+                        .voice_states
+                        .remove(&(guild_id, user_id))
+                        .and_then(|(_, voice_state)| voice_state.channel_id);
+
+                    if let Some(channel_id) = channel_id {
+                        if let Some(mut states) = self.voice_state_channels.get_mut(&channel_id) {
+                            states.remove(&(guild_id, user_id));
+                        }
+
+                        self.voice_state_channels
+                            .remove_if(&channel_id, |_, states| states.is_empty());
+                    }
+                }
+            }
+        }
+
+        if resource_types.contains(ResourceType::MEMBER) {
+            if let Some((_, ids)) = self.guild_members.remove(&guild_id) {
                 for user_id in ids {
-                    cache.members.remove(&(id, user_id));
+                    self.members.remove(&(guild_id, user_id));
+                    self.unindex_member(guild_id, user_id);
                 }
             }
         }
 
-        if cache.wants(ResourceType::PRESENCE) {
-            if let Some((_, ids)) = cache.guild_presences.remove(&id) {
+        if resource_types.contains(ResourceType::PRESENCE) {
+            if let Some((_, ids)) = self.guild_presences.remove(&guild_id) {
                 for user_id in ids {
-                    cache.presences.remove(&(id, user_id));
+                    self.presences.remove(&(guild_id, user_id));
                 }
             }
         }
     }
 }
 
-impl UpdateCache for GuildUpdate {
+impl UpdateCache for GuildCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants_in(Some(self.0.id), ResourceType::GUILD) {
+            return;
+        }
+
+        cache.cache_guild(self.0.clone());
+    }
+}
+
+impl UpdateCache for GuildDelete {
     fn update(&self, cache: &InMemoryCache) {
         if !cache.wants(ResourceType::GUILD) {
             return;
         }
 
+        cache.delete_guild_resources(self.id, cache.config.resource_types());
+    }
+}
+
+/// Update the fields of a cached guild that are present in a partial guild.
+pub(crate) fn update_guild(guild: &mut CachedGuild, update: &PartialGuild) {
+    guild.afk_channel_id = update.afk_channel_id;
+    guild.afk_timeout = update.afk_timeout;
+    guild.banner = update.banner.clone();
+    guild.default_message_notifications = update.default_message_notifications;
+    guild.description = update.description.clone();
+    guild.features = update.features.clone();
+    guild.icon = update.icon.clone();
+    guild.max_members = update.max_members;
+    guild.max_presences = Some(update.max_presences.unwrap_or(25000));
+    guild.mfa_level = update.mfa_level;
+    guild.name = update.name.clone();
+    guild.nsfw_level = update.nsfw_level;
+    guild.owner = update.owner;
+    guild.owner_id = update.owner_id;
+    guild.permissions = update.permissions;
+    guild.preferred_locale = update.preferred_locale.clone();
+    guild.premium_tier = update.premium_tier;
+    guild
+        .premium_subscription_count
+        .replace(update.premium_subscription_count.unwrap_or_default());
+    guild.splash = update.splash.clone();
+    guild.system_channel_id = update.system_channel_id;
+    guild.verification_level = update.verification_level;
+    guild.vanity_url_code = update.vanity_url_code.clone();
+    guild.widget_channel_id = update.widget_channel_id;
+    guild.widget_enabled = update.widget_enabled;
+}
+
+impl UpdateCache for GuildUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants_in(Some(self.0.id), ResourceType::GUILD) {
+            return;
+        }
+
         if let Some(mut guild) = cache.guilds.get_mut(&self.0.id) {
-            guild.afk_channel_id = self.afk_channel_id;
-            guild.afk_timeout = self.afk_timeout;
-            guild.banner = self.banner.clone();
-            guild.default_message_notifications = self.default_message_notifications;
-            guild.description = self.description.clone();
-            guild.features = self.features.clone();
-            guild.icon = self.icon.clone();
-            guild.max_members = self.max_members;
-            guild.max_presences = Some(self.max_presences.unwrap_or(25000));
-            guild.mfa_level = self.mfa_level;
-            guild.name = self.name.clone();
-            guild.nsfw_level = self.nsfw_level;
-            guild.owner = self.owner;
-            guild.owner_id = self.owner_id;
-            guild.permissions = self.permissions;
-            guild.preferred_locale = self.preferred_locale.clone();
-            guild.premium_tier = self.premium_tier;
-            guild
-                .premium_subscription_count
-                .replace(self.premium_subscription_count.unwrap_or_default());
-            guild.splash = self.splash.clone();
-            guild.system_channel_id = self.system_channel_id;
-            guild.verification_level = self.verification_level;
-            guild.vanity_url_code = self.vanity_url_code.clone();
-            guild.widget_channel_id = self.widget_channel_id;
-            guild.widget_enabled = self.widget_enabled;
+            update_guild(&mut guild, &self.0);
         };
     }
 }
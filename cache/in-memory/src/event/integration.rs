@@ -35,7 +35,7 @@ impl InMemoryCache {
 
 impl UpdateCache for IntegrationCreate {
     fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::INTEGRATION) {
+        if !cache.wants_in(self.guild_id, ResourceType::INTEGRATION) {
             return;
         }
 
@@ -62,7 +62,7 @@ impl UpdateCache for IntegrationDelete {
 
 impl UpdateCache for IntegrationUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::INTEGRATION) {
+        if !cache.wants_in(self.guild_id, ResourceType::INTEGRATION) {
             return;
         }
 
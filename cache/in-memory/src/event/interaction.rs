@@ -9,7 +9,7 @@ impl UpdateCache for InteractionCreate {
         #[allow(clippy::single_match)]
         match &self.0 {
             Interaction::ApplicationCommand(command) => {
-                if cache.wants(ResourceType::MEMBER) {
+                if cache.wants_in(command.guild_id, ResourceType::MEMBER) {
                     if let Some(member) = &command.member {
                         if let Some(user) = &member.user {
                             cache.cache_user(Cow::Borrowed(user), command.guild_id);
@@ -31,7 +31,9 @@ impl UpdateCache for InteractionCreate {
                     for u in resolved.users.values() {
                         cache.cache_user(Cow::Borrowed(u), command.guild_id);
 
-                        if !cache.wants(ResourceType::MEMBER) || command.guild_id.is_none() {
+                        if !cache.wants_in(command.guild_id, ResourceType::MEMBER)
+                            || command.guild_id.is_none()
+                        {
                             continue;
                         }
 
@@ -46,7 +48,7 @@ impl UpdateCache for InteractionCreate {
                         }
                     }
 
-                    if cache.wants(ResourceType::ROLE) {
+                    if cache.wants_in(command.guild_id, ResourceType::ROLE) {
                         if let Some(guild_id) = command.guild_id {
                             cache.cache_roles(
                                 guild_id,
@@ -0,0 +1,143 @@
+use crate::{config::ResourceType, model::CachedInvite, InMemoryCache, UpdateCache};
+use twilight_model::{
+    gateway::payload::incoming::{InviteCreate, InviteDelete},
+    id::GuildId,
+    invite::Invite,
+};
+
+impl InMemoryCache {
+    /// Replace the cached invites of a guild.
+    pub(crate) fn cache_guild_invites(
+        &self,
+        guild_id: GuildId,
+        invites: impl IntoIterator<Item = Invite>,
+    ) {
+        self.delete_guild_invites(guild_id);
+
+        for invite in invites {
+            self.cache_invite(CachedInvite {
+                channel_id: invite.channel.map(|channel| channel.id),
+                code: invite.code,
+                created_at: invite.created_at,
+                guild_id,
+                inviter_id: invite.inviter.map(|user| user.id),
+                max_age: invite.max_age.unwrap_or_default(),
+                max_uses: invite.max_uses.unwrap_or_default(),
+                temporary: invite.temporary.unwrap_or_default(),
+                uses: invite.uses.unwrap_or_default(),
+            });
+        }
+    }
+
+    fn cache_invite(&self, invite: CachedInvite) {
+        self.guild_invites
+            .entry(invite.guild_id)
+            .or_default()
+            .insert(invite.code.clone());
+
+        self.invites.insert(invite.code.clone(), invite);
+    }
+
+    fn delete_invite(&self, guild_id: GuildId, code: &str) {
+        if let Some(mut codes) = self.guild_invites.get_mut(&guild_id) {
+            codes.remove(code);
+        }
+
+        let invite = match self.invites.remove(code) {
+            Some((_, invite)) => invite,
+            None => return,
+        };
+
+        // Discord deletes invites once they reach their maximum number of
+        // uses, before the member who used it up is added, so keep the invite
+        // around for the invite tracker to attribute the member to.
+        if invite.max_uses > 0 && invite.uses + 1 == invite.max_uses {
+            self.deleted_invites
+                .entry(guild_id)
+                .or_default()
+                .push(invite);
+        }
+    }
+
+    /// Delete all of the cached invites of a guild, including deleted ones
+    /// kept for the invite tracker.
+    pub(crate) fn delete_guild_invites(&self, guild_id: GuildId) {
+        self.deleted_invites.remove(&guild_id);
+
+        if let Some((_, codes)) = self.guild_invites.remove(&guild_id) {
+            for code in codes {
+                self.invites.remove(&code);
+            }
+        }
+    }
+}
+
+impl UpdateCache for InviteCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants_in(Some(self.guild_id), ResourceType::INVITE) {
+            return;
+        }
+
+        cache.cache_invite(CachedInvite {
+            channel_id: Some(self.channel_id),
+            code: self.code.clone(),
+            created_at: Some(self.created_at),
+            guild_id: self.guild_id,
+            inviter_id: self.inviter.as_ref().map(|user| user.id),
+            max_age: self.max_age,
+            max_uses: self.max_uses,
+            temporary: self.temporary,
+            uses: u64::from(self.uses),
+        });
+    }
+}
+
+impl UpdateCache for InviteDelete {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::INVITE) {
+            return;
+        }
+
+        cache.delete_invite(self.guild_id, &self.code);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::InMemoryCache;
+    use twilight_model::{
+        datetime::Timestamp,
+        gateway::payload::incoming::{InviteCreate, InviteDelete},
+        id::{ChannelId, GuildId},
+    };
+
+    #[test]
+    fn test_invite_create_delete() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId::new(1).expect("non zero");
+
+        cache.update(&InviteCreate {
+            channel_id: ChannelId::new(2).expect("non zero"),
+            code: "twilight".to_owned(),
+            created_at: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+            guild_id,
+            inviter: None,
+            max_age: 0,
+            max_uses: 5,
+            target_user_type: None,
+            target_user: None,
+            temporary: false,
+            uses: 0,
+        });
+        assert_eq!(5, cache.invite("twilight").unwrap().max_uses());
+        assert!(cache.guild_invites(guild_id).unwrap().contains("twilight"));
+
+        cache.update(&InviteDelete {
+            channel_id: ChannelId::new(2).expect("non zero"),
+            code: "twilight".to_owned(),
+            guild_id,
+        });
+        assert!(cache.invite("twilight").is_none());
+        assert!(cache.guild_invites(guild_id).unwrap().is_empty());
+    }
+}
@@ -48,6 +48,30 @@ impl InMemoryCache {
             .entry(guild_id)
             .or_default()
             .insert(member_id);
+        self.index_member(guild_id, member_id);
+    }
+
+    pub(crate) fn delete_member(&self, guild_id: GuildId, user_id: UserId) {
+        self.members.remove(&(guild_id, user_id));
+        self.unindex_member(guild_id, user_id);
+
+        if let Some(mut members) = self.guild_members.get_mut(&guild_id) {
+            members.remove(&user_id);
+        }
+
+        // Avoid a deadlock by mutating the user, dropping the lock to the map,
+        // and then removing the user later if they are in no guilds.
+        let mut remove_user = false;
+
+        if let Some(mut user_guilds) = self.user_guilds.get_mut(&user_id) {
+            user_guilds.remove(&guild_id);
+
+            remove_user = user_guilds.is_empty();
+        }
+
+        if remove_user {
+            self.users.remove(&user_id);
+        }
     }
 
     pub(crate) fn cache_borrowed_partial_member(
@@ -82,6 +106,7 @@ impl InMemoryCache {
             user_id,
         };
         self.members.insert(id, cached);
+        self.index_member(guild_id, user_id);
     }
 
     pub(crate) fn cache_borrowed_interaction_member(
@@ -117,12 +142,13 @@ impl InMemoryCache {
         };
 
         self.members.insert(id, cached);
+        self.index_member(guild_id, user_id);
     }
 }
 
 impl UpdateCache for MemberAdd {
     fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::MEMBER) {
+        if !cache.wants_in(Some(self.guild_id), ResourceType::MEMBER) {
             return;
         }
 
@@ -138,7 +164,7 @@ impl UpdateCache for MemberAdd {
 
 impl UpdateCache for MemberChunk {
     fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::MEMBER) {
+        if !cache.wants_in(Some(self.guild_id), ResourceType::MEMBER) {
             return;
         }
 
@@ -158,46 +184,32 @@ impl UpdateCache for MemberRemove {
             return;
         }
 
-        cache.members.remove(&(self.guild_id, self.user.id));
-
-        if let Some(mut members) = cache.guild_members.get_mut(&self.guild_id) {
-            members.remove(&self.user.id);
-        }
-
-        // Avoid a deadlock by mutating the user, dropping the lock to the map,
-        // and then removing the user later if they are in no guilds.
-        let mut remove_user = false;
-
-        if let Some(mut user_guilds) = cache.user_guilds.get_mut(&self.user.id) {
-            user_guilds.remove(&self.guild_id);
-
-            remove_user = user_guilds.is_empty();
-        }
-
-        if remove_user {
-            cache.users.remove(&self.user.id);
-        }
+        cache.delete_member(self.guild_id, self.user.id);
     }
 }
 
 impl UpdateCache for MemberUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::MEMBER) {
+        if !cache.wants_in(Some(self.guild_id), ResourceType::MEMBER) {
             return;
         }
 
-        let mut member = match cache.members.get_mut(&(self.guild_id, self.user.id)) {
-            Some(member) => member,
-            None => return,
-        };
+        {
+            let mut member = match cache.members.get_mut(&(self.guild_id, self.user.id)) {
+                Some(member) => member,
+                None => return,
+            };
+
+            member.avatar = self.avatar.clone();
+            member.deaf = self.deaf.or_else(|| member.deaf());
+            member.mute = self.mute.or_else(|| member.mute());
+            member.nick = self.nick.clone();
+            member.roles = self.roles.clone();
+            member.joined_at = self.joined_at;
+            member.pending = self.pending;
+        }
 
-        member.avatar = self.avatar.clone();
-        member.deaf = self.deaf.or_else(|| member.deaf());
-        member.mute = self.mute.or_else(|| member.mute());
-        member.nick = self.nick.clone();
-        member.roles = self.roles.clone();
-        member.joined_at = self.joined_at;
-        member.pending = self.pending;
+        cache.index_member(self.guild_id, self.user.id);
     }
 }
 
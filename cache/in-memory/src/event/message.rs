@@ -1,8 +1,151 @@
-use crate::{config::ResourceType, model::CachedMessage, InMemoryCache, UpdateCache};
-use std::borrow::Cow;
-use twilight_model::gateway::payload::incoming::{
-    MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
+use crate::{
+    config::ResourceType,
+    model::{CachedMessage, CachedMessageRevision, MessageTombstone},
+    InMemoryCache, UpdateCache,
 };
+use std::{borrow::Cow, time::Instant};
+use twilight_model::{
+    channel::Message,
+    gateway::payload::incoming::{MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate},
+    id::{ChannelId, MessageId},
+};
+
+impl InMemoryCache {
+    /// Cache a message that was retrieved rather than received via the
+    /// gateway.
+    ///
+    /// The message is assumed to be older than the channel's cached messages,
+    /// so it replaces the oldest message if the channel's cache is full. It
+    /// isn't cached if the message cache size is 0.
+    #[cfg_attr(not(feature = "resolver"), allow(dead_code))]
+    pub(crate) fn cache_fetched_message(&self, message: Message) {
+        if self.config.message_cache_size() == 0 {
+            return;
+        }
+
+        let mut channel_messages = self.channel_messages.entry(message.channel_id).or_default();
+
+        if !channel_messages.contains(&message.id) {
+            if channel_messages.len() >= self.config.message_cache_size() {
+                if let Some(popped_id) = channel_messages.pop_back() {
+                    self.evict_message(popped_id);
+                }
+            }
+
+            channel_messages.push_back(message.id);
+        }
+
+        self.messages
+            .insert(message.id, CachedMessage::from(message));
+    }
+
+    /// Remove a message that no longer fits in its channel's cache, along
+    /// with its revisions.
+    pub(crate) fn evict_message(&self, message_id: MessageId) {
+        self.messages.remove(&message_id);
+        self.message_revisions.remove(&message_id);
+    }
+
+    /// Remove a deleted message, keeping a tombstone of it if configured.
+    fn delete_message(&self, channel_id: ChannelId, message_id: MessageId, bulk: bool) {
+        let message = self
+            .messages
+            .remove(&message_id)
+            .map(|(_, message)| message);
+        let revisions = self
+            .message_revisions
+            .remove(&message_id)
+            .map(|(_, revisions)| revisions)
+            .unwrap_or_default();
+
+        if self.config.deleted_message_retention().is_some() {
+            let limit = self.config.deleted_message_limit();
+
+            if limit == 0 {
+                return;
+            }
+
+            let mut order = self
+                .message_tombstone_order
+                .lock()
+                .expect("message tombstone order poisoned");
+
+            while self.message_tombstones.len() >= limit {
+                match order.pop_front() {
+                    Some((deleted_at, oldest)) => self.remove_message_tombstone(oldest, deleted_at),
+                    None => break,
+                }
+            }
+
+            let deleted_at = Instant::now();
+            order.push_back((deleted_at, message_id));
+
+            self.message_tombstones.insert(
+                message_id,
+                MessageTombstone {
+                    bulk,
+                    channel_id,
+                    deleted_at,
+                    id: message_id,
+                    message,
+                    revisions,
+                },
+            );
+        }
+    }
+
+    /// Remove tombstones that are older than the configured retention.
+    ///
+    /// Tombstones are queued in the order they were made, so only the expired
+    /// ones at the front of the queue are visited.
+    fn prune_message_tombstones(&self) {
+        let retention = match self.config.deleted_message_retention() {
+            Some(retention) => retention,
+            None => return,
+        };
+
+        let mut order = self
+            .message_tombstone_order
+            .lock()
+            .expect("message tombstone order poisoned");
+
+        while let Some((deleted_at, message_id)) = order.front().copied() {
+            if deleted_at.elapsed() < retention {
+                break;
+            }
+
+            order.pop_front();
+            self.remove_message_tombstone(message_id, deleted_at);
+        }
+    }
+
+    /// Remove a message's tombstone if it's the one made at a given time,
+    /// and not one made since the message was deleted again.
+    fn remove_message_tombstone(&self, message_id: MessageId, deleted_at: Instant) {
+        self.message_tombstones
+            .remove_if(&message_id, |_, tombstone| {
+                tombstone.deleted_at == deleted_at
+            });
+    }
+
+    /// Keep the current state of a message as a revision before it is
+    /// edited, dropping the oldest revision if the limit is reached.
+    fn cache_message_revision(&self, message: &CachedMessage) {
+        let limit = self.config.message_revision_limit();
+
+        if limit == 0 {
+            return;
+        }
+
+        let mut revisions = self.message_revisions.entry(message.id()).or_default();
+
+        if revisions.len() >= limit {
+            revisions.pop_front();
+        }
+
+        revisions.push_back(CachedMessageRevision::from(message));
+    }
+}
 
 impl UpdateCache for MessageCreate {
     fn update(&self, cache: &InMemoryCache) {
@@ -13,15 +156,17 @@ impl UpdateCache for MessageCreate {
         if let (Some(member), Some(guild_id), true) = (
             &self.member,
             self.guild_id,
-            cache.wants(ResourceType::MEMBER),
+            cache.wants_in(self.guild_id, ResourceType::MEMBER),
         ) {
             cache.cache_borrowed_partial_member(guild_id, member, self.author.id)
         }
 
-        if !cache.wants(ResourceType::MESSAGE) {
+        if !cache.wants_in(self.guild_id, ResourceType::MESSAGE) {
             return;
         }
 
+        cache.prune_message_tombstones();
+
         let mut channel_messages = cache.channel_messages.entry(self.0.channel_id).or_default();
 
         // If the channel has more messages than the cache size the user has
@@ -30,7 +175,7 @@ impl UpdateCache for MessageCreate {
         // filling up with old messages that aren't in any channel cache.
         if channel_messages.len() > cache.config.message_cache_size() {
             if let Some(popped_id) = channel_messages.pop_back() {
-                cache.messages.remove(&popped_id);
+                cache.evict_message(popped_id);
             }
         }
 
@@ -47,7 +192,8 @@ impl UpdateCache for MessageDelete {
             return;
         }
 
-        cache.messages.remove(&self.id);
+        cache.prune_message_tombstones();
+        cache.delete_message(self.channel_id, self.id, false);
 
         let mut channel_messages = cache.channel_messages.entry(self.channel_id).or_default();
 
@@ -63,10 +209,12 @@ impl UpdateCache for MessageDeleteBulk {
             return;
         }
 
+        cache.prune_message_tombstones();
+
         let mut channel_messages = cache.channel_messages.entry(self.channel_id).or_default();
 
         for id in &self.ids {
-            cache.messages.remove(id);
+            cache.delete_message(self.channel_id, *id, true);
 
             if let Some(idx) = channel_messages
                 .iter()
@@ -80,11 +228,30 @@ impl UpdateCache for MessageDeleteBulk {
 
 impl UpdateCache for MessageUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::MESSAGE) {
+        if !cache.wants_in(self.guild_id, ResourceType::MESSAGE) {
             return;
         }
 
+        cache.prune_message_tombstones();
+
         if let Some(mut message) = cache.messages.get_mut(&self.id) {
+            let revised = self
+                .attachments
+                .as_ref()
+                .map_or(false, |attachments| *attachments != message.attachments)
+                || self
+                    .content
+                    .as_ref()
+                    .map_or(false, |content| *content != message.content)
+                || self
+                    .embeds
+                    .as_ref()
+                    .map_or(false, |embeds| *embeds != message.embeds);
+
+            if revised {
+                cache.cache_message_revision(&message);
+            }
+
             if let Some(attachments) = &self.attachments {
                 message.attachments = attachments.clone();
             }
@@ -131,6 +298,7 @@ impl UpdateCache for MessageUpdate {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
     use twilight_model::{
         channel::message::{Message, MessageFlags, MessageType},
         datetime::Timestamp,
@@ -231,4 +399,174 @@ mod tests {
             assert_eq!(entry.value().len(), 1);
         }
     }
+
+    fn message_update(id: MessageId, channel_id: ChannelId, content: &str) -> MessageUpdate {
+        MessageUpdate {
+            attachments: None,
+            author: None,
+            channel_id,
+            content: Some(content.to_owned()),
+            edited_timestamp: None,
+            embeds: None,
+            guild_id: None,
+            id,
+            kind: None,
+            mention_everyone: None,
+            mention_roles: None,
+            mentions: None,
+            pinned: None,
+            timestamp: None,
+            tts: None,
+        }
+    }
+
+    #[test]
+    fn test_message_history() {
+        let cache = InMemoryCache::builder()
+            .deleted_message_retention(Some(Duration::from_secs(60)))
+            .message_revision_limit(2)
+            .build();
+        let channel_id = ChannelId::new(2).expect("non zero");
+        let message_id = MessageId::new(4).expect("non zero");
+        let mut message = crate::test::message(message_id, channel_id);
+        message.content = "v1".to_owned();
+        cache.update(&MessageCreate(message));
+
+        for content in ["v2", "v2", "v3", "v4"] {
+            cache.update(&message_update(message_id, channel_id, content));
+        }
+
+        {
+            let revisions = cache.message_revisions(message_id).unwrap();
+            let contents = revisions
+                .iter()
+                .map(CachedMessageRevision::content)
+                .collect::<Vec<_>>();
+            assert_eq!(vec!["v2", "v3"], contents);
+        }
+
+        cache.update(&MessageDelete {
+            channel_id,
+            guild_id: None,
+            id: message_id,
+        });
+        assert!(cache.message(message_id).is_none());
+        assert!(cache.message_revisions(message_id).is_none());
+
+        let tombstone = cache.deleted_message(message_id).unwrap();
+        assert!(!tombstone.is_bulk());
+        assert_eq!("v4", tombstone.message().unwrap().content());
+        assert_eq!(2, tombstone.revisions().len());
+    }
+
+    #[test]
+    fn test_message_history_bulk_and_expiry() {
+        let cache = InMemoryCache::builder()
+            .deleted_message_retention(Some(Duration::from_secs(0)))
+            .build();
+        let channel_id = ChannelId::new(2).expect("non zero");
+        let ids = vec![
+            MessageId::new(4).expect("non zero"),
+            MessageId::new(5).expect("non zero"),
+        ];
+
+        cache.update(&MessageCreate(crate::test::message(ids[0], channel_id)));
+        cache.update(&MessageDeleteBulk {
+            channel_id,
+            guild_id: None,
+            ids: ids.clone(),
+        });
+
+        {
+            let tombstone = cache.message_tombstones.get(&ids[0]).unwrap();
+            assert!(tombstone.is_bulk());
+            assert!(tombstone.message().is_some());
+            assert!(cache
+                .message_tombstones
+                .get(&ids[1])
+                .unwrap()
+                .message()
+                .is_none());
+        }
+
+        // The retention window has already passed, so looking the tombstone
+        // up removes it.
+        assert!(cache.deleted_message(ids[0]).is_none());
+        assert!(!cache.message_tombstones.contains_key(&ids[0]));
+
+        // Creating a message prunes the remaining expired tombstone.
+        cache.update(&MessageCreate(crate::test::message(
+            MessageId::new(6).expect("non zero"),
+            channel_id,
+        )));
+        assert!(cache.message_tombstones.is_empty());
+    }
+
+    #[test]
+    fn test_deleted_message_limit() {
+        let cache = InMemoryCache::builder()
+            .deleted_message_limit(2)
+            .deleted_message_retention(Some(Duration::from_secs(60)))
+            .build();
+        let channel_id = ChannelId::new(2).expect("non zero");
+        let ids = (4..7)
+            .map(|id| MessageId::new(id).expect("non zero"))
+            .collect::<Vec<_>>();
+
+        for id in &ids {
+            cache.update(&MessageDelete {
+                channel_id,
+                guild_id: None,
+                id: *id,
+            });
+        }
+
+        assert_eq!(2, cache.message_tombstones.len());
+        assert!(cache.deleted_message(ids[0]).is_none());
+        assert!(cache.deleted_message(ids[1]).is_some());
+        assert!(cache.deleted_message(ids[2]).is_some());
+    }
+
+    #[test]
+    fn test_deleted_message_limit_deleted_again() {
+        let cache = InMemoryCache::builder()
+            .deleted_message_limit(2)
+            .deleted_message_retention(Some(Duration::from_secs(60)))
+            .build();
+        let channel_id = ChannelId::new(2).expect("non zero");
+        let ids = (4..7)
+            .map(|id| MessageId::new(id).expect("non zero"))
+            .collect::<Vec<_>>();
+
+        // The first message's replaced tombstone is skipped when evicting.
+        for id in [ids[0], ids[0], ids[1], ids[2]] {
+            cache.update(&MessageDelete {
+                channel_id,
+                guild_id: None,
+                id,
+            });
+        }
+
+        assert_eq!(2, cache.message_tombstones.len());
+        assert!(cache.deleted_message(ids[0]).is_none());
+        assert!(cache.deleted_message(ids[1]).is_some());
+        assert!(cache.deleted_message(ids[2]).is_some());
+        assert!(cache
+            .message_tombstone_order
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|(_, id)| ids[1..].contains(id)));
+    }
+
+    #[test]
+    fn test_cache_fetched_message_size() {
+        let cache = InMemoryCache::builder().message_cache_size(0).build();
+        let channel_id = ChannelId::new(2).expect("non zero");
+        let message_id = MessageId::new(4).expect("non zero");
+        cache.cache_fetched_message(crate::test::message(message_id, channel_id));
+
+        assert!(cache.message(message_id).is_none());
+        assert!(!cache.channel_messages.contains_key(&channel_id));
+    }
 }
@@ -3,6 +3,7 @@ pub mod emoji;
 pub mod guild;
 pub mod integration;
 pub mod interaction;
+pub mod invite;
 pub mod member;
 pub mod message;
 pub mod presence;
@@ -30,7 +31,7 @@ impl InMemoryCache {
     }
 
     pub(crate) fn cache_user(&self, user: Cow<'_, User>, guild_id: Option<GuildId>) {
-        match self.users.get_mut(&user.id) {
+        let renamed = match self.users.get_mut(&user.id) {
             Some(u) if u.value() == user.as_ref() => {
                 if let Some(guild_id) = guild_id {
                     self.user_guilds
@@ -41,8 +42,9 @@ impl InMemoryCache {
 
                 return;
             }
-            Some(_) | None => {}
-        }
+            Some(u) => u.name != user.name,
+            None => false,
+        };
         let user = user.into_owned();
         let user_id = user.id;
 
@@ -53,6 +55,10 @@ impl InMemoryCache {
             guild_id_set.insert(guild_id);
             self.user_guilds.insert(user_id, guild_id_set);
         }
+
+        if renamed {
+            self.reindex_user(user_id);
+        }
     }
 
     fn unavailable_guild(&self, guild_id: GuildId) {
@@ -20,7 +20,7 @@ impl InMemoryCache {
 
 impl UpdateCache for PresenceUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::PRESENCE) {
+        if !cache.wants_in(Some(self.guild_id), ResourceType::PRESENCE) {
             return;
         }
 
@@ -23,18 +23,20 @@ impl InMemoryCache {
         crate::upsert_guild_item(&self.roles, guild_id, role.id, role);
     }
 
-    fn delete_role(&self, role_id: RoleId) {
+    pub(crate) fn delete_role(&self, role_id: RoleId) {
         if let Some((_, role)) = self.roles.remove(&role_id) {
             if let Some(mut roles) = self.guild_roles.get_mut(&role.guild_id) {
                 roles.remove(&role_id);
             }
+
+            self.unindex_role(role.guild_id, role_id);
         }
     }
 }
 
 impl UpdateCache for RoleCreate {
     fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::ROLE) {
+        if !cache.wants_in(Some(self.guild_id), ResourceType::ROLE) {
             return;
         }
 
@@ -54,7 +56,7 @@ impl UpdateCache for RoleDelete {
 
 impl UpdateCache for RoleUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::ROLE) {
+        if !cache.wants_in(Some(self.guild_id), ResourceType::ROLE) {
             return;
         }
 
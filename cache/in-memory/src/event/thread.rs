@@ -1,9 +1,124 @@
-use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use crate::{config::ResourceType, model::CachedThreadMember, InMemoryCache, UpdateCache};
+use std::collections::HashSet;
 use twilight_model::{
-    channel::{Channel, GuildChannel},
-    gateway::payload::incoming::{ThreadCreate, ThreadDelete, ThreadListSync, ThreadUpdate},
+    channel::{
+        thread::{ThreadMember, ThreadMetadata},
+        Channel, GuildChannel,
+    },
+    gateway::payload::incoming::{
+        ThreadCreate, ThreadDelete, ThreadListSync, ThreadMemberUpdate, ThreadMembersUpdate,
+        ThreadUpdate,
+    },
+    id::{ChannelId, GuildId, UserId},
 };
 
+/// Parent channel ID, metadata, and current user's membership of a thread.
+///
+/// Returns `None` if the channel isn't a thread.
+pub(crate) const fn thread_parts(
+    channel: &GuildChannel,
+) -> Option<(Option<ChannelId>, &ThreadMetadata, Option<&ThreadMember>)> {
+    match channel {
+        GuildChannel::NewsThread(c) => Some((c.parent_id, &c.thread_metadata, c.member.as_ref())),
+        GuildChannel::PrivateThread(c) => {
+            Some((c.parent_id, &c.thread_metadata, c.member.as_ref()))
+        }
+        GuildChannel::PublicThread(c) => Some((c.parent_id, &c.thread_metadata, c.member.as_ref())),
+        _ => None,
+    }
+}
+
+impl InMemoryCache {
+    /// Index a thread by its parent channel and, if it isn't archived, as one
+    /// of its guild's active threads.
+    pub(crate) fn index_thread(
+        &self,
+        guild_id: GuildId,
+        channel_id: ChannelId,
+        parent_id: Option<ChannelId>,
+        archived: bool,
+    ) {
+        if let Some(parent_id) = parent_id {
+            self.channel_threads
+                .entry(parent_id)
+                .or_default()
+                .insert(channel_id);
+        }
+
+        let mut active_threads = self.guild_active_threads.entry(guild_id).or_default();
+
+        if archived {
+            active_threads.remove(&channel_id);
+        } else {
+            active_threads.insert(channel_id);
+        }
+    }
+
+    /// Remove a thread from the indexes of its parent channel and guild.
+    pub(crate) fn unindex_thread(
+        &self,
+        guild_id: GuildId,
+        channel_id: ChannelId,
+        parent_id: Option<ChannelId>,
+    ) {
+        if let Some(parent_id) = parent_id {
+            if let Some(mut threads) = self.channel_threads.get_mut(&parent_id) {
+                threads.remove(&channel_id);
+            }
+        }
+
+        if let Some(mut active_threads) = self.guild_active_threads.get_mut(&guild_id) {
+            active_threads.remove(&channel_id);
+        }
+    }
+
+    /// Cache a member of a thread.
+    ///
+    /// If the thread member doesn't include the ID of its user then it's
+    /// considered to be the current user's membership.
+    pub(crate) fn cache_thread_member(&self, channel_id: ChannelId, member: &ThreadMember) {
+        let user_id = match member
+            .user_id
+            .or_else(|| self.current_user().map(|user| user.id))
+        {
+            Some(user_id) => user_id,
+            None => return,
+        };
+
+        self.thread_members
+            .entry(channel_id)
+            .or_default()
+            .insert(user_id);
+
+        self.thread_memberships.insert(
+            (channel_id, user_id),
+            CachedThreadMember {
+                channel_id,
+                flags: member.flags,
+                join_timestamp: member.join_timestamp,
+                user_id,
+            },
+        );
+    }
+
+    fn delete_thread_member(&self, channel_id: ChannelId, user_id: UserId) {
+        if let Some(mut members) = self.thread_members.get_mut(&channel_id) {
+            members.remove(&user_id);
+        }
+
+        self.thread_memberships.remove(&(channel_id, user_id));
+    }
+
+    /// Delete all of the members of a thread.
+    pub(crate) fn delete_thread_members(&self, channel_id: ChannelId) {
+        if let Some((_, user_ids)) = self.thread_members.remove(&channel_id) {
+            for user_id in user_ids {
+                self.thread_memberships.remove(&(channel_id, user_id));
+            }
+        }
+    }
+}
+
 impl UpdateCache for ThreadCreate {
     fn update(&self, cache: &InMemoryCache) {
         if !cache.wants(ResourceType::CHANNEL) {
@@ -12,7 +127,9 @@ impl UpdateCache for ThreadCreate {
 
         if let Channel::Guild(c) = &self.0 {
             if let Some(gid) = c.guild_id() {
-                cache.cache_guild_channel(gid, c.clone());
+                if cache.wants_in(Some(gid), ResourceType::CHANNEL) {
+                    cache.cache_guild_channel(gid, c.clone());
+                }
             }
         }
     }
@@ -30,7 +147,7 @@ impl UpdateCache for ThreadDelete {
 
 impl UpdateCache for ThreadListSync {
     fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::CHANNEL) {
+        if !cache.wants_in(Some(self.guild_id), ResourceType::CHANNEL) {
             return;
         }
 
@@ -43,7 +160,92 @@ impl UpdateCache for ThreadListSync {
             })
             .collect();
 
+        // Threads that are no longer active in the synced channels, or the
+        // whole guild if no channels are given, are omitted from the list.
+        let synced: HashSet<ChannelId> = threads.iter().map(GuildChannel::id).collect();
+        let parent_ids: HashSet<ChannelId> = self
+            .channel_ids
+            .iter()
+            .map(|id| ChannelId::from(id.0))
+            .collect();
+
+        if let Some(mut active_threads) = cache.guild_active_threads.get_mut(&self.guild_id) {
+            active_threads.retain(|channel_id| {
+                if synced.contains(channel_id) || parent_ids.is_empty() {
+                    return synced.contains(channel_id);
+                }
+
+                cache
+                    .channels_guild
+                    .get(channel_id)
+                    .and_then(|channel| thread_parts(channel.resource()).and_then(|parts| parts.0))
+                    .map_or(true, |parent_id| !parent_ids.contains(&parent_id))
+            });
+        }
+
         cache.cache_guild_channels(self.guild_id, threads);
+
+        if cache.wants_in(Some(self.guild_id), ResourceType::THREAD_MEMBER) {
+            for member in &self.members {
+                if let Some(channel_id) = member.id {
+                    cache.cache_thread_member(channel_id, member);
+                }
+            }
+        }
+    }
+}
+
+impl UpdateCache for ThreadMemberUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        let channel_id = match self.id {
+            Some(channel_id) => channel_id,
+            None => return,
+        };
+
+        // The event doesn't include the guild, so it's found via the thread.
+        let guild_id = cache
+            .channels_guild
+            .get(&channel_id)
+            .map(|channel| channel.guild_id());
+
+        if !cache.wants_in(guild_id, ResourceType::THREAD_MEMBER) {
+            return;
+        }
+
+        cache.cache_thread_member(channel_id, &self.0);
+    }
+}
+
+impl UpdateCache for ThreadMembersUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if cache.wants_in(Some(self.guild_id), ResourceType::CHANNEL) {
+            if let Some(mut channel) = cache.channels_guild.get_mut(&self.id) {
+                match channel.value_mut().value {
+                    GuildChannel::NewsThread(ref mut c) => c.member_count = self.member_count,
+                    GuildChannel::PrivateThread(ref mut c) => c.member_count = self.member_count,
+                    GuildChannel::PublicThread(ref mut c) => c.member_count = self.member_count,
+                    _ => {}
+                }
+            }
+        }
+
+        if !cache.wants_in(Some(self.guild_id), ResourceType::THREAD_MEMBER) {
+            return;
+        }
+
+        for member in &self.added_members {
+            cache.cache_thread_member(self.id, member);
+
+            if let Some(guild_member) = &member.member {
+                if cache.wants_in(Some(self.guild_id), ResourceType::MEMBER) {
+                    cache.cache_member(self.guild_id, guild_member.clone());
+                }
+            }
+        }
+
+        for user_id in &self.removed_member_ids {
+            cache.delete_thread_member(self.id, *user_id);
+        }
     }
 }
 
@@ -55,8 +257,153 @@ impl UpdateCache for ThreadUpdate {
 
         if let Channel::Guild(c) = &self.0 {
             if let Some(gid) = c.guild_id() {
-                cache.cache_guild_channel(gid, c.clone());
+                if cache.wants_in(Some(gid), ResourceType::CHANNEL) {
+                    cache.cache_guild_channel(gid, c.clone());
+                }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{InMemoryCache, ResourceType};
+    use twilight_model::{
+        channel::{
+            thread::{AutoArchiveDuration, PublicThread, ThreadMember, ThreadMetadata},
+            Channel, ChannelType, GuildChannel,
+        },
+        datetime::Timestamp,
+        gateway::payload::incoming::{
+            ThreadDelete, ThreadListSync, ThreadMembersUpdate, ThreadUpdate,
+        },
+        id::{ChannelId, GuildId, UserId},
+    };
+
+    fn guild_id() -> GuildId {
+        GuildId::new(1).expect("non zero")
+    }
+
+    fn parent_id() -> ChannelId {
+        ChannelId::new(2).expect("non zero")
+    }
+
+    fn thread(id: u64, archived: bool) -> GuildChannel {
+        GuildChannel::PublicThread(PublicThread {
+            default_auto_archive_duration: None,
+            guild_id: Some(guild_id()),
+            id: ChannelId::new(id).expect("non zero"),
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: None,
+            member: None,
+            member_count: 0,
+            message_count: 0,
+            name: "thread".to_owned(),
+            owner_id: None,
+            parent_id: Some(parent_id()),
+            rate_limit_per_user: None,
+            thread_metadata: ThreadMetadata {
+                archived,
+                auto_archive_duration: AutoArchiveDuration::Hour,
+                archive_timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+                invitable: None,
+                locked: false,
+            },
+        })
+    }
+
+    fn member(user_id: UserId) -> ThreadMember {
+        ThreadMember {
+            flags: 0,
+            id: None,
+            join_timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+            member: None,
+            presence: None,
+            user_id: Some(user_id),
+        }
+    }
+
+    #[test]
+    fn test_active_threads() {
+        let cache = InMemoryCache::new();
+        let thread_id = ChannelId::new(3).expect("non zero");
+        cache.cache_guild_channels(guild_id(), vec![thread(3, false), thread(4, true)]);
+
+        assert_eq!(2, cache.threads_in_channel(parent_id()).unwrap().len());
+        assert!(cache
+            .active_threads(guild_id())
+            .unwrap()
+            .contains(&thread_id));
+        assert_eq!(1, cache.active_threads(guild_id()).unwrap().len());
+        assert!(cache.thread_auto_archive_at(thread_id).is_some());
+
+        cache.update(&ThreadUpdate(Channel::Guild(thread(3, true))));
+        assert!(cache.active_threads(guild_id()).unwrap().is_empty());
+        assert!(cache.thread_auto_archive_at(thread_id).is_none());
+
+        cache.update(&ThreadListSync {
+            channel_ids: Vec::new(),
+            guild_id: guild_id(),
+            members: Vec::new(),
+            threads: vec![Channel::Guild(thread(4, false))],
+        });
+        assert_eq!(1, cache.active_threads(guild_id()).unwrap().len());
+
+        cache.update(&ThreadDelete(Channel::Guild(thread(4, false))));
+        assert!(cache.active_threads(guild_id()).unwrap().is_empty());
+        assert_eq!(1, cache.threads_in_channel(parent_id()).unwrap().len());
+    }
+
+    #[test]
+    fn test_thread_members() {
+        let cache = InMemoryCache::new();
+        let thread_id = ChannelId::new(3).expect("non zero");
+        let user_a = UserId::new(5).expect("non zero");
+        let user_b = UserId::new(6).expect("non zero");
+        cache.cache_guild_channel(guild_id(), thread(3, false));
+
+        cache.update(&ThreadMembersUpdate {
+            added_members: vec![member(user_a), member(user_b)],
+            guild_id: guild_id(),
+            id: thread_id,
+            member_count: 2,
+            removed_member_ids: Vec::new(),
+        });
+        assert_eq!(2, cache.thread_members(thread_id).unwrap().len());
+        assert_eq!(
+            user_a,
+            cache.thread_member(thread_id, user_a).unwrap().user_id()
+        );
+
+        cache.update(&ThreadMembersUpdate {
+            added_members: Vec::new(),
+            guild_id: guild_id(),
+            id: thread_id,
+            member_count: 1,
+            removed_member_ids: vec![user_a],
+        });
+        assert!(cache.thread_member(thread_id, user_a).is_none());
+        assert_eq!(1, cache.thread_members(thread_id).unwrap().len());
+
+        cache.update(&ThreadDelete(Channel::Guild(thread(3, false))));
+        assert!(cache.thread_members(thread_id).is_none());
+        assert!(cache.thread_member(thread_id, user_b).is_none());
+    }
+
+    #[test]
+    fn test_thread_members_resource_type() {
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::CHANNEL)
+            .build();
+        let thread_id = ChannelId::new(3).expect("non zero");
+
+        cache.update(&ThreadMembersUpdate {
+            added_members: vec![member(UserId::new(5).expect("non zero"))],
+            guild_id: guild_id(),
+            id: thread_id,
+            member_count: 1,
+            removed_member_ids: Vec::new(),
+        });
+        assert!(cache.thread_members(thread_id).is_none());
+    }
+}
@@ -78,13 +78,17 @@ impl InMemoryCache {
 
 impl UpdateCache for VoiceStateUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !cache.wants(ResourceType::VOICE_STATE) {
+        if !cache.wants_in(self.0.guild_id, ResourceType::VOICE_STATE) {
             return;
         }
 
         cache.cache_voice_state(self.0.clone());
 
-        if let (Some(guild_id), Some(member)) = (self.0.guild_id, &self.0.member) {
+        if let (Some(guild_id), Some(member), true) = (
+            self.0.guild_id,
+            &self.0.member,
+            cache.wants_in(self.0.guild_id, ResourceType::MEMBER),
+        ) {
             cache.cache_member(guild_id, member.clone());
         }
     }
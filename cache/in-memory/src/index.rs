@@ -0,0 +1,272 @@
+//! Secondary indexes over cached members and private channels.
+//!
+//! Indexes are only maintained if enabled via
+//! [`InMemoryCacheBuilder::secondary_indexes`].
+//!
+//! [`InMemoryCacheBuilder::secondary_indexes`]: crate::InMemoryCacheBuilder::secondary_indexes
+
+use super::InMemoryCache;
+use twilight_model::{
+    channel::PrivateChannel,
+    id::{GuildId, RoleId, UserId},
+};
+
+/// Keys a member is indexed under, used to remove the member from the indexes
+/// after the member has changed.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct MemberIndexKeys {
+    /// Lowercased username and nickname of the member.
+    names: Vec<String>,
+    /// Roles of the member.
+    roles: Vec<RoleId>,
+}
+
+impl InMemoryCache {
+    /// Index a cached member by its roles and names, replacing its previous
+    /// index entries.
+    ///
+    /// The member is removed from the indexes if it isn't cached.
+    pub(crate) fn index_member(&self, guild_id: GuildId, user_id: UserId) {
+        if !self.config.secondary_indexes() {
+            return;
+        }
+
+        let (nick, roles) = match self.members.get(&(guild_id, user_id)) {
+            Some(member) => (member.nick.clone(), member.roles.clone()),
+            None => return self.unindex_member(guild_id, user_id),
+        };
+
+        let mut names = self
+            .users
+            .get(&user_id)
+            .map(|user| user.name.to_lowercase())
+            .into_iter()
+            .chain(nick.map(|nick| nick.to_lowercase()))
+            .collect::<Vec<_>>();
+        names.dedup();
+
+        self.unindex_member(guild_id, user_id);
+
+        for role_id in &roles {
+            self.role_members
+                .entry(*role_id)
+                .or_default()
+                .insert(user_id);
+        }
+
+        if !names.is_empty() {
+            let mut guild_names = self.guild_member_names.entry(guild_id).or_default();
+
+            for name in &names {
+                guild_names.entry(name.clone()).or_default().insert(user_id);
+            }
+        }
+
+        self.member_index_keys
+            .insert((guild_id, user_id), MemberIndexKeys { names, roles });
+    }
+
+    /// Remove a member from the indexes.
+    pub(crate) fn unindex_member(&self, guild_id: GuildId, user_id: UserId) {
+        let keys = match self.member_index_keys.remove(&(guild_id, user_id)) {
+            Some((_, keys)) => keys,
+            None => return,
+        };
+
+        for role_id in keys.roles {
+            if let Some(mut members) = self.role_members.get_mut(&role_id) {
+                members.remove(&user_id);
+            }
+
+            self.role_members
+                .remove_if(&role_id, |_, members| members.is_empty());
+        }
+
+        if let Some(mut guild_names) = self.guild_member_names.get_mut(&guild_id) {
+            for name in keys.names {
+                let now_empty = guild_names.get_mut(&name).map_or(false, |members| {
+                    members.remove(&user_id);
+
+                    members.is_empty()
+                });
+
+                if now_empty {
+                    guild_names.remove(&name);
+                }
+            }
+        }
+
+        self.guild_member_names
+            .remove_if(&guild_id, |_, names| names.is_empty());
+    }
+
+    /// Remove a deleted role from the indexes of its members.
+    pub(crate) fn unindex_role(&self, guild_id: GuildId, role_id: RoleId) {
+        if let Some((_, members)) = self.role_members.remove(&role_id) {
+            for user_id in members {
+                if let Some(mut keys) = self.member_index_keys.get_mut(&(guild_id, user_id)) {
+                    keys.roles.retain(|id| *id != role_id);
+                }
+            }
+        }
+    }
+
+    /// Index the members of a user's guilds again, such as after the user's
+    /// name changed.
+    pub(crate) fn reindex_user(&self, user_id: UserId) {
+        if !self.config.secondary_indexes() {
+            return;
+        }
+
+        let guild_ids = match self.user_guilds.get(&user_id) {
+            Some(guild_ids) => guild_ids.clone(),
+            None => return,
+        };
+
+        for guild_id in guild_ids {
+            if self.member_index_keys.contains_key(&(guild_id, user_id)) {
+                self.index_member(guild_id, user_id);
+            }
+        }
+    }
+
+    /// Index a private channel by its recipients.
+    pub(crate) fn index_private_channel(&self, channel: &PrivateChannel) {
+        if !self.config.secondary_indexes() {
+            return;
+        }
+
+        for recipient in &channel.recipients {
+            self.user_private_channels.insert(recipient.id, channel.id);
+        }
+    }
+
+    /// Remove a private channel from the index of its recipients.
+    pub(crate) fn unindex_private_channel(&self, channel: &PrivateChannel) {
+        for recipient in &channel.recipients {
+            self.user_private_channels
+                .remove_if(&recipient.id, |_, channel_id| *channel_id == channel.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test, InMemoryCache};
+    use std::borrow::Cow;
+    use twilight_model::{
+        channel::{ChannelType, PrivateChannel},
+        gateway::payload::incoming::{MemberRemove, MemberUpdate, RoleDelete},
+        id::{ChannelId, GuildId, RoleId, UserId},
+    };
+
+    fn indexed_cache() -> InMemoryCache {
+        InMemoryCache::builder().secondary_indexes(true).build()
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId::new(1).expect("non zero");
+        let user_id = UserId::new(2).expect("non zero");
+        let mut member = test::member(user_id, guild_id);
+        member.roles.push(RoleId::new(3).expect("non zero"));
+        cache.cache_member(guild_id, member);
+
+        assert!(cache
+            .role_members(RoleId::new(3).expect("non zero"))
+            .is_none());
+        assert!(cache.members_by_name_prefix(guild_id, "t").is_empty());
+    }
+
+    #[test]
+    fn test_member_indexes() {
+        let cache = indexed_cache();
+        let guild_id = GuildId::new(1).expect("non zero");
+        let role_id = RoleId::new(3).expect("non zero");
+        let user_id = UserId::new(2).expect("non zero");
+
+        let mut member = test::member(user_id, guild_id);
+        member.nick = Some("Twily".to_owned());
+        member.roles.push(role_id);
+        cache.cache_member(guild_id, member.clone());
+
+        assert!(cache.role_members(role_id).unwrap().contains(&user_id));
+        assert_eq!(vec![user_id], cache.members_by_name_prefix(guild_id, "TW"));
+        assert_eq!(
+            vec![user_id],
+            cache.members_by_name_prefix(guild_id, "user")
+        );
+        assert!(cache.members_by_name_prefix(guild_id, "x").is_empty());
+
+        cache.update(&MemberUpdate {
+            avatar: None,
+            deaf: None,
+            guild_id,
+            joined_at: member.joined_at,
+            mute: None,
+            nick: None,
+            pending: false,
+            premium_since: None,
+            roles: Vec::new(),
+            user: member.user.clone(),
+        });
+
+        assert!(cache.role_members(role_id).is_none());
+        assert!(cache.members_by_name_prefix(guild_id, "tw").is_empty());
+        assert_eq!(vec![user_id], cache.members_by_name_prefix(guild_id, "us"));
+
+        let mut renamed = member.user.clone();
+        renamed.name = "renamed".to_owned();
+        cache.cache_user(Cow::Owned(renamed), Some(guild_id));
+        assert!(cache.members_by_name_prefix(guild_id, "us").is_empty());
+        assert_eq!(vec![user_id], cache.members_by_name_prefix(guild_id, "re"));
+
+        cache.update(&MemberRemove {
+            guild_id,
+            user: member.user,
+        });
+        assert!(cache.members_by_name_prefix(guild_id, "").is_empty());
+        assert!(cache.member_index_keys.is_empty());
+    }
+
+    #[test]
+    fn test_role_delete() {
+        let cache = indexed_cache();
+        let guild_id = GuildId::new(1).expect("non zero");
+        let role_id = RoleId::new(3).expect("non zero");
+        let user_id = UserId::new(2).expect("non zero");
+
+        cache.cache_roles(guild_id, vec![test::role(role_id)]);
+        let mut member = test::member(user_id, guild_id);
+        member.roles.push(role_id);
+        cache.cache_member(guild_id, member);
+        assert!(cache.role_members(role_id).is_some());
+
+        cache.update(&RoleDelete { guild_id, role_id });
+        assert!(cache.role_members(role_id).is_none());
+        assert!(cache
+            .member_index_keys
+            .get(&(guild_id, user_id))
+            .unwrap()
+            .roles
+            .is_empty());
+    }
+
+    #[test]
+    fn test_user_private_channel() {
+        let cache = indexed_cache();
+        let channel_id = ChannelId::new(1).expect("non zero");
+        let user_id = UserId::new(2).expect("non zero");
+
+        cache.cache_private_channel(PrivateChannel {
+            id: channel_id,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            kind: ChannelType::Private,
+            recipients: vec![test::user(user_id)],
+        });
+
+        assert_eq!(channel_id, cache.user_private_channel(user_id).unwrap().id);
+    }
+}
@@ -0,0 +1,404 @@
+//! Attribute members joining a guild to the invite they used.
+//!
+//! Discord doesn't say which invite a member joined with. Instead, the use
+//! counts of a guild's invites can be compared before and after a member joins:
+//! the invite whose use count increased is the one that was used.
+//!
+//! Use counts aren't sent over the gateway, so the tracker must be seeded with
+//! a guild's invites retrieved via [`Client::guild_invites`] and, if the guild
+//! has one, its vanity URL retrieved via [`Client::guild_vanity_url`]. When a
+//! member joins the invites are retrieved again and compared with the cached
+//! ones via [`InMemoryCacheInviteTracker::member_joined`].
+//!
+//! Invites created and deleted in the meantime are tracked by the cache via
+//! the [`InviteCreate`] and [`InviteDelete`] events, which requires the
+//! [`ResourceType::INVITE`] resource type.
+//!
+//! # Examples
+//!
+//! Seed the tracker when a guild becomes available and attribute joins:
+//!
+//! ```no_run
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use futures::stream::StreamExt;
+//! use std::env;
+//! use twilight_cache_inmemory::InMemoryCache;
+//! use twilight_gateway::{Event, Intents, Shard};
+//! use twilight_http::Client;
+//!
+//! let token = env::var("DISCORD_TOKEN")?;
+//! let http = Client::new(token.clone());
+//! let cache = InMemoryCache::new();
+//!
+//! let intents = Intents::GUILDS | Intents::GUILD_INVITES | Intents::GUILD_MEMBERS;
+//! let (shard, mut events) = Shard::new(token, intents);
+//! shard.start().await?;
+//!
+//! while let Some(event) = events.next().await {
+//!     cache.update(&event);
+//!
+//!     match event {
+//!         Event::GuildCreate(guild) => {
+//!             let invites = http.guild_invites(guild.id).exec().await?.model().await?;
+//!
+//!             cache.invite_tracker().seed(guild.id, invites, None);
+//!         }
+//!         Event::MemberAdd(member) => {
+//!             let invites = http.guild_invites(member.guild_id).exec().await?.model().await?;
+//!             let tracker = cache.invite_tracker();
+//!
+//!             if let Some(invite_use) = tracker.member_joined(member.guild_id, invites, None) {
+//!                 println!("{} joined with {}", member.user.name, invite_use.code());
+//!             }
+//!         }
+//!         _ => {}
+//!     }
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! [`Client::guild_invites`]: https://docs.rs/twilight-http/*/twilight_http/struct.Client.html#method.guild_invites
+//! [`Client::guild_vanity_url`]: https://docs.rs/twilight-http/*/twilight_http/struct.Client.html#method.guild_vanity_url
+//! [`InviteCreate`]: twilight_model::gateway::payload::incoming::InviteCreate
+//! [`InviteDelete`]: twilight_model::gateway::payload::incoming::InviteDelete
+//! [`ResourceType::INVITE`]: crate::ResourceType::INVITE
+
+use super::{model::CachedInvite, InMemoryCache};
+use std::cmp::Reverse;
+use twilight_model::{
+    guild::VanityUrl,
+    id::{GuildId, UserId},
+    invite::Invite,
+};
+
+/// Invite that a member most likely joined a guild with.
+///
+/// Returned by [`InMemoryCacheInviteTracker::member_joined`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InviteUse {
+    code: String,
+    confident: bool,
+    inviter_id: Option<UserId>,
+    vanity: bool,
+}
+
+impl InviteUse {
+    /// Code of the invite.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// ID of the user who created the invite, if known.
+    ///
+    /// This is always `None` for vanity URLs.
+    pub const fn inviter_id(&self) -> Option<UserId> {
+        self.inviter_id
+    }
+
+    /// Whether the use count of only one invite changed.
+    ///
+    /// If multiple members joined between retrievals of the guild's invites,
+    /// or an invite was used up and deleted, then the attribution is a guess:
+    /// an invite one use away from its maximum may also have been deleted by a
+    /// moderator.
+    pub const fn is_confident(&self) -> bool {
+        self.confident
+    }
+
+    /// Whether the invite is the guild's vanity URL.
+    pub const fn is_vanity(&self) -> bool {
+        self.vanity
+    }
+}
+
+/// Attribute members joining a guild to the invite they used.
+///
+/// Refer to the [module-level] documentation for more information.
+///
+/// [module-level]: crate::invite
+#[derive(Clone, Debug)]
+pub struct InMemoryCacheInviteTracker<'a>(&'a InMemoryCache);
+
+impl<'a> InMemoryCacheInviteTracker<'a> {
+    pub(super) const fn new(cache: &'a InMemoryCache) -> Self {
+        Self(cache)
+    }
+
+    /// Return an immutable reference to the underlying cache.
+    pub const fn cache_ref(&'a self) -> &'a InMemoryCache {
+        self.0
+    }
+
+    /// Consume the tracker, returning the underlying cache reference.
+    pub const fn into_cache(self) -> &'a InMemoryCache {
+        self.0
+    }
+
+    /// Replace the cached invites and vanity URL of a guild with retrieved
+    /// ones.
+    ///
+    /// Invites may be retrieved via [`Client::guild_invites`] and the vanity
+    /// URL via [`Client::guild_vanity_url`].
+    ///
+    /// [`Client::guild_invites`]: https://docs.rs/twilight-http/*/twilight_http/struct.Client.html#method.guild_invites
+    /// [`Client::guild_vanity_url`]: https://docs.rs/twilight-http/*/twilight_http/struct.Client.html#method.guild_vanity_url
+    pub fn seed(
+        &self,
+        guild_id: GuildId,
+        invites: impl IntoIterator<Item = Invite>,
+        vanity_url: Option<VanityUrl>,
+    ) {
+        self.0.cache_guild_invites(guild_id, invites);
+
+        if let Some(vanity_url) = vanity_url {
+            self.0.guild_vanity_urls.insert(guild_id, vanity_url);
+        } else {
+            self.0.guild_vanity_urls.remove(&guild_id);
+        }
+    }
+
+    /// Determine which invite a member joined a guild with by comparing
+    /// invites retrieved after the member joined with the cached ones.
+    ///
+    /// The retrieved invites then replace the cached ones, as with [`seed`].
+    ///
+    /// The invite whose use count increased the most is returned. If no use
+    /// count increased then an invite that was one use away from its maximum
+    /// and has since been deleted is returned, which the cache keeps after
+    /// processing its [`InviteDelete`] event until the next member joins.
+    /// Returns `None` if no invite could be attributed, such as when the guild
+    /// wasn't seeded.
+    ///
+    /// [`InviteDelete`]: twilight_model::gateway::payload::incoming::InviteDelete
+    /// [`seed`]: Self::seed
+    pub fn member_joined(
+        &self,
+        guild_id: GuildId,
+        invites: impl IntoIterator<Item = Invite>,
+        vanity_url: Option<VanityUrl>,
+    ) -> Option<InviteUse> {
+        let invites = invites.into_iter().collect::<Vec<_>>();
+
+        // Use counts that increased, along with the code, inviter, and whether
+        // the invite is a vanity URL.
+        let mut candidates = invites
+            .iter()
+            .filter_map(|invite| {
+                let uses = invite.uses.unwrap_or_default();
+                let cached_uses = self
+                    .0
+                    .invites
+                    .get(&invite.code)
+                    .map_or(0, |cached| cached.uses);
+
+                (uses > cached_uses).then(|| {
+                    (
+                        uses - cached_uses,
+                        invite.code.clone(),
+                        invite.inviter.as_ref().map(|user| user.id),
+                        false,
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(vanity_url) = &vanity_url {
+            let cached_uses = self
+                .0
+                .guild_vanity_urls
+                .get(&guild_id)
+                .map_or(0, |cached| cached.uses);
+
+            if vanity_url.uses > cached_uses {
+                candidates.push((
+                    vanity_url.uses - cached_uses,
+                    vanity_url.code.clone(),
+                    None,
+                    true,
+                ));
+            }
+        }
+
+        let confident = candidates.len() == 1;
+
+        // An invite reaching its maximum number of uses is deleted, so it's
+        // only present in the cache, or among the invites kept since being
+        // deleted.
+        if candidates.is_empty() {
+            let used_up = |cached: &CachedInvite| {
+                cached.max_uses > 0
+                    && cached.uses + 1 == cached.max_uses
+                    && !invites.iter().any(|invite| invite.code == cached.code)
+            };
+
+            if let Some(codes) = self.0.guild_invites.get(&guild_id) {
+                candidates.extend(
+                    codes
+                        .iter()
+                        .filter_map(|code| self.0.invites.get(code))
+                        .filter(|cached| used_up(cached))
+                        .map(|cached| (1, cached.code.clone(), cached.inviter_id, false)),
+                );
+            }
+
+            if let Some(deleted) = self.0.deleted_invites.get(&guild_id) {
+                candidates.extend(
+                    deleted
+                        .iter()
+                        .filter(|cached| used_up(cached))
+                        .map(|cached| (1, cached.code.clone(), cached.inviter_id, false)),
+                );
+            }
+        }
+
+        self.seed(guild_id, invites, vanity_url);
+
+        candidates.sort_by_key(|(increase, ..)| Reverse(*increase));
+
+        candidates
+            .into_iter()
+            .next()
+            .map(|(_, code, inviter_id, vanity)| InviteUse {
+                code,
+                confident,
+                inviter_id,
+                vanity,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InMemoryCacheInviteTracker, InviteUse};
+    use crate::{test, InMemoryCache};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        gateway::payload::incoming::{InviteDelete, MemberAdd},
+        guild::VanityUrl,
+        id::{ChannelId, GuildId, UserId},
+        invite::Invite,
+    };
+
+    assert_impl_all!(InMemoryCacheInviteTracker<'_>: Clone, Debug, Send, Sync);
+    assert_impl_all!(InviteUse: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    fn invite(code: &str, uses: u64, max_uses: u64) -> Invite {
+        Invite {
+            approximate_member_count: None,
+            approximate_presence_count: None,
+            channel: None,
+            code: code.to_owned(),
+            created_at: None,
+            expires_at: None,
+            guild: None,
+            inviter: None,
+            max_age: None,
+            max_uses: Some(max_uses),
+            stage_instance: None,
+            target_type: None,
+            target_user: None,
+            temporary: None,
+            uses: Some(uses),
+        }
+    }
+
+    fn vanity_url(uses: u64) -> Option<VanityUrl> {
+        Some(VanityUrl {
+            code: "twilight".to_owned(),
+            uses,
+        })
+    }
+
+    #[test]
+    fn test_member_joined() {
+        let cache = InMemoryCache::new();
+        let tracker = cache.invite_tracker();
+        let guild_id = GuildId::new(1).expect("non zero");
+
+        tracker.seed(
+            guild_id,
+            vec![invite("a", 1, 0), invite("b", 3, 0)],
+            vanity_url(5),
+        );
+        assert_eq!(2, cache.guild_invites(guild_id).unwrap().len());
+
+        let used = tracker
+            .member_joined(
+                guild_id,
+                vec![invite("a", 1, 0), invite("b", 4, 0)],
+                vanity_url(5),
+            )
+            .unwrap();
+        assert_eq!("b", used.code());
+        assert!(used.is_confident());
+        assert!(!used.is_vanity());
+        assert_eq!(4, cache.invite("b").unwrap().uses());
+
+        let used = tracker
+            .member_joined(
+                guild_id,
+                vec![invite("a", 1, 0), invite("b", 4, 0)],
+                vanity_url(6),
+            )
+            .unwrap();
+        assert_eq!("twilight", used.code());
+        assert!(used.is_vanity());
+        assert_eq!(6, cache.guild_vanity_url(guild_id).unwrap().uses);
+
+        assert!(tracker
+            .member_joined(
+                guild_id,
+                vec![invite("a", 1, 0), invite("b", 4, 0)],
+                vanity_url(6),
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn test_member_joined_used_up() {
+        let cache = InMemoryCache::new();
+        let tracker = cache.invite_tracker();
+        let guild_id = GuildId::new(1).expect("non zero");
+
+        tracker.seed(guild_id, vec![invite("a", 1, 0), invite("b", 1, 2)], None);
+
+        let used = tracker
+            .member_joined(guild_id, vec![invite("a", 1, 0)], None)
+            .unwrap();
+        assert_eq!("b", used.code());
+        assert!(!used.is_confident());
+        assert!(cache.invite("b").is_none());
+    }
+
+    #[test]
+    fn test_member_joined_used_up_deleted() {
+        let cache = InMemoryCache::new();
+        let tracker = cache.invite_tracker();
+        let guild_id = GuildId::new(1).expect("non zero");
+
+        tracker.seed(guild_id, vec![invite("a", 1, 0), invite("b", 1, 2)], None);
+
+        // Discord deletes the used up invite before adding the member.
+        cache.update(&InviteDelete {
+            channel_id: ChannelId::new(2).expect("non zero"),
+            code: "b".to_owned(),
+            guild_id,
+        });
+        assert!(cache.invite("b").is_none());
+
+        let member = MemberAdd(test::member(UserId::new(3).expect("non zero"), guild_id));
+        cache.update(&member);
+
+        let used = tracker
+            .member_joined(member.guild_id, vec![invite("a", 1, 0)], None)
+            .unwrap();
+        assert_eq!("b", used.code());
+        assert!(!used.is_confident());
+
+        // Deleted invites are only kept until the next member joins.
+        assert!(tracker
+            .member_joined(guild_id, vec![invite("a", 1, 0)], None)
+            .is_none());
+    }
+}
@@ -10,6 +10,15 @@
 //!
 //! By default no feature is enabled.
 //!
+//! ### `auditor`
+//!
+//! The `auditor` feature flag will bring in support for the
+//! `InMemoryCacheAuditor`, which compares the cached state of a guild to its
+//! state retrieved via `twilight-http` and optionally repairs the cache. It is
+//! exposed via `InMemoryCache::auditor`.
+//!
+//! Refer to the `auditor` module for more documentation.
+//!
 //! ### `permission-calculator`
 //!
 //! The `permission-calculator` feature flag will bring in support for the
@@ -20,6 +29,14 @@
 //!
 //! Refer to the `permission` module for more documentation.
 //!
+//! ### `resolver`
+//!
+//! The `resolver` feature flag will bring in support for the
+//! `InMemoryCacheResolver`, which resolves resources from the cache and falls
+//! back to retrieving them via `twilight-http` if they aren't cached.
+//!
+//! Refer to the `resolver` module for more documentation.
+//!
 //! ## Examples
 //!
 //! Update a cache with events that come in through the gateway:
@@ -72,6 +89,10 @@
     warnings
 )]
 
+#[cfg(feature = "auditor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "auditor")))]
+pub mod auditor;
+pub mod invite;
 pub mod iter;
 pub mod model;
 
@@ -79,9 +100,15 @@ pub mod model;
 #[cfg_attr(docsrs, doc(cfg(feature = "permission-calculator")))]
 pub mod permission;
 
+#[cfg(feature = "resolver")]
+#[cfg_attr(docsrs, doc(cfg(feature = "resolver")))]
+pub mod resolver;
+
 mod builder;
 mod config;
 mod event;
+mod index;
+mod size;
 mod stats;
 
 #[cfg(test)]
@@ -93,26 +120,39 @@ pub use self::{
     stats::InMemoryCacheStats,
 };
 
+#[cfg(feature = "auditor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "auditor")))]
+pub use self::auditor::InMemoryCacheAuditor;
+
 #[cfg(feature = "permission-calculator")]
 #[cfg_attr(docsrs, doc(cfg(feature = "permission-calculator")))]
 pub use self::permission::InMemoryCachePermissions;
 
-use self::{iter::InMemoryCacheIter, model::*};
+#[cfg(feature = "resolver")]
+#[cfg_attr(docsrs, doc(cfg(feature = "resolver")))]
+pub use self::resolver::InMemoryCacheResolver;
+
+use self::{
+    config::GuildFilter, index::MemberIndexKeys, invite::InMemoryCacheInviteTracker,
+    iter::InMemoryCacheIter, model::*,
+};
 use dashmap::{
     mapref::{entry::Entry, one::Ref},
     DashMap, DashSet,
 };
 use std::{
-    collections::{BTreeSet, HashSet, VecDeque},
+    collections::{BTreeMap, BTreeSet, HashSet, VecDeque},
     fmt::{Debug, Formatter, Result as FmtResult},
     hash::Hash,
     ops::Deref,
     sync::Mutex,
+    time::Instant,
 };
 use twilight_model::{
     channel::{message::sticker::StickerId, Group, GuildChannel, PrivateChannel, StageInstance},
+    datetime::Timestamp,
     gateway::event::Event,
-    guild::{GuildIntegration, Role},
+    guild::{GuildIntegration, Role, VanityUrl},
     id::{ChannelId, EmojiId, GuildId, IntegrationId, MessageId, RoleId, StageId, UserId},
     user::{CurrentUser, User},
     voice::VoiceState,
@@ -245,32 +285,70 @@ fn upsert_item<K: Eq + Hash, V: PartialEq>(map: &DashMap<K, V>, k: K, v: V) {
 #[derive(Debug, Default)]
 pub struct InMemoryCache {
     config: Config,
+    guild_filter: GuildFilter,
     channels_guild: DashMap<ChannelId, GuildResource<GuildChannel>>,
     channels_private: DashMap<ChannelId, PrivateChannel>,
     channel_messages: DashMap<ChannelId, VecDeque<MessageId>>,
+    /// Mapping of channels and the threads created in them.
+    channel_threads: DashMap<ChannelId, HashSet<ChannelId>>,
     // So long as the lock isn't held across await or panic points this is fine.
     current_user: Mutex<Option<CurrentUser>>,
+    /// Mapping of guilds and their invites that were deleted one use away
+    /// from their maximum since the invite tracker last retrieved them.
+    deleted_invites: DashMap<GuildId, Vec<CachedInvite>>,
     emojis: DashMap<EmojiId, GuildResource<CachedEmoji>>,
     groups: DashMap<ChannelId, Group>,
     guilds: DashMap<GuildId, CachedGuild>,
     guild_channels: DashMap<GuildId, HashSet<ChannelId>>,
     guild_emojis: DashMap<GuildId, HashSet<EmojiId>>,
+    /// Mapping of guilds and their threads that aren't archived.
+    guild_active_threads: DashMap<GuildId, HashSet<ChannelId>>,
     guild_integrations: DashMap<GuildId, HashSet<IntegrationId>>,
+    guild_invites: DashMap<GuildId, HashSet<String>>,
     guild_members: DashMap<GuildId, HashSet<UserId>>,
+    /// Mapping of guilds and the lowercased usernames and nicknames of their
+    /// members, if secondary indexes are enabled.
+    guild_member_names: DashMap<GuildId, BTreeMap<String, HashSet<UserId>>>,
     guild_presences: DashMap<GuildId, HashSet<UserId>>,
     guild_roles: DashMap<GuildId, HashSet<RoleId>>,
     guild_stage_instances: DashMap<GuildId, HashSet<StageId>>,
     guild_stickers: DashMap<GuildId, HashSet<StickerId>>,
+    guild_vanity_urls: DashMap<GuildId, VanityUrl>,
     integrations: DashMap<(GuildId, IntegrationId), GuildResource<GuildIntegration>>,
+    invites: DashMap<String, CachedInvite>,
     members: DashMap<(GuildId, UserId), CachedMember>,
+    /// Mapping of guild ID and user ID pairs to the keys their members are
+    /// indexed under, if secondary indexes are enabled.
+    member_index_keys: DashMap<(GuildId, UserId), MemberIndexKeys>,
+    /// Mapping of messages and their previous revisions, if revisions are
+    /// kept.
+    message_revisions: DashMap<MessageId, VecDeque<CachedMessageRevision>>,
+    /// Mapping of deleted messages and their tombstones, if tombstones are
+    /// kept.
+    message_tombstones: DashMap<MessageId, MessageTombstone>,
+    /// Deletion times and IDs of tombstoned messages, oldest first.
+    ///
+    /// Entries of tombstones that were since replaced or removed are skipped
+    /// when popped.
+    message_tombstone_order: Mutex<VecDeque<(Instant, MessageId)>>,
     messages: DashMap<MessageId, CachedMessage>,
     presences: DashMap<(GuildId, UserId), CachedPresence>,
+    /// Mapping of roles and the members that have them, if secondary indexes
+    /// are enabled.
+    role_members: DashMap<RoleId, HashSet<UserId>>,
     roles: DashMap<RoleId, GuildResource<Role>>,
     stage_instances: DashMap<StageId, GuildResource<StageInstance>>,
     stickers: DashMap<StickerId, GuildResource<CachedSticker>>,
+    /// Mapping of threads and the users that are members of them.
+    thread_members: DashMap<ChannelId, HashSet<UserId>>,
+    /// Mapping of thread ID and user ID pairs to their memberships.
+    thread_memberships: DashMap<(ChannelId, UserId), CachedThreadMember>,
     unavailable_guilds: DashSet<GuildId>,
     users: DashMap<UserId, User>,
     user_guilds: DashMap<UserId, BTreeSet<GuildId>>,
+    /// Mapping of users and their private channel with the current user, if
+    /// secondary indexes are enabled.
+    user_private_channels: DashMap<UserId, ChannelId>,
     /// Mapping of channels and the users currently connected.
     voice_state_channels: DashMap<ChannelId, HashSet<(GuildId, UserId)>>,
     /// Mapping of guilds and users currently connected to its voice channels.
@@ -309,34 +387,91 @@ impl InMemoryCache {
         self.channels_guild.clear();
         self.channels_private.clear();
         self.channel_messages.clear();
+        self.channel_threads.clear();
         self.current_user
             .lock()
             .expect("current user poisoned")
             .take();
+        self.deleted_invites.clear();
         self.emojis.clear();
         self.groups.clear();
         self.guilds.clear();
         self.guild_channels.clear();
         self.guild_emojis.clear();
+        self.guild_active_threads.clear();
         self.guild_integrations.clear();
+        self.guild_invites.clear();
         self.guild_members.clear();
+        self.guild_member_names.clear();
         self.guild_presences.clear();
         self.guild_roles.clear();
         self.guild_stage_instances.clear();
         self.guild_stickers.clear();
+        self.guild_vanity_urls.clear();
         self.integrations.clear();
+        self.invites.clear();
         self.members.clear();
+        self.member_index_keys.clear();
+        self.message_revisions.clear();
+        self.message_tombstones.clear();
+        self.message_tombstone_order
+            .lock()
+            .expect("message tombstone order poisoned")
+            .clear();
         self.messages.clear();
         self.presences.clear();
+        self.role_members.clear();
         self.roles.clear();
         self.stickers.clear();
+        self.thread_members.clear();
+        self.thread_memberships.clear();
         self.unavailable_guilds.clear();
         self.users.clear();
+        self.user_private_channels.clear();
         self.voice_state_channels.clear();
         self.voice_state_guilds.clear();
         self.voice_states.clear();
     }
 
+    /// Remove the resources of a guild with the given resource types.
+    ///
+    /// Resource types that aren't scoped to guilds, such as
+    /// [`ResourceType::USER`], are ignored. Purged resources are cached again
+    /// as events for them are received, unless they are filtered out via
+    /// [`InMemoryCacheBuilder::guild_filter`].
+    ///
+    /// # Examples
+    ///
+    /// Remove the members and messages of a guild:
+    ///
+    /// ```
+    /// use twilight_cache_inmemory::{InMemoryCache, ResourceType};
+    /// use twilight_model::id::GuildId;
+    ///
+    /// let cache = InMemoryCache::new();
+    /// let guild_id = GuildId::new(1).expect("non zero");
+    ///
+    /// cache.purge_guild(guild_id, ResourceType::MEMBER | ResourceType::MESSAGE);
+    /// ```
+    pub fn purge_guild(&self, guild_id: GuildId, resource_types: ResourceType) {
+        self.delete_guild_resources(guild_id, resource_types);
+    }
+
+    /// Evaluate the guild filter for a guild again, removing the resources
+    /// it now rejects.
+    ///
+    /// Refer to [`InMemoryCacheBuilder::guild_filter`] for more information.
+    pub fn refilter_guild(&self, guild_id: GuildId) {
+        let rejected = (0..u64::BITS)
+            .filter_map(|bit| ResourceType::from_bits(1 << bit))
+            .filter(|resource_type| !self.guild_filter.allows(guild_id, *resource_type))
+            .fold(ResourceType::empty(), |rejected, resource_type| {
+                rejected | resource_type
+            });
+
+        self.delete_guild_resources(guild_id, rejected);
+    }
+
     /// Returns a copy of the config cache.
     pub const fn config(&self) -> &Config {
         &self.config
@@ -385,6 +520,29 @@ impl InMemoryCache {
         InMemoryCacheStats::new(self)
     }
 
+    /// Create an interface for attributing members joining a guild to the
+    /// invite they used.
+    ///
+    /// Refer to the [`invite`] module-level documentation for more
+    /// information.
+    pub const fn invite_tracker(&self) -> InMemoryCacheInviteTracker<'_> {
+        InMemoryCacheInviteTracker::new(self)
+    }
+
+    /// Create an auditor for comparing the cached state of guilds to their
+    /// state retrieved via an HTTP client.
+    ///
+    /// Refer to the [`auditor`] module-level documentation for more
+    /// information.
+    #[cfg(feature = "auditor")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auditor")))]
+    pub const fn auditor<'a>(
+        &'a self,
+        http: &'a twilight_http::Client,
+    ) -> InMemoryCacheAuditor<'a> {
+        InMemoryCacheAuditor::new(self, http)
+    }
+
     /// Create an interface for retrieving the permissions of a member in a
     /// guild or channel.
     ///
@@ -435,6 +593,69 @@ impl InMemoryCache {
             .clone()
     }
 
+    /// Gets the set of threads in a guild that aren't archived.
+    ///
+    /// Archived threads are removed when the cache receives an update archiving
+    /// them or a thread list sync that no longer includes them.
+    ///
+    /// This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn active_threads(
+        &self,
+        guild_id: GuildId,
+    ) -> Option<Reference<'_, GuildId, HashSet<ChannelId>>> {
+        self.guild_active_threads.get(&guild_id).map(Reference::new)
+    }
+
+    /// Gets the current user's membership of a thread.
+    ///
+    /// This requires the [`GUILDS`] intent and the
+    /// [`ResourceType::THREAD_MEMBER`] resource type.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn current_user_thread_member(
+        &self,
+        channel_id: ChannelId,
+    ) -> Option<Reference<'_, (ChannelId, UserId), CachedThreadMember>> {
+        let user_id = self.current_user()?.id;
+
+        self.thread_member(channel_id, user_id)
+    }
+
+    /// Gets the tombstone of a deleted message by ID.
+    ///
+    /// This requires tombstones to be kept via
+    /// [`InMemoryCacheBuilder::deleted_message_retention`] and returns `None`
+    /// otherwise, or if the tombstone has expired.
+    ///
+    /// This requires one or both of the [`GUILD_MESSAGES`] or
+    /// [`DIRECT_MESSAGES`] intents.
+    ///
+    /// [`GUILD_MESSAGES`]: ::twilight_model::gateway::Intents::GUILD_MESSAGES
+    /// [`DIRECT_MESSAGES`]: ::twilight_model::gateway::Intents::DIRECT_MESSAGES
+    pub fn deleted_message(
+        &self,
+        message_id: MessageId,
+    ) -> Option<Reference<'_, MessageId, MessageTombstone>> {
+        let retention = self.config.deleted_message_retention()?;
+        let tombstone = self.message_tombstones.get(&message_id)?;
+
+        if tombstone.deleted_at.elapsed() < retention {
+            return Some(Reference::new(tombstone));
+        }
+
+        // The read lock on the tombstone's shard has to be released before
+        // the expired tombstone can be removed.
+        drop(tombstone);
+        self.message_tombstones
+            .remove_if(&message_id, |_, tombstone| {
+                tombstone.deleted_at.elapsed() >= retention
+            });
+
+        None
+    }
+
     /// Gets an emoji by ID.
     ///
     /// This requires the [`GUILD_EMOJIS`] intent.
@@ -511,6 +732,23 @@ impl InMemoryCache {
         self.guild_integrations.get(&guild_id).map(Reference::new)
     }
 
+    /// Gets the set of codes of the invites to a guild.
+    ///
+    /// This requires the [`GUILD_INVITES`] intent. The
+    /// [`ResourceType::INVITE`] resource type must be enabled.
+    ///
+    /// Invites created before the cache started are only known once the
+    /// [invite tracker] is seeded with them.
+    ///
+    /// [`GUILD_INVITES`]: twilight_model::gateway::Intents::GUILD_INVITES
+    /// [invite tracker]: Self::invite_tracker
+    pub fn guild_invites(
+        &self,
+        guild_id: GuildId,
+    ) -> Option<Reference<'_, GuildId, HashSet<String>>> {
+        self.guild_invites.get(&guild_id).map(Reference::new)
+    }
+
     /// Gets the set of members in a guild.
     ///
     /// This list may be incomplete if not all members have been cached.
@@ -580,6 +818,15 @@ impl InMemoryCache {
         self.guild_stickers.get(&guild_id).map(Reference::new)
     }
 
+    /// Gets the vanity URL of a guild.
+    ///
+    /// This is only known once the [invite tracker] is seeded with it.
+    ///
+    /// [invite tracker]: Self::invite_tracker
+    pub fn guild_vanity_url(&self, guild_id: GuildId) -> Option<Reference<'_, GuildId, VanityUrl>> {
+        self.guild_vanity_urls.get(&guild_id).map(Reference::new)
+    }
+
     /// Gets an integration by guild ID and integration ID.
     ///
     /// This requires the [`GUILD_INTEGRATIONS`] intent. The
@@ -596,6 +843,16 @@ impl InMemoryCache {
             .map(Reference::new)
     }
 
+    /// Gets an invite by code.
+    ///
+    /// This requires the [`GUILD_INVITES`] intent. The
+    /// [`ResourceType::INVITE`] resource type must be enabled.
+    ///
+    /// [`GUILD_INVITES`]: twilight_model::gateway::Intents::GUILD_INVITES
+    pub fn invite(&self, code: &str) -> Option<Reference<'_, String, CachedInvite>> {
+        self.invites.get(code).map(Reference::new)
+    }
+
     /// Gets a member by guild ID and user ID.
     ///
     /// This requires the [`GUILD_MEMBERS`] intent.
@@ -609,6 +866,42 @@ impl InMemoryCache {
         self.members.get(&(guild_id, user_id)).map(Reference::new)
     }
 
+    /// Gets the IDs of a guild's members whose username or nickname starts
+    /// with a prefix, ignoring case.
+    ///
+    /// Members are ordered by their matching name. This requires secondary
+    /// indexes to be enabled via [`InMemoryCacheBuilder::secondary_indexes`]
+    /// and returns no members otherwise.
+    ///
+    /// This requires the [`GUILD_MEMBERS`] intent.
+    ///
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn members_by_name_prefix(&self, guild_id: GuildId, prefix: &str) -> Vec<UserId> {
+        let guild_names = match self.guild_member_names.get(&guild_id) {
+            Some(guild_names) => guild_names,
+            None => return Vec::new(),
+        };
+
+        let prefix = prefix.to_lowercase();
+        let mut seen = HashSet::new();
+        let mut user_ids = Vec::new();
+
+        // A member whose username and nickname both match is only returned
+        // under the first of them.
+        for (_, members) in guild_names
+            .range(prefix.clone()..)
+            .take_while(|(name, _)| name.starts_with(&prefix))
+        {
+            for user_id in members {
+                if seen.insert(*user_id) {
+                    user_ids.push(*user_id);
+                }
+            }
+        }
+
+        user_ids
+    }
+
     /// Gets a message by ID.
     ///
     /// This requires one or both of the [`GUILD_MESSAGES`] or
@@ -623,6 +916,18 @@ impl InMemoryCache {
         self.messages.get(&message_id).map(Reference::new)
     }
 
+    /// Gets the previous revisions of a cached message, oldest first.
+    ///
+    /// This requires revisions to be kept via
+    /// [`InMemoryCacheBuilder::message_revision_limit`] and returns `None`
+    /// otherwise, or if the message hasn't been edited.
+    pub fn message_revisions(
+        &self,
+        message_id: MessageId,
+    ) -> Option<Reference<'_, MessageId, VecDeque<CachedMessageRevision>>> {
+        self.message_revisions.get(&message_id).map(Reference::new)
+    }
+
     /// Gets a presence by, optionally, guild ID, and user ID.
     ///
     /// This requires the [`GUILD_PRESENCES`] intent.
@@ -657,6 +962,19 @@ impl InMemoryCache {
         self.roles.get(&role_id).map(Reference::new)
     }
 
+    /// Gets the set of members that have a role.
+    ///
+    /// This requires secondary indexes to be enabled via
+    /// [`InMemoryCacheBuilder::secondary_indexes`] and returns `None`
+    /// otherwise.
+    ///
+    /// This requires the [`GUILD_MEMBERS`] intent.
+    ///
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn role_members(&self, role_id: RoleId) -> Option<Reference<'_, RoleId, HashSet<UserId>>> {
+        self.role_members.get(&role_id).map(Reference::new)
+    }
+
     /// Gets a stage instance by ID.
     ///
     /// This requires the [`GUILDS`] intent.
@@ -683,6 +1001,75 @@ impl InMemoryCache {
         self.stickers.get(&sticker_id).map(Reference::new)
     }
 
+    /// Gets when a thread is expected to be automatically archived.
+    ///
+    /// This is estimated from when the thread's archive status last changed and
+    /// its auto archive duration; activity in the thread postpones archival.
+    /// Returns `None` if the channel isn't a cached thread or is already
+    /// archived.
+    ///
+    /// This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn thread_auto_archive_at(&self, channel_id: ChannelId) -> Option<Timestamp> {
+        let channel = self.channels_guild.get(&channel_id)?;
+        let (_, metadata, _) = event::thread::thread_parts(channel.resource())?;
+
+        if metadata.archived {
+            return None;
+        }
+
+        let duration = i64::from(metadata.auto_archive_duration.number()) * 60 * 1_000_000;
+
+        Timestamp::from_micros(metadata.archive_timestamp.as_micros() + duration).ok()
+    }
+
+    /// Gets a member of a thread by thread ID and user ID.
+    ///
+    /// Members of threads are only known once the gateway sends them, so this
+    /// may be incomplete. The current user's memberships are always sent.
+    ///
+    /// This requires the [`GUILDS`] intent and the
+    /// [`ResourceType::THREAD_MEMBER`] resource type. The [`GUILD_MEMBERS`]
+    /// intent is required to receive the memberships of other users.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn thread_member(
+        &self,
+        channel_id: ChannelId,
+        user_id: UserId,
+    ) -> Option<Reference<'_, (ChannelId, UserId), CachedThreadMember>> {
+        self.thread_memberships
+            .get(&(channel_id, user_id))
+            .map(Reference::new)
+    }
+
+    /// Gets the set of members of a thread.
+    ///
+    /// Refer to [`thread_member`] for the requirements.
+    ///
+    /// [`thread_member`]: Self::thread_member
+    pub fn thread_members(
+        &self,
+        channel_id: ChannelId,
+    ) -> Option<Reference<'_, ChannelId, HashSet<UserId>>> {
+        self.thread_members.get(&channel_id).map(Reference::new)
+    }
+
+    /// Gets the set of threads created in a channel, including archived
+    /// threads that have been cached.
+    ///
+    /// This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn threads_in_channel(
+        &self,
+        parent_id: ChannelId,
+    ) -> Option<Reference<'_, ChannelId, HashSet<ChannelId>>> {
+        self.channel_threads.get(&parent_id).map(Reference::new)
+    }
+
     /// Gets a user by ID.
     ///
     /// This requires the [`GUILD_MEMBERS`] intent.
@@ -692,6 +1079,24 @@ impl InMemoryCache {
         self.users.get(&user_id).map(Reference::new)
     }
 
+    /// Gets the private channel between a user and the current user.
+    ///
+    /// This requires secondary indexes to be enabled via
+    /// [`InMemoryCacheBuilder::secondary_indexes`] and returns `None`
+    /// otherwise.
+    ///
+    /// This requires the [`DIRECT_MESSAGES`] intent.
+    ///
+    /// [`DIRECT_MESSAGES`]: ::twilight_model::gateway::Intents::DIRECT_MESSAGES
+    pub fn user_private_channel(
+        &self,
+        user_id: UserId,
+    ) -> Option<Reference<'_, ChannelId, PrivateChannel>> {
+        let channel_id = *self.user_private_channels.get(&user_id)?;
+
+        self.channels_private.get(&channel_id).map(Reference::new)
+    }
+
     /// Gets the voice states within a voice channel.
     ///
     /// This requires both the [`GUILDS`] and [`GUILD_VOICE_STATES`] intents.
@@ -753,9 +1158,10 @@ impl InMemoryCache {
         highest_role.map(|(_, id)| id)
     }
 
-    fn new_with_config(config: Config) -> Self {
+    fn new_with_config(config: Config, guild_filter: GuildFilter) -> Self {
         Self {
             config,
+            guild_filter,
             ..Default::default()
         }
     }
@@ -765,6 +1171,15 @@ impl InMemoryCache {
     const fn wants(&self, resource_type: ResourceType) -> bool {
         self.config.resource_types().contains(resource_type)
     }
+
+    /// Determine whether the configured cache wants a specific resource to be
+    /// processed in a guild, if the resource belongs to one.
+    fn wants_in(&self, guild_id: Option<GuildId>, resource_type: ResourceType) -> bool {
+        self.wants(resource_type)
+            && guild_id.map_or(true, |guild_id| {
+                self.guild_filter.allows(guild_id, resource_type)
+            })
+    }
 }
 
 /// Implemented for dispatch events.
@@ -825,8 +1240,8 @@ impl UpdateCache for Event {
             IntegrationDelete(v) => c.update(v.deref()),
             IntegrationUpdate(v) => c.update(v.deref()),
             InteractionCreate(v) => c.update(v.deref()),
-            InviteCreate(_) => {}
-            InviteDelete(_) => {}
+            InviteCreate(v) => c.update(v.deref()),
+            InviteDelete(v) => c.update(v),
             MemberAdd(v) => c.update(v.deref()),
             MemberRemove(v) => c.update(v),
             MemberUpdate(v) => c.update(v.deref()),
@@ -848,7 +1263,9 @@ impl UpdateCache for Event {
             RoleUpdate(v) => c.update(v),
             ShardConnected(_) => {}
             ShardConnecting(_) => {}
+            ShardDead(_) => {}
             ShardDisconnected(_) => {}
+            ShardGuildsReady(_) => {}
             ShardIdentifying(_) => {}
             ShardReconnecting(_) => {}
             ShardPayload(_) => {}
@@ -860,8 +1277,8 @@ impl UpdateCache for Event {
             ThreadUpdate(v) => c.update(v),
             ThreadDelete(v) => c.update(v),
             ThreadListSync(v) => c.update(v),
-            ThreadMemberUpdate(_) => {}
-            ThreadMembersUpdate(_) => {}
+            ThreadMemberUpdate(v) => c.update(v),
+            ThreadMembersUpdate(v) => c.update(v),
             TypingStart(_) => {}
             UnavailableGuild(v) => c.update(v),
             UserUpdate(v) => c.update(v),
@@ -874,12 +1291,16 @@ impl UpdateCache for Event {
 
 #[cfg(test)]
 mod tests {
-    use crate::{test, InMemoryCache};
+    use crate::{test, InMemoryCache, ResourceType};
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
     use twilight_model::{
         datetime::Timestamp,
-        gateway::payload::incoming::RoleDelete,
+        gateway::payload::incoming::{MemberAdd, MessageCreate, RoleCreate, RoleDelete},
         guild::{Member, Permissions, Role},
-        id::{EmojiId, GuildId, RoleId, UserId},
+        id::{ChannelId, EmojiId, GuildId, MessageId, RoleId, UserId},
     };
 
     #[test]
@@ -910,6 +1331,53 @@ mod tests {
         assert!(cache.members.is_empty());
     }
 
+    #[test]
+    fn test_guild_filter() {
+        let premium = Arc::new(AtomicBool::new(false));
+        let premium_ref = Arc::clone(&premium);
+        let premium_id = GuildId::new(1).expect("non zero");
+        let other_id = GuildId::new(2).expect("non zero");
+
+        let cache = InMemoryCache::builder()
+            .guild_filter(move |guild_id, resource_type| {
+                !resource_type.intersects(ResourceType::MEMBER | ResourceType::MESSAGE)
+                    || (guild_id == premium_id && premium_ref.load(Ordering::Relaxed))
+            })
+            .build();
+        premium.store(true, Ordering::Relaxed);
+
+        for guild_id in [premium_id, other_id] {
+            let user_id = UserId::new(guild_id.get() + 10).expect("non zero");
+            cache.update(&MemberAdd(test::member(user_id, guild_id)));
+            cache.update(&RoleCreate {
+                guild_id,
+                role: test::role(RoleId::new(guild_id.get()).expect("non zero")),
+            });
+
+            let mut message = test::message(
+                MessageId::new(guild_id.get()).expect("non zero"),
+                ChannelId::new(guild_id.get()).expect("non zero"),
+            );
+            message.guild_id = Some(guild_id);
+            cache.update(&MessageCreate(message));
+        }
+
+        assert_eq!(1, cache.members.len());
+        assert_eq!(1, cache.messages.len());
+        assert_eq!(2, cache.roles.len());
+        assert!(cache.guild_members(premium_id).is_some());
+
+        premium.store(false, Ordering::Relaxed);
+        cache.refilter_guild(premium_id);
+        assert!(cache.members.is_empty());
+        assert!(cache.messages.is_empty());
+        assert_eq!(2, cache.roles.len());
+
+        cache.purge_guild(other_id, ResourceType::ROLE);
+        assert!(cache.role(RoleId::new(2).expect("non zero")).is_none());
+        assert!(cache.role(RoleId::new(1).expect("non zero")).is_some());
+    }
+
     #[test]
     fn test_highest_role() {
         let joined_at = Timestamp::from_secs(1_632_072_645).expect("non zero");
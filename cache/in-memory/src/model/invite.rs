@@ -0,0 +1,68 @@
+use serde::Serialize;
+use twilight_model::{
+    datetime::Timestamp,
+    id::{ChannelId, GuildId, UserId},
+};
+
+/// Represents a cached [`Invite`].
+///
+/// [`Invite`]: twilight_model::invite::Invite
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct CachedInvite {
+    pub(crate) channel_id: Option<ChannelId>,
+    pub(crate) code: String,
+    pub(crate) created_at: Option<Timestamp>,
+    pub(crate) guild_id: GuildId,
+    pub(crate) inviter_id: Option<UserId>,
+    pub(crate) max_age: u64,
+    pub(crate) max_uses: u64,
+    pub(crate) temporary: bool,
+    pub(crate) uses: u64,
+}
+
+impl CachedInvite {
+    /// ID of the channel the invite is for.
+    pub const fn channel_id(&self) -> Option<ChannelId> {
+        self.channel_id
+    }
+
+    /// Code of the invite.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// [`Timestamp`] of when the invite was created.
+    pub const fn created_at(&self) -> Option<Timestamp> {
+        self.created_at
+    }
+
+    /// ID of the guild the invite is for.
+    pub const fn guild_id(&self) -> GuildId {
+        self.guild_id
+    }
+
+    /// ID of the user who created the invite.
+    pub const fn inviter_id(&self) -> Option<UserId> {
+        self.inviter_id
+    }
+
+    /// Number of seconds the invite is valid for, or 0 if it never expires.
+    pub const fn max_age(&self) -> u64 {
+        self.max_age
+    }
+
+    /// Maximum number of times the invite can be used, or 0 if unlimited.
+    pub const fn max_uses(&self) -> u64 {
+        self.max_uses
+    }
+
+    /// Whether the invite only grants temporary membership.
+    pub const fn temporary(&self) -> bool {
+        self.temporary
+    }
+
+    /// Number of times the invite has been used.
+    pub const fn uses(&self) -> u64 {
+        self.uses
+    }
+}
@@ -0,0 +1,106 @@
+use super::CachedMessage;
+use serde::Serialize;
+use std::{collections::VecDeque, time::Instant};
+use twilight_model::{
+    channel::{embed::Embed, Attachment},
+    datetime::Timestamp,
+    id::{ChannelId, MessageId},
+};
+
+/// Revision of a cached message before it was edited.
+///
+/// Revisions are only kept if enabled via
+/// [`InMemoryCacheBuilder::message_revision_limit`].
+///
+/// [`InMemoryCacheBuilder::message_revision_limit`]: crate::InMemoryCacheBuilder::message_revision_limit
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct CachedMessageRevision {
+    pub(crate) attachments: Vec<Attachment>,
+    pub(crate) content: String,
+    pub(crate) edited_timestamp: Option<Timestamp>,
+    pub(crate) embeds: Vec<Embed>,
+}
+
+impl CachedMessageRevision {
+    /// Attached files of the revision.
+    pub fn attachments(&self) -> &[Attachment] {
+        &self.attachments
+    }
+
+    /// Content of the revision.
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// [`Timestamp`] of when the revision was created by an edit.
+    ///
+    /// This is `None` for the message's original revision.
+    pub const fn edited_timestamp(&self) -> Option<Timestamp> {
+        self.edited_timestamp
+    }
+
+    /// Embeds of the revision.
+    pub fn embeds(&self) -> &[Embed] {
+        &self.embeds
+    }
+}
+
+impl From<&CachedMessage> for CachedMessageRevision {
+    fn from(message: &CachedMessage) -> Self {
+        Self {
+            attachments: message.attachments.clone(),
+            content: message.content.clone(),
+            edited_timestamp: message.edited_timestamp,
+            embeds: message.embeds.clone(),
+        }
+    }
+}
+
+/// Record of a deleted message.
+///
+/// Tombstones are only kept if enabled via
+/// [`InMemoryCacheBuilder::deleted_message_retention`].
+///
+/// [`InMemoryCacheBuilder::deleted_message_retention`]: crate::InMemoryCacheBuilder::deleted_message_retention
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct MessageTombstone {
+    pub(crate) bulk: bool,
+    pub(crate) channel_id: ChannelId,
+    #[serde(skip)]
+    pub(crate) deleted_at: Instant,
+    pub(crate) id: MessageId,
+    pub(crate) message: Option<CachedMessage>,
+    pub(crate) revisions: VecDeque<CachedMessageRevision>,
+}
+
+impl MessageTombstone {
+    /// ID of the channel the message was deleted from.
+    pub const fn channel_id(&self) -> ChannelId {
+        self.channel_id
+    }
+
+    /// [`Instant`] of when the deletion was processed by the cache.
+    pub const fn deleted_at(&self) -> Instant {
+        self.deleted_at
+    }
+
+    /// ID of the deleted message.
+    pub const fn id(&self) -> MessageId {
+        self.id
+    }
+
+    /// Whether the message was deleted as part of a bulk deletion.
+    pub const fn is_bulk(&self) -> bool {
+        self.bulk
+    }
+
+    /// Last known state of the message, if it was cached when it was deleted.
+    pub const fn message(&self) -> Option<&CachedMessage> {
+        self.message.as_ref()
+    }
+
+    /// Revisions of the message before it was edited, oldest first.
+    pub const fn revisions(&self) -> &VecDeque<CachedMessageRevision> {
+        &self.revisions
+    }
+}
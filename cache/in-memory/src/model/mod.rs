@@ -2,15 +2,26 @@
 
 mod emoji;
 mod guild;
+mod invite;
 mod member;
 mod message;
+mod message_history;
 mod presence;
 mod sticker;
+mod thread_member;
 mod voice_state;
 
 pub use self::{
-    emoji::CachedEmoji, guild::CachedGuild, member::CachedMember, message::CachedMessage,
-    presence::CachedPresence, sticker::CachedSticker, voice_state::CachedVoiceState,
+    emoji::CachedEmoji,
+    guild::CachedGuild,
+    invite::CachedInvite,
+    member::CachedMember,
+    message::CachedMessage,
+    message_history::{CachedMessageRevision, MessageTombstone},
+    presence::CachedPresence,
+    sticker::CachedSticker,
+    thread_member::CachedThreadMember,
+    voice_state::CachedVoiceState,
 };
 
 #[cfg(tests)]
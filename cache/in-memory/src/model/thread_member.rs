@@ -0,0 +1,40 @@
+use serde::Serialize;
+use twilight_model::{
+    datetime::Timestamp,
+    id::{ChannelId, UserId},
+};
+
+/// Represents a cached [`ThreadMember`].
+///
+/// [`ThreadMember`]: twilight_model::channel::thread::ThreadMember
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct CachedThreadMember {
+    pub(crate) channel_id: ChannelId,
+    pub(crate) flags: u64,
+    pub(crate) join_timestamp: Timestamp,
+    pub(crate) user_id: UserId,
+}
+
+impl CachedThreadMember {
+    /// ID of the thread the user is a member of.
+    pub const fn channel_id(&self) -> ChannelId {
+        self.channel_id
+    }
+
+    /// Flags of the membership.
+    ///
+    /// These are currently undocumented.
+    pub const fn flags(&self) -> u64 {
+        self.flags
+    }
+
+    /// [`Timestamp`] of when the user joined the thread.
+    pub const fn join_timestamp(&self) -> Timestamp {
+        self.join_timestamp
+    }
+
+    /// ID of the user.
+    pub const fn user_id(&self) -> UserId {
+        self.user_id
+    }
+}
@@ -0,0 +1,609 @@
+//! Resolve resources from the cache, falling back to retrieving them via
+//! [`twilight_http`].
+//!
+//! Resources that are cached are returned immediately. Resources that aren't
+//! are retrieved via the HTTP API and written into the cache, and the cached
+//! resource is returned. Concurrent resolutions of the same missing resource
+//! share a single request.
+//!
+//! Retrieved resources are written into the cache regardless of its
+//! configured [`ResourceType`]s, but they are only kept up to date if the
+//! relevant resource types are enabled and events are processed by the cache.
+//!
+//! # Examples
+//!
+//! Resolve a member, retrieving it if it isn't cached:
+//!
+//! ```no_run
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use std::{env, sync::Arc};
+//! use twilight_cache_inmemory::{InMemoryCache, InMemoryCacheResolver};
+//! use twilight_http::Client;
+//! use twilight_model::id::{GuildId, UserId};
+//!
+//! let cache = Arc::new(InMemoryCache::new());
+//! let http = Arc::new(Client::new(env::var("DISCORD_TOKEN")?));
+//! let resolver = InMemoryCacheResolver::new(cache, http);
+//!
+//! let guild_id = GuildId::new(1).expect("non zero");
+//! let user_id = UserId::new(2).expect("non zero");
+//!
+//! let member = resolver.member(guild_id, user_id).await?;
+//! println!("member joined at {:?}", member.joined_at());
+//! # Ok(()) }
+//! ```
+//!
+//! [`ResourceType`]: crate::ResourceType
+
+use super::{
+    model::{CachedGuild, CachedMember, CachedMessage},
+    GuildResource, InMemoryCache, Reference,
+};
+use futures_util::future::{FutureExt, Shared};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+use twilight_http::{error::ErrorType as HttpErrorType, Client, Error as HttpError};
+use twilight_model::{
+    channel::{Channel, GuildChannel, PrivateChannel},
+    guild::Role,
+    id::{ChannelId, GuildId, MessageId, RoleId, UserId},
+    user::User,
+};
+
+/// Resolving a resource failed.
+#[derive(Debug)]
+pub struct ResolveError {
+    kind: ResolveErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ResolveError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ResolveErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ResolveErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    const fn not_found() -> Self {
+        Self {
+            kind: ResolveErrorType::NotFound,
+            source: None,
+        }
+    }
+}
+
+impl Display for ResolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            ResolveErrorType::Deserializing => {
+                f.write_str("failed to deserialize the retrieved resource")
+            }
+            ResolveErrorType::NotFound => f.write_str("resource does not exist"),
+            ResolveErrorType::Requesting => f.write_str("failed to retrieve the resource"),
+        }
+    }
+}
+
+impl Error for ResolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ResolveError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ResolveErrorType {
+    /// Deserializing the retrieved resource failed.
+    Deserializing,
+    /// Resource does not exist, or the current user can't access it.
+    NotFound,
+    /// Requesting the resource failed.
+    Requesting,
+}
+
+/// Failure of a shared retrieval, cloned to each of its waiters.
+#[derive(Clone, Debug)]
+enum FetchError {
+    Deserializing(Arc<dyn Error + Send + Sync>),
+    NotFound,
+    Requesting(Arc<HttpError>),
+}
+
+impl FetchError {
+    fn deserializing(source: impl Error + Send + Sync + 'static) -> Self {
+        Self::Deserializing(Arc::new(source))
+    }
+
+    fn requesting(source: HttpError) -> Self {
+        match source.kind() {
+            HttpErrorType::Response { status, .. } if status.raw() == 404 => Self::NotFound,
+            _ => Self::Requesting(Arc::new(source)),
+        }
+    }
+}
+
+impl From<FetchError> for ResolveError {
+    fn from(error: FetchError) -> Self {
+        match error {
+            FetchError::Deserializing(source) => Self {
+                kind: ResolveErrorType::Deserializing,
+                source: Some(Box::new(source)),
+            },
+            FetchError::NotFound => Self::not_found(),
+            FetchError::Requesting(source) => Self {
+                kind: ResolveErrorType::Requesting,
+                source: Some(Box::new(source)),
+            },
+        }
+    }
+}
+
+/// Resource being retrieved.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Key {
+    Channel(ChannelId),
+    Guild(GuildId),
+    Member(GuildId, UserId),
+    Message(MessageId),
+    Roles(GuildId),
+    User(UserId),
+}
+
+type Fetch = Shared<Pin<Box<dyn Future<Output = Result<(), FetchError>> + Send>>>;
+
+/// Resolve resources from a cache, falling back to retrieving them via an
+/// HTTP client.
+///
+/// Refer to the [module-level] documentation for more information.
+///
+/// [module-level]: crate::resolver
+#[derive(Clone)]
+pub struct InMemoryCacheResolver {
+    cache: Arc<InMemoryCache>,
+    http: Arc<Client>,
+    in_flight: Arc<Mutex<HashMap<Key, Fetch>>>,
+}
+
+impl InMemoryCacheResolver {
+    /// Create a new resolver with a cache and an HTTP client.
+    pub fn new(cache: Arc<InMemoryCache>, http: Arc<Client>) -> Self {
+        Self {
+            cache,
+            http,
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Immutable reference to the cache.
+    pub fn cache(&self) -> &InMemoryCache {
+        &self.cache
+    }
+
+    /// Immutable reference to the HTTP client.
+    pub fn http(&self) -> &Client {
+        &self.http
+    }
+
+    /// Resolve a guild by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ResolveErrorType::NotFound`] if the guild
+    /// doesn't exist.
+    ///
+    /// Returns an error of type [`ResolveErrorType::Requesting`] or
+    /// [`ResolveErrorType::Deserializing`] if retrieving the guild failed.
+    pub async fn guild(
+        &self,
+        guild_id: GuildId,
+    ) -> Result<Reference<'_, GuildId, CachedGuild>, ResolveError> {
+        if let Some(guild) = self.cache.guild(guild_id) {
+            return Ok(guild);
+        }
+
+        let (cache, http) = self.clients();
+
+        self.fetch(Key::Guild(guild_id), async move {
+            let guild = http
+                .guild(guild_id)
+                .exec()
+                .await
+                .map_err(FetchError::requesting)?
+                .model()
+                .await
+                .map_err(FetchError::deserializing)?;
+
+            cache.cache_guild(guild);
+
+            Ok(())
+        })
+        .await?;
+
+        self.cache
+            .guild(guild_id)
+            .ok_or_else(ResolveError::not_found)
+    }
+
+    /// Resolve a guild channel by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ResolveErrorType::NotFound`] if the channel
+    /// doesn't exist or isn't a guild channel.
+    ///
+    /// Returns an error of type [`ResolveErrorType::Requesting`] or
+    /// [`ResolveErrorType::Deserializing`] if retrieving the channel failed.
+    pub async fn guild_channel(
+        &self,
+        channel_id: ChannelId,
+    ) -> Result<Reference<'_, ChannelId, GuildResource<GuildChannel>>, ResolveError> {
+        if let Some(channel) = self.cache.guild_channel(channel_id) {
+            return Ok(channel);
+        }
+
+        self.fetch_channel(channel_id).await?;
+
+        self.cache
+            .guild_channel(channel_id)
+            .ok_or_else(ResolveError::not_found)
+    }
+
+    /// Resolve a member by guild ID and user ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ResolveErrorType::NotFound`] if the user
+    /// isn't a member of the guild.
+    ///
+    /// Returns an error of type [`ResolveErrorType::Requesting`] or
+    /// [`ResolveErrorType::Deserializing`] if retrieving the member failed.
+    pub async fn member(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Result<Reference<'_, (GuildId, UserId), CachedMember>, ResolveError> {
+        if let Some(member) = self.cache.member(guild_id, user_id) {
+            return Ok(member);
+        }
+
+        let (cache, http) = self.clients();
+
+        self.fetch(Key::Member(guild_id, user_id), async move {
+            let member = http
+                .guild_member(guild_id, user_id)
+                .exec()
+                .await
+                .map_err(FetchError::requesting)?
+                .model()
+                .await
+                .map_err(FetchError::deserializing)?;
+
+            cache.cache_member(guild_id, member);
+
+            Ok(())
+        })
+        .await?;
+
+        self.cache
+            .member(guild_id, user_id)
+            .ok_or_else(ResolveError::not_found)
+    }
+
+    /// Resolve a message by channel ID and message ID.
+    ///
+    /// Retrieved messages are cached as the oldest message of their channel.
+    /// If the configured [message cache size] is 0 retrieved messages aren't
+    /// cached, and an error of type [`ResolveErrorType::NotFound`] is
+    /// returned for messages that aren't already cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ResolveErrorType::NotFound`] if the message
+    /// doesn't exist.
+    ///
+    /// Returns an error of type [`ResolveErrorType::Requesting`] or
+    /// [`ResolveErrorType::Deserializing`] if retrieving the message failed.
+    ///
+    /// [message cache size]: crate::InMemoryCacheBuilder::message_cache_size
+    pub async fn message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<Reference<'_, MessageId, CachedMessage>, ResolveError> {
+        if let Some(message) = self.cache.message(message_id) {
+            return Ok(message);
+        }
+
+        let (cache, http) = self.clients();
+
+        self.fetch(Key::Message(message_id), async move {
+            let message = http
+                .message(channel_id, message_id)
+                .exec()
+                .await
+                .map_err(FetchError::requesting)?
+                .model()
+                .await
+                .map_err(FetchError::deserializing)?;
+
+            cache.cache_fetched_message(message);
+
+            Ok(())
+        })
+        .await?;
+
+        self.cache
+            .message(message_id)
+            .ok_or_else(ResolveError::not_found)
+    }
+
+    /// Resolve a private channel by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ResolveErrorType::NotFound`] if the channel
+    /// doesn't exist or isn't a private channel.
+    ///
+    /// Returns an error of type [`ResolveErrorType::Requesting`] or
+    /// [`ResolveErrorType::Deserializing`] if retrieving the channel failed.
+    pub async fn private_channel(
+        &self,
+        channel_id: ChannelId,
+    ) -> Result<Reference<'_, ChannelId, PrivateChannel>, ResolveError> {
+        if let Some(channel) = self.cache.private_channel(channel_id) {
+            return Ok(channel);
+        }
+
+        self.fetch_channel(channel_id).await?;
+
+        self.cache
+            .private_channel(channel_id)
+            .ok_or_else(ResolveError::not_found)
+    }
+
+    /// Resolve a role by guild ID and role ID.
+    ///
+    /// Roles can't be retrieved individually, so all of the guild's roles are
+    /// retrieved and cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ResolveErrorType::NotFound`] if the role
+    /// doesn't exist in the guild.
+    ///
+    /// Returns an error of type [`ResolveErrorType::Requesting`] or
+    /// [`ResolveErrorType::Deserializing`] if retrieving the roles failed.
+    pub async fn role(
+        &self,
+        guild_id: GuildId,
+        role_id: RoleId,
+    ) -> Result<Reference<'_, RoleId, GuildResource<Role>>, ResolveError> {
+        if let Some(role) = self.guild_role(guild_id, role_id) {
+            return Ok(role);
+        }
+
+        let (cache, http) = self.clients();
+
+        self.fetch(Key::Roles(guild_id), async move {
+            let roles = http
+                .roles(guild_id)
+                .exec()
+                .await
+                .map_err(FetchError::requesting)?
+                .models()
+                .await
+                .map_err(FetchError::deserializing)?;
+
+            cache.cache_roles(guild_id, roles);
+
+            Ok(())
+        })
+        .await?;
+
+        self.guild_role(guild_id, role_id)
+            .ok_or_else(ResolveError::not_found)
+    }
+
+    /// Get a cached role if it's in the guild.
+    fn guild_role(
+        &self,
+        guild_id: GuildId,
+        role_id: RoleId,
+    ) -> Option<Reference<'_, RoleId, GuildResource<Role>>> {
+        self.cache
+            .role(role_id)
+            .filter(|role| role.guild_id() == guild_id)
+    }
+
+    /// Resolve a user by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ResolveErrorType::NotFound`] if the user
+    /// doesn't exist.
+    ///
+    /// Returns an error of type [`ResolveErrorType::Requesting`] or
+    /// [`ResolveErrorType::Deserializing`] if retrieving the user failed.
+    pub async fn user(&self, user_id: UserId) -> Result<Reference<'_, UserId, User>, ResolveError> {
+        if let Some(user) = self.cache.user(user_id) {
+            return Ok(user);
+        }
+
+        let (cache, http) = self.clients();
+
+        self.fetch(Key::User(user_id), async move {
+            let user = http
+                .user(user_id)
+                .exec()
+                .await
+                .map_err(FetchError::requesting)?
+                .model()
+                .await
+                .map_err(FetchError::deserializing)?;
+
+            cache.cache_user(Cow::Owned(user), None);
+
+            Ok(())
+        })
+        .await?;
+
+        self.cache.user(user_id).ok_or_else(ResolveError::not_found)
+    }
+
+    fn clients(&self) -> (Arc<InMemoryCache>, Arc<Client>) {
+        (Arc::clone(&self.cache), Arc::clone(&self.http))
+    }
+
+    /// Retrieve a channel of any type and cache it.
+    async fn fetch_channel(&self, channel_id: ChannelId) -> Result<(), ResolveError> {
+        let (cache, http) = self.clients();
+
+        self.fetch(Key::Channel(channel_id), async move {
+            let channel = http
+                .channel(channel_id)
+                .exec()
+                .await
+                .map_err(FetchError::requesting)?
+                .model()
+                .await
+                .map_err(FetchError::deserializing)?;
+
+            match channel {
+                Channel::Group(group) => cache.cache_group(group),
+                Channel::Guild(channel) => {
+                    if let Some(guild_id) = channel.guild_id() {
+                        cache.cache_guild_channel(guild_id, channel);
+                    }
+                }
+                Channel::Private(channel) => cache.cache_private_channel(channel),
+            }
+
+            Ok(())
+        })
+        .await
+    }
+
+    /// Run a retrieval, or wait on the one already in flight for the key.
+    async fn fetch(
+        &self,
+        key: Key,
+        future: impl Future<Output = Result<(), FetchError>> + Send + 'static,
+    ) -> Result<(), ResolveError> {
+        let fetch = self
+            .in_flight
+            .lock()
+            .expect("in flight poisoned")
+            .entry(key)
+            .or_insert_with(|| {
+                let future: Pin<Box<dyn Future<Output = _> + Send>> = Box::pin(future);
+
+                future.shared()
+            })
+            .clone();
+
+        let result = fetch.clone().await;
+
+        // Only remove the retrieval if a newer one hasn't replaced it.
+        let mut in_flight = self.in_flight.lock().expect("in flight poisoned");
+
+        if in_flight
+            .get(&key)
+            .map_or(false, |current| current.ptr_eq(&fetch))
+        {
+            in_flight.remove(&key);
+        }
+
+        result.map_err(ResolveError::from)
+    }
+}
+
+impl Debug for InMemoryCacheResolver {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InMemoryCacheResolver")
+            .field("cache", &self.cache)
+            .field("http", &self.http)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InMemoryCacheResolver, ResolveError, ResolveErrorType};
+    use crate::{test, InMemoryCache};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, sync::Arc};
+    use twilight_http::Client;
+    use twilight_model::id::{GuildId, RoleId, UserId};
+
+    assert_impl_all!(InMemoryCacheResolver: Clone, Debug, Send, Sync);
+    assert_impl_all!(ResolveError: Error, Send, Sync);
+    assert_impl_all!(ResolveErrorType: Debug, Send, Sync);
+
+    #[tokio::test]
+    async fn test_cached_without_requesting() -> Result<(), ResolveError> {
+        let guild_id = GuildId::new(1).expect("non zero");
+        let user_id = UserId::new(2).expect("non zero");
+
+        let cache = Arc::new(InMemoryCache::new());
+        cache.cache_member(guild_id, test::member(user_id, guild_id));
+
+        // The client has no token, so any request would fail.
+        let resolver = InMemoryCacheResolver::new(cache, Arc::new(Client::new(String::new())));
+
+        assert_eq!(user_id, resolver.member(guild_id, user_id).await?.user_id());
+        assert_eq!(user_id, resolver.user(user_id).await?.id);
+        assert!(resolver.cache().guild(guild_id).is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_role_in_other_guild() -> Result<(), ResolveError> {
+        let guild_id = GuildId::new(1).expect("non zero");
+        let other_guild_id = GuildId::new(2).expect("non zero");
+        let role_id = RoleId::new(3).expect("non zero");
+
+        let cache = Arc::new(InMemoryCache::new());
+        cache.cache_roles(guild_id, vec![test::role(role_id)]);
+
+        // Requests are sent to a proxy that refuses connections, so any
+        // request fails.
+        let http = Client::builder()
+            .proxy("127.0.0.1:1".to_owned(), true)
+            .ratelimiter(None)
+            .build();
+        let resolver = InMemoryCacheResolver::new(cache, Arc::new(http));
+
+        assert_eq!(role_id, resolver.role(guild_id, role_id).await?.id);
+
+        let error = resolver
+            .role(other_guild_id, role_id)
+            .await
+            .expect_err("role isn't in the other guild");
+        assert!(matches!(error.kind(), ResolveErrorType::Requesting));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,351 @@
+//! Estimates of the memory held by cached resources.
+//!
+//! Estimates include the inline size of a value and the heap allocations
+//! owned by it, such as the buffers of strings and vectors. The overhead of
+//! the maps holding the values and of the allocator isn't included.
+//!
+//! Buffers are measured by their length rather than their capacity, so that
+//! owned strings and vectors are measured the same as the slices borrowed
+//! from them.
+
+use crate::model::{
+    CachedMember, CachedMessage, CachedMessageRevision, CachedPresence, MessageTombstone,
+};
+use std::{collections::VecDeque, mem};
+use twilight_model::{
+    channel::{
+        embed::{
+            Embed, EmbedAuthor, EmbedField, EmbedFooter, EmbedImage, EmbedProvider, EmbedThumbnail,
+            EmbedVideo,
+        },
+        message::{sticker::MessageSticker, MessageActivity, MessageApplication, MessageReaction},
+        Attachment, ChannelMention, ReactionType,
+    },
+    gateway::presence::{
+        Activity, ActivityAssets, ActivityButton, ActivityEmoji, ActivityParty, ActivitySecrets,
+    },
+    guild::PartialMember,
+    id::{RoleId, UserId},
+    user::User,
+};
+
+/// Number of bytes a value holds on the heap.
+pub(crate) trait HeapSize {
+    /// Estimate the number of bytes held on the heap.
+    fn heap_size(&self) -> usize;
+}
+
+/// Estimate the number of bytes held by a value, inline and on the heap.
+pub(crate) fn estimated_size<T: HeapSize>(value: &T) -> usize {
+    mem::size_of::<T>() + value.heap_size()
+}
+
+impl<T: HeapSize + ?Sized> HeapSize for &T {
+    fn heap_size(&self) -> usize {
+        (*self).heap_size()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Option<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, HeapSize::heap_size)
+    }
+}
+
+/// Slices count the buffer holding their elements.
+impl<T: HeapSize> HeapSize for [T] {
+    fn heap_size(&self) -> usize {
+        mem::size_of_val(self) + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Vec<T> {
+    fn heap_size(&self) -> usize {
+        self.as_slice().heap_size()
+    }
+}
+
+impl<T: HeapSize> HeapSize for VecDeque<T> {
+    fn heap_size(&self) -> usize {
+        self.len() * mem::size_of::<T>() + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+impl HeapSize for str {
+    fn heap_size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl HeapSize for String {
+    fn heap_size(&self) -> usize {
+        self.as_str().heap_size()
+    }
+}
+
+/// Implement [`HeapSize`] for types that don't allocate.
+macro_rules! impl_inline {
+    ($($ty:ty),*) => {
+        $(
+            impl HeapSize for $ty {
+                fn heap_size(&self) -> usize {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_inline!(RoleId, UserId);
+
+impl HeapSize for Activity {
+    fn heap_size(&self) -> usize {
+        self.assets.heap_size()
+            + self.buttons.heap_size()
+            + self.details.heap_size()
+            + self.emoji.heap_size()
+            + self.id.heap_size()
+            + self.name.heap_size()
+            + self.party.heap_size()
+            + self.secrets.heap_size()
+            + self.state.heap_size()
+            + self.url.heap_size()
+    }
+}
+
+impl HeapSize for ActivityAssets {
+    fn heap_size(&self) -> usize {
+        self.large_image.heap_size()
+            + self.large_text.heap_size()
+            + self.small_image.heap_size()
+            + self.small_text.heap_size()
+    }
+}
+
+impl HeapSize for ActivityButton {
+    fn heap_size(&self) -> usize {
+        self.label().heap_size() + self.url().heap_size()
+    }
+}
+
+impl HeapSize for ActivityEmoji {
+    fn heap_size(&self) -> usize {
+        self.id.heap_size() + self.name.heap_size()
+    }
+}
+
+impl HeapSize for ActivityParty {
+    fn heap_size(&self) -> usize {
+        self.id.heap_size()
+    }
+}
+
+impl HeapSize for ActivitySecrets {
+    fn heap_size(&self) -> usize {
+        self.join.heap_size() + self.match_.heap_size() + self.spectate.heap_size()
+    }
+}
+
+impl HeapSize for Attachment {
+    fn heap_size(&self) -> usize {
+        self.content_type.heap_size()
+            + self.description.heap_size()
+            + self.filename.heap_size()
+            + self.proxy_url.heap_size()
+            + self.url.heap_size()
+    }
+}
+
+impl HeapSize for CachedMember {
+    fn heap_size(&self) -> usize {
+        self.avatar.heap_size() + self.nick.heap_size() + self.roles.heap_size()
+    }
+}
+
+impl HeapSize for CachedMessage {
+    fn heap_size(&self) -> usize {
+        self.activity().heap_size()
+            + self.application().heap_size()
+            + self.attachments.heap_size()
+            + self.content.heap_size()
+            + self.embeds.heap_size()
+            + self.member().heap_size()
+            + self.mention_channels().heap_size()
+            + self.mention_roles.heap_size()
+            + self.mentions.heap_size()
+            + self.reactions.heap_size()
+            + self.sticker_items().heap_size()
+    }
+}
+
+impl HeapSize for CachedMessageRevision {
+    fn heap_size(&self) -> usize {
+        self.attachments.heap_size() + self.content.heap_size() + self.embeds.heap_size()
+    }
+}
+
+impl HeapSize for MessageTombstone {
+    fn heap_size(&self) -> usize {
+        self.message.heap_size() + self.revisions.heap_size()
+    }
+}
+
+impl HeapSize for CachedPresence {
+    fn heap_size(&self) -> usize {
+        self.activities.heap_size()
+    }
+}
+
+impl HeapSize for ChannelMention {
+    fn heap_size(&self) -> usize {
+        self.name.heap_size()
+    }
+}
+
+impl HeapSize for Embed {
+    fn heap_size(&self) -> usize {
+        self.author.heap_size()
+            + self.description.heap_size()
+            + self.fields.heap_size()
+            + self.footer.heap_size()
+            + self.image.heap_size()
+            + self.kind.heap_size()
+            + self.provider.heap_size()
+            + self.thumbnail.heap_size()
+            + self.title.heap_size()
+            + self.url.heap_size()
+            + self.video.heap_size()
+    }
+}
+
+impl HeapSize for EmbedAuthor {
+    fn heap_size(&self) -> usize {
+        self.icon_url.heap_size()
+            + self.name.heap_size()
+            + self.proxy_icon_url.heap_size()
+            + self.url.heap_size()
+    }
+}
+
+impl HeapSize for EmbedField {
+    fn heap_size(&self) -> usize {
+        self.name.heap_size() + self.value.heap_size()
+    }
+}
+
+impl HeapSize for EmbedFooter {
+    fn heap_size(&self) -> usize {
+        self.icon_url.heap_size() + self.proxy_icon_url.heap_size() + self.text.heap_size()
+    }
+}
+
+impl HeapSize for EmbedImage {
+    fn heap_size(&self) -> usize {
+        self.proxy_url.heap_size() + self.url.heap_size()
+    }
+}
+
+impl HeapSize for EmbedProvider {
+    fn heap_size(&self) -> usize {
+        self.name.heap_size() + self.url.heap_size()
+    }
+}
+
+impl HeapSize for EmbedThumbnail {
+    fn heap_size(&self) -> usize {
+        self.proxy_url.heap_size() + self.url.heap_size()
+    }
+}
+
+impl HeapSize for EmbedVideo {
+    fn heap_size(&self) -> usize {
+        self.proxy_url.heap_size() + self.url.heap_size()
+    }
+}
+
+impl HeapSize for MessageActivity {
+    fn heap_size(&self) -> usize {
+        self.party_id.heap_size()
+    }
+}
+
+impl HeapSize for MessageApplication {
+    fn heap_size(&self) -> usize {
+        self.cover_image.heap_size()
+            + self.description.heap_size()
+            + self.icon.heap_size()
+            + self.name.heap_size()
+    }
+}
+
+impl HeapSize for MessageReaction {
+    fn heap_size(&self) -> usize {
+        match &self.emoji {
+            ReactionType::Custom { name, .. } => name.heap_size(),
+            ReactionType::Unicode { name } => name.heap_size(),
+        }
+    }
+}
+
+impl HeapSize for MessageSticker {
+    fn heap_size(&self) -> usize {
+        self.name.heap_size()
+    }
+}
+
+impl HeapSize for PartialMember {
+    fn heap_size(&self) -> usize {
+        self.avatar.heap_size()
+            + self.nick.heap_size()
+            + self.roles.heap_size()
+            + self.user.heap_size()
+    }
+}
+
+impl HeapSize for User {
+    fn heap_size(&self) -> usize {
+        self.avatar.heap_size()
+            + self.banner.heap_size()
+            + self.email.heap_size()
+            + self.locale.heap_size()
+            + self.name.heap_size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{estimated_size, HeapSize};
+    use crate::test;
+    use std::mem;
+    use twilight_model::id::{ChannelId, MessageId, UserId};
+
+    #[test]
+    fn test_heap_size() {
+        assert_eq!(0, None::<String>.heap_size());
+        assert_eq!(5, String::from("hello").heap_size());
+        assert_eq!(
+            16 + mem::size_of::<String>() * 2,
+            vec![String::from("hello world"), String::from("abcde")].heap_size()
+        );
+
+        let user = test::user(UserId::new(1).expect("non zero"));
+        let banner = user.banner.as_ref().map_or(0, String::capacity);
+        assert_eq!(
+            mem::size_of_val(&user) + banner + user.name.capacity(),
+            estimated_size(&user)
+        );
+    }
+
+    #[test]
+    fn test_message_size_grows_with_content() {
+        let id = MessageId::new(1).expect("non zero");
+        let channel_id = ChannelId::new(2).expect("non zero");
+
+        let small = crate::model::CachedMessage::from(test::message(id, channel_id));
+        let mut message = test::message(id, channel_id);
+        message.content = "a".repeat(1000);
+        let large = crate::model::CachedMessage::from(message);
+
+        assert!(estimated_size(&large) >= estimated_size(&small) + 990);
+    }
+}
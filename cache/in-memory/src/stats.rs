@@ -1,9 +1,22 @@
-use twilight_model::id::{ChannelId, GuildId};
+use twilight_model::id::{ChannelId, GuildId, MessageId};
 
-use super::InMemoryCache;
+use super::{
+    size::{estimated_size, HeapSize},
+    InMemoryCache,
+};
+use dashmap::DashMap;
+use std::{hash::Hash, mem};
 
 /// Retrieve statistics about the number of entities of each resource in the
 /// cache.
+///
+/// Estimates of the number of bytes held by members, messages, presences, and
+/// users are also available. Estimates of messages include their kept
+/// revisions and the tombstones of deleted messages. Estimates include the
+/// heap allocations of strings and vectors within resources, but not the
+/// overhead of the maps holding them. Estimating iterates over the resources,
+/// so it is cheap enough to sample periodically but shouldn't be done for
+/// every event.
 #[derive(Clone, Debug)]
 pub struct InMemoryCacheStats<'a>(&'a InMemoryCache);
 
@@ -34,6 +47,44 @@ impl<'a> InMemoryCacheStats<'a> {
         Some(channel.len())
     }
 
+    /// Estimated number of bytes held by the messages of a given channel in
+    /// the cache.
+    ///
+    /// Returns `None` if the channel hasn't yet been cached or there are
+    /// neither messages nor tombstones of deleted messages in the channel.
+    pub fn channel_messages_size(&self, channel_id: ChannelId) -> Option<usize> {
+        let messages = self.channel_messages_entries_size(channel_id);
+        let tombstones = self.tombstones_size(|id| id == channel_id);
+
+        if messages.is_none() && tombstones == 0 {
+            return None;
+        }
+
+        Some(messages.unwrap_or_default() + tombstones)
+    }
+
+    /// Estimated number of bytes held by the messages of a given channel and
+    /// their revisions, excluding tombstones.
+    fn channel_messages_entries_size(&self, channel_id: ChannelId) -> Option<usize> {
+        let message_ids = self.0.channel_messages.get(&channel_id)?.clone();
+
+        Some(
+            entries_size(&self.0.messages, message_ids.iter().copied())
+                + entries_size(&self.0.message_revisions, message_ids),
+        )
+    }
+
+    /// Estimated number of bytes held by the tombstones of messages deleted
+    /// from channels matching a predicate.
+    fn tombstones_size(&self, predicate: impl Fn(ChannelId) -> bool) -> usize {
+        self.0
+            .message_tombstones
+            .iter()
+            .filter(|entry| predicate(entry.channel_id))
+            .map(|entry| mem::size_of::<MessageId>() + estimated_size(entry.value()))
+            .sum()
+    }
+
     /// Number of voice states in a given channel in the cache.
     ///
     /// Returns `None` if the channel hasn't yet been cached or there are no
@@ -87,6 +138,32 @@ impl<'a> InMemoryCacheStats<'a> {
         Some(guild.len())
     }
 
+    /// Estimated number of bytes held by the members of a given guild in the
+    /// cache.
+    ///
+    /// Returns `None` if the guild hasn't yet been cached.
+    pub fn guild_members_size(&self, guild_id: GuildId) -> Option<usize> {
+        let user_ids = self.0.guild_members.get(&guild_id)?.clone();
+        let keys = user_ids.into_iter().map(|user_id| (guild_id, user_id));
+
+        Some(entries_size(&self.0.members, keys))
+    }
+
+    /// Estimated number of bytes held by the messages of a given guild in the
+    /// cache.
+    ///
+    /// Returns `None` if the guild's channels haven't yet been cached.
+    pub fn guild_messages_size(&self, guild_id: GuildId) -> Option<usize> {
+        let channel_ids = self.0.guild_channels.get(&guild_id)?.clone();
+
+        let messages = channel_ids
+            .iter()
+            .filter_map(|channel_id| self.channel_messages_entries_size(*channel_id))
+            .sum::<usize>();
+
+        Some(messages + self.tombstones_size(|id| channel_ids.contains(&id)))
+    }
+
     /// Number of presences in a given guild in the cache.
     ///
     /// Returns `None` if the guild hasn't yet been cached.
@@ -96,6 +173,37 @@ impl<'a> InMemoryCacheStats<'a> {
         Some(guild.len())
     }
 
+    /// Estimated number of bytes held by the presences of a given guild in the
+    /// cache.
+    ///
+    /// Returns `None` if the guild hasn't yet been cached.
+    pub fn guild_presences_size(&self, guild_id: GuildId) -> Option<usize> {
+        let user_ids = self.0.guild_presences.get(&guild_id)?.clone();
+        let keys = user_ids.into_iter().map(|user_id| (guild_id, user_id));
+
+        Some(entries_size(&self.0.presences, keys))
+    }
+
+    /// Estimated number of bytes held by the members, messages, and presences
+    /// of a given guild in the cache.
+    ///
+    /// Returns `None` if the guild hasn't yet been cached.
+    pub fn guild_size(&self, guild_id: GuildId) -> Option<usize> {
+        let members = self.guild_members_size(guild_id);
+        let messages = self.guild_messages_size(guild_id);
+        let presences = self.guild_presences_size(guild_id);
+
+        if members.is_none() && messages.is_none() && presences.is_none() {
+            return None;
+        }
+
+        Some(
+            members.unwrap_or_default()
+                + messages.unwrap_or_default()
+                + presences.unwrap_or_default(),
+        )
+    }
+
     /// Number of roles in a given guild in the cache.
     ///
     /// Returns `None` if the guild hasn't yet been cached.
@@ -119,11 +227,28 @@ impl<'a> InMemoryCacheStats<'a> {
         self.0.members.len()
     }
 
+    /// Estimated number of bytes held by the members in the cache.
+    pub fn members_size(&self) -> usize {
+        map_size(&self.0.members)
+    }
+
+    /// Estimated number of bytes held by the messages in the cache.
+    pub fn messages_size(&self) -> usize {
+        map_size(&self.0.messages)
+            + map_size(&self.0.message_revisions)
+            + map_size(&self.0.message_tombstones)
+    }
+
     /// Number of presences in the cache.
     pub fn presences(&self) -> usize {
         self.0.presences.len()
     }
 
+    /// Estimated number of bytes held by the presences in the cache.
+    pub fn presences_size(&self) -> usize {
+        map_size(&self.0.presences)
+    }
+
     /// Number of private channels in the cache.
     pub fn private_channels(&self) -> usize {
         self.0.channels_private.len()
@@ -134,6 +259,15 @@ impl<'a> InMemoryCacheStats<'a> {
         self.0.roles.len()
     }
 
+    /// Number of members in a given thread in the cache.
+    ///
+    /// Returns `None` if no members of the thread have been cached.
+    pub fn thread_members(&self, channel_id: ChannelId) -> Option<usize> {
+        let thread = self.0.thread_members.get(&channel_id)?;
+
+        Some(thread.len())
+    }
+
     /// Number of unavailable_guilds in the cache.
     pub fn unavailable_guilds(&self) -> usize {
         self.0.unavailable_guilds.len()
@@ -144,17 +278,95 @@ impl<'a> InMemoryCacheStats<'a> {
         self.0.users.len()
     }
 
+    /// Estimated number of bytes held by the users in the cache.
+    pub fn users_size(&self) -> usize {
+        map_size(&self.0.users)
+    }
+
+    /// Estimated number of bytes held by the members, messages, presences,
+    /// and users in the cache.
+    pub fn size(&self) -> usize {
+        self.members_size() + self.messages_size() + self.presences_size() + self.users_size()
+    }
+
     /// Number of voice_states in the cache.
     pub fn voice_states(&self) -> usize {
         self.0.voice_states.len()
     }
 }
 
+/// Estimate the number of bytes held by the keys and values of a map.
+fn map_size<K: Eq + Hash, V: HeapSize>(map: &DashMap<K, V>) -> usize {
+    map.iter()
+        .map(|entry| mem::size_of::<K>() + estimated_size(entry.value()))
+        .sum()
+}
+
+/// Estimate the number of bytes held by the given entries of a map.
+fn entries_size<K: Eq + Hash, V: HeapSize>(
+    map: &DashMap<K, V>,
+    keys: impl IntoIterator<Item = K>,
+) -> usize {
+    keys.into_iter()
+        .filter_map(|key| map.get(&key))
+        .map(|entry| mem::size_of::<K>() + estimated_size(entry.value()))
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::InMemoryCacheStats;
+    use crate::{test, InMemoryCache};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{fmt::Debug, time::Duration};
+    use twilight_model::{
+        gateway::payload::incoming::{MessageCreate, MessageDelete},
+        id::{ChannelId, GuildId, MessageId, UserId},
+    };
 
     assert_impl_all!(InMemoryCacheStats<'_>: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_sizes() {
+        let cache = InMemoryCache::new();
+        let guild_id = GuildId::new(1).expect("non zero");
+        let stats = cache.stats();
+        assert_eq!(0, stats.size());
+        assert!(stats.guild_size(guild_id).is_none());
+
+        cache.cache_member(
+            guild_id,
+            test::member(UserId::new(2).expect("non zero"), guild_id),
+        );
+
+        let members = stats.members_size();
+        assert!(members > 0);
+        assert_eq!(Some(members), stats.guild_members_size(guild_id));
+        assert_eq!(Some(members), stats.guild_size(guild_id));
+        assert_eq!(members + stats.users_size(), stats.size());
+    }
+
+    #[test]
+    fn test_messages_size_tombstones() {
+        let cache = InMemoryCache::builder()
+            .deleted_message_retention(Some(Duration::from_secs(60)))
+            .build();
+        let channel_id = ChannelId::new(2).expect("non zero");
+        let message_id = MessageId::new(4).expect("non zero");
+        cache.update(&MessageCreate(test::message(message_id, channel_id)));
+
+        let stats = cache.stats();
+        assert!(stats.messages_size() > 0);
+
+        cache.update(&MessageDelete {
+            channel_id,
+            guild_id: None,
+            id: message_id,
+        });
+        assert!(cache.message(message_id).is_none());
+
+        let tombstones = stats.messages_size();
+        assert!(tombstones > 0);
+        assert_eq!(Some(tombstones), stats.channel_messages_size(channel_id));
+    }
 }
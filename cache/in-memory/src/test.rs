@@ -6,7 +6,11 @@ use twilight_model::{
     },
     datetime::Timestamp,
     gateway::payload::incoming::{MessageCreate, ReactionAdd},
-    guild::{Emoji, Member, PartialMember, Permissions, Role},
+    guild::{
+        DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, Guild, Member, MfaLevel,
+        NSFWLevel, PartialMember, Permissions, PremiumTier, Role, SystemChannelFlags,
+        VerificationLevel,
+    },
     id::{ChannelId, EmojiId, GuildId, MessageId, RoleId, UserId},
     user::{CurrentUser, User},
     voice::VoiceState,
@@ -210,6 +214,58 @@ pub fn guild_channel_text() -> (GuildId, ChannelId, GuildChannel) {
     (guild_id, channel_id, channel)
 }
 
+#[cfg_attr(not(feature = "auditor"), allow(dead_code))]
+pub fn guild(id: GuildId) -> Guild {
+    Guild {
+        afk_channel_id: None,
+        afk_timeout: 0,
+        application_id: None,
+        approximate_member_count: None,
+        approximate_presence_count: None,
+        banner: None,
+        channels: Vec::new(),
+        default_message_notifications: DefaultMessageNotificationLevel::Mentions,
+        description: None,
+        discovery_splash: None,
+        emojis: Vec::new(),
+        explicit_content_filter: ExplicitContentFilter::None,
+        features: Vec::new(),
+        icon: None,
+        id,
+        joined_at: None,
+        large: false,
+        max_members: None,
+        max_presences: None,
+        max_video_channel_users: None,
+        member_count: None,
+        members: Vec::new(),
+        mfa_level: MfaLevel::None,
+        name: "test".to_owned(),
+        nsfw_level: NSFWLevel::Default,
+        owner_id: UserId::new(1).expect("non zero"),
+        owner: None,
+        permissions: None,
+        preferred_locale: "en_us".to_owned(),
+        premium_subscription_count: None,
+        premium_tier: PremiumTier::None,
+        presences: Vec::new(),
+        roles: Vec::new(),
+        rules_channel_id: None,
+        splash: None,
+        stage_instances: Vec::new(),
+        stickers: Vec::new(),
+        system_channel_flags: SystemChannelFlags::empty(),
+        system_channel_id: None,
+        threads: Vec::new(),
+        unavailable: false,
+        vanity_url_code: None,
+        verification_level: VerificationLevel::VeryHigh,
+        voice_states: Vec::new(),
+        widget_channel_id: None,
+        widget_enabled: None,
+    }
+}
+
 pub fn member(id: UserId, guild_id: GuildId) -> Member {
     let joined_at = Timestamp::from_secs(1_632_072_645).expect("non zero");
 
@@ -227,6 +283,40 @@ pub fn member(id: UserId, guild_id: GuildId) -> Member {
     }
 }
 
+pub fn message(id: MessageId, channel_id: ChannelId) -> Message {
+    Message {
+        activity: None,
+        application: None,
+        application_id: None,
+        attachments: Vec::new(),
+        author: user(UserId::new(3).expect("non zero")),
+        channel_id,
+        components: Vec::new(),
+        content: "ping".to_owned(),
+        edited_timestamp: None,
+        embeds: Vec::new(),
+        flags: Some(MessageFlags::empty()),
+        guild_id: None,
+        id,
+        interaction: None,
+        kind: MessageType::Regular,
+        member: None,
+        mention_channels: Vec::new(),
+        mention_everyone: false,
+        mention_roles: Vec::new(),
+        mentions: Vec::new(),
+        pinned: false,
+        reactions: Vec::new(),
+        reference: None,
+        sticker_items: Vec::new(),
+        thread: None,
+        referenced_message: None,
+        timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+        tts: false,
+        webhook_id: None,
+    }
+}
+
 pub fn role(id: RoleId) -> Role {
     Role {
         color: 0,
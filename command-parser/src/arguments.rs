@@ -1,3 +1,7 @@
+use crate::{
+    extract::{ArgumentError, ArgumentErrorType, FromArgument},
+    CommandPath,
+};
 use std::{
     fmt::{Debug, Formatter, Result as FmtResult},
     str::CharIndices,
@@ -9,6 +13,11 @@ pub struct Arguments<'a> {
     buf: &'a str,
     indices: CharIndices<'a>,
     idx: usize,
+    /// Path of the command the arguments are for, if parsed by a
+    /// [`Parser`].
+    ///
+    /// [`Parser`]: crate::Parser
+    pub(crate) path: CommandPath<'a>,
 }
 
 impl<'a> Arguments<'a> {
@@ -18,6 +27,7 @@ impl<'a> Arguments<'a> {
             buf: buf.trim(),
             indices: buf.trim().char_indices(),
             idx: 0,
+            path: CommandPath::empty(),
         }
     }
 
@@ -65,6 +75,129 @@ impl<'a> Arguments<'a> {
     pub fn into_remainder(self) -> Option<&'a str> {
         self.buf.get(self.idx..)
     }
+
+    /// Parse the next argument into a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_command_parser::Arguments;
+    ///
+    /// let mut args = Arguments::new("3 true");
+    /// assert_eq!(3, args.parse::<u8>().unwrap());
+    /// assert!(args.parse::<bool>().unwrap());
+    /// assert!(args.parse::<bool>().is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ArgumentErrorType::Invalid`] if the
+    /// argument isn't a valid value.
+    ///
+    /// Returns an error of type [`ArgumentErrorType::Missing`] if there are no
+    /// more arguments.
+    pub fn parse<T: FromArgument>(&mut self) -> Result<T, ArgumentError> {
+        self.parse_optional()?.ok_or_else(|| ArgumentError {
+            kind: ArgumentErrorType::Missing {
+                expected: T::EXPECTED,
+                position: self.buf.len(),
+            },
+            source: None,
+        })
+    }
+
+    /// Parse the next argument into a value if there is one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ArgumentErrorType::Invalid`] if the
+    /// argument isn't a valid value.
+    pub fn parse_optional<T: FromArgument>(&mut self) -> Result<Option<T>, ArgumentError> {
+        self.next().map(|value| self.convert(value)).transpose()
+    }
+
+    /// Parse the rest of the arguments as one value.
+    ///
+    /// Quotes are not treated specially.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_command_parser::Arguments;
+    ///
+    /// let mut args = Arguments::new(r#"5 "a long" reason"#);
+    /// assert_eq!(5, args.parse::<u64>().unwrap());
+    /// assert_eq!(r#""a long" reason"#, args.parse_rest::<String>().unwrap());
+    /// assert!(args.next().is_none());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ArgumentErrorType::Invalid`] if the rest of
+    /// the arguments isn't a valid value.
+    ///
+    /// Returns an error of type [`ArgumentErrorType::Missing`] if there are no
+    /// more arguments.
+    pub fn parse_rest<T: FromArgument>(&mut self) -> Result<T, ArgumentError> {
+        let rest = self.buf.get(self.idx..).map_or("", str::trim);
+        self.idx = usize::max_value();
+
+        if rest.is_empty() {
+            return Err(ArgumentError {
+                kind: ArgumentErrorType::Missing {
+                    expected: T::EXPECTED,
+                    position: self.buf.len(),
+                },
+                source: None,
+            });
+        }
+
+        self.convert(rest)
+    }
+
+    /// Parse all of the remaining arguments into values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_command_parser::Arguments;
+    /// use twilight_model::id::RoleId;
+    ///
+    /// let mut args = Arguments::new("<@&1> 2 <@&3>");
+    /// let roles = args.parse_variadic::<RoleId>().unwrap();
+    /// assert_eq!(vec![1, 2, 3], roles.iter().map(|id| id.get()).collect::<Vec<_>>());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ArgumentErrorType::Invalid`] if any of the
+    /// arguments isn't a valid value.
+    pub fn parse_variadic<T: FromArgument>(&mut self) -> Result<Vec<T>, ArgumentError> {
+        let mut values = Vec::new();
+
+        while let Some(value) = self.parse_optional()? {
+            values.push(value);
+        }
+
+        Ok(values)
+    }
+
+    /// Convert an argument yielded by the iterator into a value.
+    fn convert<T: FromArgument>(&self, value: &str) -> Result<T, ArgumentError> {
+        T::from_argument(value).map_err(|source| {
+            // Arguments are always slices of the buffer.
+            let start = value.as_ptr() as usize - self.buf.as_ptr() as usize;
+
+            ArgumentError {
+                kind: ArgumentErrorType::Invalid {
+                    expected: T::EXPECTED,
+                    found: value.to_owned(),
+                    position: start..start + value.len(),
+                },
+                source: Some(source),
+            }
+        })
+    }
 }
 
 impl<'a> Debug for Arguments<'a> {
@@ -130,8 +263,10 @@ impl<'a> Iterator for Arguments<'a> {
 #[cfg(test)]
 mod tests {
     use super::Arguments;
+    use crate::extract::ArgumentErrorType;
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{fmt::Debug, time::Duration};
+    use twilight_model::id::UserId;
 
     assert_impl_all!(Arguments<'_>: Clone, Debug, Iterator, Send, Sync);
 
@@ -213,4 +348,64 @@ mod tests {
         assert_eq!(Some("kewl"), args.next());
         assert_eq!(None, args.next());
     }
+
+    #[test]
+    fn test_parse() {
+        let mut args = Arguments::new(r#"<@1> "2 3" 4 5 rest of  line"#);
+        assert_eq!(UserId::new(1), Some(args.parse().unwrap()));
+        assert_eq!(Some(String::from("2 3")), args.parse_optional().unwrap());
+        assert_eq!(4, args.parse::<u8>().unwrap());
+        assert_eq!(5, args.parse::<u8>().unwrap());
+        assert_eq!("rest of  line", args.parse_rest::<String>().unwrap());
+        assert!(args.parse_optional::<String>().unwrap().is_none());
+        assert!(args.parse_variadic::<String>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let mut args = Arguments::new("  1 two 3h  ");
+        assert_eq!(1, args.parse::<u8>().unwrap());
+
+        let error = args.parse::<u8>().unwrap_err();
+        assert_eq!(
+            &ArgumentErrorType::Invalid {
+                expected: "integer",
+                found: String::from("two"),
+                position: 2..5,
+            },
+            error.kind()
+        );
+        assert_eq!("two", &args.as_str()[2..5]);
+
+        assert_eq!(
+            Duration::from_secs(10_800),
+            args.parse::<Duration>().unwrap()
+        );
+        let error = args.parse::<UserId>().unwrap_err();
+        assert_eq!(
+            &ArgumentErrorType::Missing {
+                expected: "user",
+                position: 8,
+            },
+            error.kind()
+        );
+        assert_eq!(
+            "expected an argument of type user at position 8",
+            error.to_string()
+        );
+        assert!(matches!(
+            args.parse_rest::<String>().unwrap_err().kind(),
+            ArgumentErrorType::Missing { position: 8, .. }
+        ));
+    }
+
+    #[test]
+    fn test_parse_variadic_error() {
+        let mut args = Arguments::new("1 2 x");
+        let error = args.parse_variadic::<u64>().unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ArgumentErrorType::Invalid { position, .. } if *position == (4..5)
+        ));
+    }
 }
@@ -1,5 +1,20 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
 use unicase::UniCase;
 
+/// Case-insensitive hash of a name to index it by.
+///
+/// Names that are equal regardless of case have the same key, so a borrowed
+/// name can be looked up without allocating.
+pub fn key(name: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    UniCase::new(name).hash(&mut hasher);
+
+    hasher.finish()
+}
+
 /// Case sensitivity of a command.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum CaseSensitivity {
@@ -11,9 +26,22 @@ pub enum CaseSensitivity {
 }
 
 impl CaseSensitivity {
+    pub fn new(name: String, case_sensitive: bool) -> Self {
+        if case_sensitive {
+            Self::Sensitive(name)
+        } else {
+            Self::Insensitive(name.into())
+        }
+    }
+
     pub const fn is_sensitive(&self) -> bool {
         matches!(self, Self::Sensitive(_))
     }
+
+    /// Case-insensitive key to index the name by.
+    pub fn key(&self) -> u64 {
+        key(self.as_ref())
+    }
 }
 
 impl AsRef<str> for CaseSensitivity {
@@ -60,4 +88,15 @@ mod tests {
         Send,
         Sync
     );
+
+    #[test]
+    fn test_key() {
+        assert_eq!(super::key("echo"), super::key("EcHo"));
+        assert_eq!(super::key("wei\u{df}"), super::key("WEISS"));
+        assert_ne!(super::key("echo"), super::key("ping"));
+        assert_eq!(
+            super::key("echo"),
+            CaseSensitivity::new("ECHO".to_owned(), true).key()
+        );
+    }
 }
@@ -5,24 +5,231 @@
 //! [adding prefixes][`add_prefix`] and [removing prefixes][`remove_prefix`].
 //! You can also [iterate over commands][`commands`] and [prefixes][`prefixes`].
 //!
+//! Commands with aliases and subcommands can be added as a
+//! [`CommandDefinition`] via [`add_command_definition`].
+//!
 //! [`Parser`]: super::Parser
 //! [`add_command`]: CommandParserConfig::add_command
+//! [`add_command_definition`]: CommandParserConfig::add_command_definition
 //! [`add_prefix`]: CommandParserConfig::add_prefix
 //! [`commands`]: CommandParserConfig::commands
 //! [`prefixes`]: CommandParserConfig::prefixes
 //! [`remove_command`]: CommandParserConfig::remove_command
 //! [`remove_prefix`]: CommandParserConfig::remove_prefix
 
-use super::casing::CaseSensitivity;
+use super::casing::{self, CaseSensitivity};
 use std::borrow::Cow;
 use std::slice::{Iter, IterMut};
+use twilight_model::id::UserId;
+
+/// Command with optional aliases and subcommands.
+///
+/// Commands with subcommands act as command groups: when parsing
+/// `!config role add`, the `add` subcommand of the `role` subcommand of the
+/// `config` command is matched, and the parsed [`Command`]'s [path] is
+/// `["config", "role", "add"]`.
+///
+/// Aliases and subcommands use the case sensitivity of the command they're
+/// added to.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_command_parser::{CommandDefinition, CommandParserConfig, Parser};
+///
+/// let mut config = CommandParserConfig::new();
+/// config.add_prefix("!");
+/// config.add_command_definition(
+///     CommandDefinition::new("config", false).alias("cfg").subcommand(
+///         CommandDefinition::new("role", false)
+///             .subcommand(CommandDefinition::new("add", false).alias("+"))
+///             .subcommand(CommandDefinition::new("remove", false)),
+///     ),
+/// );
+///
+/// let parser = Parser::new(config);
+/// let command = parser.parse("!cfg role + <@&123>").expect("command matches");
+///
+/// assert_eq!(
+///     vec!["config", "role", "add"],
+///     command.path().collect::<Vec<_>>(),
+/// );
+/// assert_eq!("<@&123>", command.arguments.as_str());
+/// ```
+///
+/// [`Command`]: crate::Command
+/// [path]: crate::Command::path
+#[derive(Clone, Debug)]
+pub struct CommandDefinition {
+    aliases: Vec<CaseSensitivity>,
+    name: CaseSensitivity,
+    pub(crate) subcommands: CommandSet,
+}
+
+impl CommandDefinition {
+    /// Create a new command definition without aliases or subcommands.
+    pub fn new(name: impl Into<String>, case_sensitive: bool) -> Self {
+        Self {
+            aliases: Vec::new(),
+            name: CaseSensitivity::new(name.into(), case_sensitive),
+            subcommands: CommandSet::default(),
+        }
+    }
+
+    /// Add an alias that the command can also be called by.
+    #[must_use = "command definitions must be added to a configuration"]
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        let alias = CaseSensitivity::new(alias.into(), self.is_case_sensitive());
+        self.aliases.push(alias);
+
+        self
+    }
+
+    /// Add a subcommand.
+    ///
+    /// Subcommands with a name or alias already used by another subcommand are
+    /// ignored.
+    #[must_use = "command definitions must be added to a configuration"]
+    pub fn subcommand(mut self, subcommand: CommandDefinition) -> Self {
+        self.subcommands.insert(subcommand);
+
+        self
+    }
+
+    /// Iterator over the aliases of the command.
+    pub fn aliases(&self) -> impl Iterator<Item = &str> {
+        self.aliases.iter().map(AsRef::as_ref)
+    }
+
+    /// Whether the command is case sensitive.
+    pub const fn is_case_sensitive(&self) -> bool {
+        self.name.is_sensitive()
+    }
+
+    /// Name of the command.
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
+
+    /// Iterator over the subcommands of the command.
+    pub fn subcommands(&self) -> impl Iterator<Item = &CommandDefinition> {
+        self.subcommands.commands.iter()
+    }
+
+    /// Whether the command is called by a name.
+    fn matches(&self, name: &str) -> bool {
+        self.name == *name || self.aliases.iter().any(|alias| alias == name)
+    }
+
+    /// Whether the command has the same name as another, or either has an
+    /// alias that the other is called by.
+    fn collides(&self, other: &Self) -> bool {
+        self.name == other.name
+            || self
+                .aliases
+                .iter()
+                .any(|alias| other.matches(alias.as_ref()))
+            || other
+                .aliases
+                .iter()
+                .any(|alias| self.matches(alias.as_ref()))
+    }
+}
+
+/// Set of commands indexed by their names and aliases.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CommandSet {
+    commands: Vec<CommandDefinition>,
+    /// Indices of commands by the case-insensitive [key] of their names and
+    /// aliases, sorted by key.
+    ///
+    /// [key]: casing::key
+    index: Vec<(u64, usize)>,
+    /// Whether command names may have been renamed since the index was built,
+    /// in which case commands are found by scanning them.
+    stale: bool,
+}
+
+impl CommandSet {
+    pub const fn new() -> Self {
+        Self {
+            commands: Vec::new(),
+            index: Vec::new(),
+            stale: false,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn clear(&mut self) {
+        self.commands.clear();
+        self.index.clear();
+    }
+
+    /// Find the command called by a name.
+    pub fn find(&self, name: &str) -> Option<&CommandDefinition> {
+        if self.stale {
+            return self.commands.iter().find(|command| command.matches(name));
+        }
+
+        let key = casing::key(name);
+        let start = self.index.partition_point(|(other, _)| *other < key);
+
+        self.index[start..]
+            .iter()
+            .take_while(|(other, _)| *other == key)
+            .filter_map(|(_, idx)| self.commands.get(*idx))
+            .find(|command| command.matches(name))
+    }
+
+    fn insert(&mut self, command: CommandDefinition) -> bool {
+        if self
+            .commands
+            .iter()
+            .any(|existing| existing.collides(&command))
+        {
+            return false;
+        }
+
+        self.commands.push(command);
+        self.reindex();
+
+        true
+    }
+
+    fn remove(&mut self, name: &str) {
+        self.commands.retain(|command| !command.matches(name));
+        self.reindex();
+    }
+
+    /// Mutable iterator over the commands, after which the index is stale
+    /// until the next insertion or removal.
+    fn iter_mut(&mut self) -> IterMut<'_, CommandDefinition> {
+        self.stale = true;
+
+        self.commands.iter_mut()
+    }
+
+    fn reindex(&mut self) {
+        self.index.clear();
+        self.stale = false;
+
+        for (idx, command) in self.commands.iter().enumerate() {
+            for name in Some(&command.name).into_iter().chain(&command.aliases) {
+                self.index.push((name.key(), idx));
+            }
+        }
+
+        self.index.sort_unstable();
+    }
+}
 
 /// Configuration for a [`Parser`].
 ///
 /// [`Parser`]: crate::Parser
 #[derive(Clone, Debug, Default)]
 pub struct CommandParserConfig<'a> {
-    pub(crate) commands: Vec<CaseSensitivity>,
+    pub(crate) commands: CommandSet,
+    pub(crate) mention_prefix: Option<UserId>,
     pub(crate) prefixes: Vec<Cow<'a, str>>,
 }
 
@@ -30,7 +237,8 @@ impl<'a> CommandParserConfig<'a> {
     /// Creates a fresh default configuration with no commands or prefixes.
     pub const fn new() -> Self {
         Self {
-            commands: Vec::new(),
+            commands: CommandSet::new(),
+            mention_prefix: None,
             prefixes: Vec::new(),
         }
     }
@@ -38,14 +246,15 @@ impl<'a> CommandParserConfig<'a> {
     /// Returns an iterator of immutable references to the commands.
     pub fn commands(&self) -> Commands<'_> {
         Commands {
-            iter: self.commands.iter(),
+            iter: self.commands.commands.iter(),
         }
     }
 
     /// Returns an iterator of mutable references to the commands.
     ///
     /// Use the [`add_command`] and [`remove_command`] methods for an easier way to
-    /// manage commands.
+    /// manage commands. Renamed commands are found by scanning all commands
+    /// until the next command is added or removed.
     ///
     /// [`add_command`]: Self::add_command
     /// [`remove_command`]: Self::remove_command
@@ -68,6 +277,15 @@ impl<'a> CommandParserConfig<'a> {
         }
     }
 
+    /// ID of the user whose mention is a prefix, if any.
+    ///
+    /// Refer to [`set_mention_prefix`] for more information.
+    ///
+    /// [`set_mention_prefix`]: Self::set_mention_prefix
+    pub const fn mention_prefix(&self) -> Option<UserId> {
+        self.mention_prefix
+    }
+
     /// Returns an iterator of mutable references to the prefixes.
     pub fn prefixes_mut(&'a mut self) -> PrefixesMut<'a> {
         PrefixesMut {
@@ -89,26 +307,24 @@ impl<'a> CommandParserConfig<'a> {
     /// assert_eq!(1, config.commands().len());
     /// ```
     pub fn add_command(&mut self, name: impl Into<String>, case_sensitive: bool) -> bool {
-        self._add_command(name.into(), case_sensitive)
+        self.add_command_definition(CommandDefinition::new(name, case_sensitive))
     }
 
-    fn _add_command(&mut self, name: String, case_sensitive: bool) -> bool {
-        let command = if case_sensitive {
-            CaseSensitivity::Sensitive(name)
-        } else {
-            CaseSensitivity::Insensitive(name.into())
-        };
-        if self.commands.contains(&command) {
-            false
-        } else {
-            self.commands.push(command);
-            true
-        }
+    /// Add a command with aliases and subcommands to the list of commands.
+    ///
+    /// Returns whether the command was added, which is not the case if a
+    /// command with the same name already exists, or if an alias of either
+    /// command is a name or alias of the other.
+    ///
+    /// Refer to [`CommandDefinition`] for an example.
+    pub fn add_command_definition(&mut self, command: CommandDefinition) -> bool {
+        self.commands.insert(command)
     }
 
     /// Removes a command from the list of commands.
     ///
-    /// Any commands that would match the command provided are removed.
+    /// Any commands that would match the command provided are removed,
+    /// including commands with the name as an alias.
     ///
     /// # Examples
     ///
@@ -125,7 +341,7 @@ impl<'a> CommandParserConfig<'a> {
     /// assert_eq!(config.commands().len(), 0);
     /// ```
     pub fn remove_command(&mut self, command: impl AsRef<str>) {
-        self.commands.retain(|c| c != command.as_ref());
+        self.commands.remove(command.as_ref());
     }
 
     /// Adds a prefix to the list of prefixes.
@@ -149,6 +365,30 @@ impl<'a> CommandParserConfig<'a> {
         }
     }
 
+    /// Set the ID of the user whose mention is a prefix, usually the bot's.
+    ///
+    /// Both `<@id>` and `<@!id>` mentions are matched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_command_parser::{CommandParserConfig, Parser};
+    /// use twilight_model::id::UserId;
+    ///
+    /// let mut config = CommandParserConfig::new();
+    /// config.set_mention_prefix(UserId::new(123));
+    /// config.add_command("ping", false);
+    ///
+    /// let parser = Parser::new(config);
+    /// let command = parser.parse("<@!123> ping").expect("command matches");
+    ///
+    /// assert_eq!("<@!123>", command.prefix);
+    /// assert_eq!("ping", command.name);
+    /// ```
+    pub fn set_mention_prefix(&mut self, user_id: Option<UserId>) {
+        self.mention_prefix = user_id;
+    }
+
     /// Removes a prefix from the list of prefixes.
     ///
     /// Returns whether a prefix with the name was removed.
@@ -176,7 +416,7 @@ impl<'a> CommandParserConfig<'a> {
 
 /// Iterator over the parser configuration's immutably borrowed commands.
 pub struct Commands<'a> {
-    iter: Iter<'a, CaseSensitivity>,
+    iter: Iter<'a, CommandDefinition>,
 }
 
 impl<'a> Iterator for Commands<'a> {
@@ -185,7 +425,7 @@ impl<'a> Iterator for Commands<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         self.iter
             .next()
-            .map(|casing| (casing.as_ref(), casing.is_sensitive()))
+            .map(|command| (command.name(), command.is_case_sensitive()))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -197,17 +437,17 @@ impl<'a> ExactSizeIterator for Commands<'a> {}
 
 /// Iterator over the parser configuration's mutably borrowed commands.
 pub struct CommandsMut<'a> {
-    iter: IterMut<'a, CaseSensitivity>,
+    iter: IterMut<'a, CommandDefinition>,
 }
 
 impl<'a> Iterator for CommandsMut<'a> {
     type Item = (&'a mut str, bool);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let casing = self.iter.next()?;
-        let is_sensitive = casing.is_sensitive();
+        let command = self.iter.next()?;
+        let is_sensitive = command.is_case_sensitive();
 
-        Some((casing.as_mut(), is_sensitive))
+        Some((command.name.as_mut(), is_sensitive))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -257,10 +497,13 @@ impl<'a> ExactSizeIterator for PrefixesMut<'a> {}
 
 #[cfg(test)]
 mod tests {
-    use super::{CommandParserConfig, Commands, CommandsMut, Prefixes, PrefixesMut};
+    use super::{
+        CommandDefinition, CommandParserConfig, Commands, CommandsMut, Prefixes, PrefixesMut,
+    };
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
 
+    assert_impl_all!(CommandDefinition: Clone, Debug, Send, Sync);
     assert_impl_all!(CommandParserConfig<'_>: Clone, Debug, Default, Send, Sync);
     assert_impl_all!(CommandsMut<'_>: ExactSizeIterator, Iterator, Send, Sync);
     assert_impl_all!(Commands<'_>: ExactSizeIterator, Iterator, Send, Sync);
@@ -275,4 +518,54 @@ mod tests {
         assert!(config.prefixes().len() == 0);
         assert!(config.prefixes_mut().len() == 0);
     }
+
+    #[test]
+    fn test_command_definitions() {
+        let mut config = CommandParserConfig::new();
+        assert!(config.add_command_definition(
+            CommandDefinition::new("config", false)
+                .alias("cfg")
+                .subcommand(CommandDefinition::new("role", false))
+        ));
+        assert!(!config.add_command("CONFIG", false));
+        assert!(!config.add_command("cfg", false));
+        assert!(!config
+            .add_command_definition(CommandDefinition::new("settings", false).alias("Config")));
+        assert!(
+            !config.add_command_definition(CommandDefinition::new("options", false).alias("CFG"))
+        );
+        assert!(config.add_command("Ping", true));
+
+        let config_command = config.commands.find("CFG").expect("alias matches");
+        assert_eq!("config", config_command.name());
+        assert!(config_command.subcommands.find("Role").is_some());
+        assert!(config.commands.find("Ping").is_some());
+        assert!(config.commands.find("ping").is_none());
+
+        config.remove_command("cfg");
+        assert!(config.commands.find("config").is_none());
+        assert!(config.commands.find("Ping").is_some());
+        assert_eq!(1, config.commands().len());
+    }
+
+    #[test]
+    fn test_commands_mut_rename() {
+        let mut config = CommandParserConfig::new();
+        config.add_command("ping", true);
+        config.add_command("echo", false);
+
+        for (name, _) in config.commands_mut() {
+            if name == "ping" {
+                name.make_ascii_uppercase();
+            }
+        }
+
+        assert!(config.commands.find("PING").is_some());
+        assert!(config.commands.find("ping").is_none());
+        assert!(config.commands.find("echo").is_some());
+
+        config.add_command("pong", false);
+        assert!(config.commands.find("PING").is_some());
+        assert!(config.commands.find("pong").is_some());
+    }
 }
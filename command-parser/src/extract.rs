@@ -0,0 +1,421 @@
+//! Typed extraction of command arguments.
+//!
+//! Values implementing [`FromArgument`] can be parsed out of [`Arguments`]
+//! via [`Arguments::parse`], as well as optional values via
+//! [`Arguments::parse_optional`], all remaining values via
+//! [`Arguments::parse_variadic`], and the rest of the line as one value via
+//! [`Arguments::parse_rest`].
+//!
+//! Implementations are provided for numbers, booleans, strings, durations, and
+//! [`ChannelId`]s, [`RoleId`]s, and [`UserId`]s in either mention or raw ID
+//! form. Enums with a fixed set of values can implement [`Choices`] to be
+//! parsed by name.
+//!
+//! # Examples
+//!
+//! Parse a user, an optional duration, and a reason out of the arguments of a
+//! `!mute` command:
+//!
+//! ```
+//! use std::time::Duration;
+//! use twilight_command_parser::Arguments;
+//! use twilight_model::id::UserId;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut args = Arguments::new("<@123> 1h30m being too loud");
+//!
+//! let user_id = args.parse::<UserId>()?;
+//! let duration = args.parse_optional::<Duration>()?;
+//! let reason = args.parse_rest::<String>()?;
+//!
+//! assert_eq!(123, user_id.get());
+//! assert_eq!(Some(Duration::from_secs(5400)), duration);
+//! assert_eq!("being too loud", reason);
+//! # Ok(()) }
+//! ```
+//!
+//! [`Arguments`]: crate::Arguments
+//! [`Arguments::parse`]: crate::Arguments::parse
+//! [`Arguments::parse_optional`]: crate::Arguments::parse_optional
+//! [`Arguments::parse_rest`]: crate::Arguments::parse_rest
+//! [`Arguments::parse_variadic`]: crate::Arguments::parse_variadic
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::Range,
+    time::Duration,
+};
+use twilight_mention::ParseMention;
+use twilight_model::id::{ChannelId, RoleId, UserId};
+
+/// Value that can be parsed out of a single command argument.
+///
+/// # Examples
+///
+/// Implement parsing of a hex color:
+///
+/// ```
+/// use std::error::Error;
+/// use twilight_command_parser::{Arguments, FromArgument};
+///
+/// struct Color(u32);
+///
+/// impl FromArgument for Color {
+///     const EXPECTED: &'static str = "hex color";
+///
+///     fn from_argument(value: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+///         let hex = value.strip_prefix('#').unwrap_or(value);
+///
+///         Ok(Self(u32::from_str_radix(hex, 16)?))
+///     }
+/// }
+///
+/// let mut args = Arguments::new("#ff0000");
+/// assert_eq!(0xff_00_00, args.parse::<Color>().unwrap().0);
+/// ```
+pub trait FromArgument: Sized {
+    /// Description of the expected value, used in error messages.
+    ///
+    /// For example, `"integer"` or `"user"`.
+    const EXPECTED: &'static str;
+
+    /// Parse a value out of an argument.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the argument isn't a valid value of the type.
+    fn from_argument(value: &str) -> Result<Self, Box<dyn Error + Send + Sync>>;
+}
+
+/// Enum with a fixed set of values parsed by name.
+///
+/// Names are matched case-insensitively.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_command_parser::{extract::Choices, Arguments};
+///
+/// #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// enum Volume {
+///     Low,
+///     High,
+/// }
+///
+/// impl Choices for Volume {
+///     const CHOICES: &'static [(&'static str, Self)] = &[("low", Self::Low), ("high", Self::High)];
+/// }
+///
+/// let mut args = Arguments::new("HIGH");
+/// assert_eq!(Volume::High, args.parse::<Volume>().unwrap());
+/// ```
+pub trait Choices: Clone + 'static {
+    /// Names of the values and the values themselves.
+    const CHOICES: &'static [(&'static str, Self)];
+}
+
+impl<T: Choices> FromArgument for T {
+    const EXPECTED: &'static str = "choice";
+
+    fn from_argument(value: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        T::CHOICES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(value))
+            .map(|(_, choice)| choice.clone())
+            .ok_or_else(|| {
+                let names = T::CHOICES
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                Box::from(format!("expected one of: {}", names))
+            })
+    }
+}
+
+/// Implement [`FromArgument`] for types implementing [`FromStr`].
+///
+/// [`FromStr`]: std::str::FromStr
+macro_rules! impl_from_str {
+    ($expected: literal: $($ty: ty),+) => {
+        $(
+            impl FromArgument for $ty {
+                const EXPECTED: &'static str = $expected;
+
+                fn from_argument(value: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+                    Ok(value.parse()?)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_str!("integer": i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_from_str!("number": f32, f64);
+impl_from_str!("text": String);
+
+impl FromArgument for bool {
+    const EXPECTED: &'static str = "boolean";
+
+    fn from_argument(value: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        const FALSE: &[&str] = &["false", "no", "off", "disable"];
+        const TRUE: &[&str] = &["true", "yes", "on", "enable"];
+
+        if TRUE.iter().any(|name| name.eq_ignore_ascii_case(value)) {
+            Ok(true)
+        } else if FALSE.iter().any(|name| name.eq_ignore_ascii_case(value)) {
+            Ok(false)
+        } else {
+            Err(Box::from("expected one of: true, false, yes, no, on, off"))
+        }
+    }
+}
+
+/// Durations are a sequence of numbers with a unit, such as `1h30m`.
+///
+/// Supported units are `w` (weeks), `d` (days), `h` (hours), `m` (minutes),
+/// and `s` (seconds). A number without a unit is a number of seconds.
+impl FromArgument for Duration {
+    const EXPECTED: &'static str = "duration";
+
+    fn from_argument(value: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        if value.is_empty() {
+            return Err(Box::from("duration is empty"));
+        }
+
+        if let Ok(seconds) = value.parse() {
+            return Ok(Self::from_secs(seconds));
+        }
+
+        let mut seconds = 0_u64;
+        let mut rest = value;
+
+        while !rest.is_empty() {
+            let digits = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .ok_or("duration is missing a unit")?;
+
+            if digits == 0 {
+                return Err(Box::from("expected a number before the unit"));
+            }
+
+            let amount = rest[..digits].parse::<u64>()?;
+            let mut chars = rest[digits..].chars();
+
+            let multiplier = match chars.next() {
+                Some('w') => 604_800,
+                Some('d') => 86_400,
+                Some('h') => 3_600,
+                Some('m') => 60,
+                Some('s') => 1,
+                _ => return Err(Box::from("unit must be one of: w, d, h, m, s")),
+            };
+
+            seconds = amount
+                .checked_mul(multiplier)
+                .and_then(|amount| seconds.checked_add(amount))
+                .ok_or("duration is too long")?;
+            rest = chars.as_str();
+        }
+
+        Ok(Self::from_secs(seconds))
+    }
+}
+
+/// Implement [`FromArgument`] for IDs that can be mentioned.
+///
+/// Both mentions and raw IDs are accepted.
+macro_rules! impl_mention {
+    ($($expected: literal: $ty: ty),+) => {
+        $(
+            impl FromArgument for $ty {
+                const EXPECTED: &'static str = $expected;
+
+                fn from_argument(value: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+                    if let Some(id) = value.parse().ok().and_then(Self::new) {
+                        return Ok(id);
+                    }
+
+                    // Mention parsing errors borrow the value, so they can't
+                    // be a source.
+                    <$ty>::parse(value).map_err(|source| Box::from(source.to_string()))
+                }
+            }
+        )+
+    };
+}
+
+impl_mention!("channel": ChannelId, "role": RoleId, "user": UserId);
+
+/// Extracting a typed argument failed.
+#[derive(Debug)]
+pub struct ArgumentError {
+    pub(crate) kind: ArgumentErrorType,
+    pub(crate) source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ArgumentError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ArgumentErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ArgumentErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for ArgumentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ArgumentErrorType::Invalid {
+                expected,
+                found,
+                position,
+            } => {
+                f.write_str("argument '")?;
+                f.write_str(found)?;
+                f.write_str("' at position ")?;
+                Display::fmt(&position.start, f)?;
+                f.write_str(" is not a valid ")?;
+                f.write_str(expected)?;
+
+                if let Some(source) = &self.source {
+                    f.write_str(": ")?;
+                    Display::fmt(source, f)?;
+                }
+
+                Ok(())
+            }
+            ArgumentErrorType::Missing { expected, position } => {
+                f.write_str("expected an argument of type ")?;
+                f.write_str(expected)?;
+                f.write_str(" at position ")?;
+
+                Display::fmt(position, f)
+            }
+        }
+    }
+}
+
+impl Error for ArgumentError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ArgumentError`] that occurred.
+#[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ArgumentErrorType {
+    /// Argument isn't a valid value.
+    Invalid {
+        /// Description of the expected value.
+        expected: &'static str,
+        /// Argument that failed to parse.
+        found: String,
+        /// Byte range of the argument in [`Arguments::as_str`].
+        ///
+        /// [`Arguments::as_str`]: crate::Arguments::as_str
+        position: Range<usize>,
+    },
+    /// Required argument wasn't provided.
+    Missing {
+        /// Description of the expected value.
+        expected: &'static str,
+        /// Byte position in [`Arguments::as_str`] where the argument was
+        /// expected, which is the end of the buffer.
+        ///
+        /// [`Arguments::as_str`]: crate::Arguments::as_str
+        position: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArgumentError, ArgumentErrorType, Choices, FromArgument};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug, time::Duration};
+    use twilight_model::id::{ChannelId, RoleId, UserId};
+
+    assert_fields!(ArgumentErrorType::Invalid: expected, found, position);
+    assert_fields!(ArgumentErrorType::Missing: expected, position);
+    assert_impl_all!(ArgumentErrorType: Debug, Send, Sync);
+    assert_impl_all!(ArgumentError: Error, Send, Sync);
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    enum Volume {
+        Low,
+        High,
+    }
+
+    impl Choices for Volume {
+        const CHOICES: &'static [(&'static str, Self)] =
+            &[("low", Self::Low), ("high", Self::High)];
+    }
+
+    #[test]
+    fn test_primitives() {
+        assert_eq!(-5, i32::from_argument("-5").unwrap());
+        assert!(u8::from_argument("256").is_err());
+        assert!((f64::from_argument("1.5").unwrap() - 1.5).abs() < f64::EPSILON);
+        assert!(bool::from_argument("Yes").unwrap());
+        assert!(!bool::from_argument("off").unwrap());
+        assert!(bool::from_argument("maybe").is_err());
+        assert_eq!("a", String::from_argument("a").unwrap());
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(
+            Duration::from_secs(90),
+            Duration::from_argument("90").unwrap()
+        );
+        assert_eq!(
+            Duration::from_secs(5_400),
+            Duration::from_argument("1h30m").unwrap()
+        );
+        assert_eq!(
+            Duration::from_secs(694_861),
+            Duration::from_argument("1w1d1h1m1s").unwrap()
+        );
+        assert!(Duration::from_argument("").is_err());
+        assert!(Duration::from_argument("h").is_err());
+        assert!(Duration::from_argument("1h30").is_err());
+        assert!(Duration::from_argument("5y").is_err());
+    }
+
+    #[test]
+    fn test_ids() {
+        assert_eq!(1, UserId::from_argument("<@1>").unwrap().get());
+        assert_eq!(1, UserId::from_argument("<@!1>").unwrap().get());
+        assert_eq!(2, UserId::from_argument("2").unwrap().get());
+        assert_eq!(3, RoleId::from_argument("<@&3>").unwrap().get());
+        assert_eq!(4, ChannelId::from_argument("<#4>").unwrap().get());
+        assert!(UserId::from_argument("<#4>").is_err());
+        assert!(UserId::from_argument("0").is_err());
+    }
+
+    #[test]
+    fn test_choices() {
+        assert_eq!(Volume::Low, Volume::from_argument("low").unwrap());
+        assert_eq!(Volume::High, Volume::from_argument("HIGH").unwrap());
+        assert_eq!(
+            "expected one of: low, high",
+            Volume::from_argument("medium").unwrap_err().to_string()
+        );
+    }
+}
@@ -70,6 +70,8 @@
 #![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
 
 pub mod config;
+pub mod extract;
+pub mod prefix;
 
 mod arguments;
 mod casing;
@@ -77,6 +79,8 @@ mod parser;
 
 pub use self::{
     arguments::Arguments,
-    config::CommandParserConfig,
-    parser::{Command, Parser},
+    config::{CommandDefinition, CommandParserConfig},
+    extract::FromArgument,
+    parser::{Command, CommandPath, Parser},
+    prefix::PrefixResolver,
 };
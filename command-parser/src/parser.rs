@@ -1,4 +1,10 @@
-use crate::{Arguments, CommandParserConfig};
+use crate::{
+    config::CommandSet,
+    prefix::{self, PrefixContext, PrefixResolver},
+    Arguments, CommandParserConfig,
+};
+use std::str::SplitWhitespace;
+use twilight_model::{channel::Message, id::UserId};
 
 /// Indicator that a command was used.
 #[derive(Clone, Debug)]
@@ -8,11 +14,65 @@ pub struct Command<'a> {
     /// how to use it.
     pub arguments: Arguments<'a>,
     /// The name of the command that was called.
+    ///
+    /// If a subcommand was called, then this is the name of the top-level
+    /// command.
     pub name: &'a str,
     /// The prefix used to call the command.
     pub prefix: &'a str,
 }
 
+impl<'a> Command<'a> {
+    /// Iterator over the names of the command and any subcommands that were
+    /// called.
+    ///
+    /// For example, calling the `add` subcommand of the `role` subcommand of
+    /// the `config` command results in `["config", "role", "add"]`. Names are
+    /// the configured names, even if the command was called by an alias.
+    pub fn path(&self) -> CommandPath<'a> {
+        self.arguments.path.clone()
+    }
+}
+
+/// Iterator over the names of a called command and its subcommands, returned
+/// by [`Command::path`].
+#[derive(Clone, Debug)]
+pub struct CommandPath<'a> {
+    /// Commands that the next name is looked up in.
+    commands: &'a CommandSet,
+    /// Names, or aliases, that the command and its subcommands were called by.
+    names: SplitWhitespace<'a>,
+}
+
+impl<'a> CommandPath<'a> {
+    /// Create an empty path.
+    pub(crate) fn empty() -> Self {
+        static COMMANDS: CommandSet = CommandSet::new();
+
+        Self::new(&COMMANDS, "")
+    }
+
+    /// Create a path of the commands called by names, looked up starting
+    /// with a set of commands.
+    fn new(commands: &'a CommandSet, names: &'a str) -> Self {
+        Self {
+            commands,
+            names: names.split_whitespace(),
+        }
+    }
+}
+
+impl<'a> Iterator for CommandPath<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let command = self.commands.find(self.names.next()?)?;
+        self.commands = &command.subcommands;
+
+        Some(command.name())
+    }
+}
+
 /// A struct to parse prefixes, commands, and arguments out of messages.
 ///
 /// While parsing, the parser takes into account the configuration that it was
@@ -79,7 +139,7 @@ impl<'a> Parser<'a> {
     ///
     /// If a configured prefix and command are in the buffer, then some
     /// [`Command`] is returned with them and a lazy iterator of the
-    /// argument list.
+    /// argument list. If several prefixes match, then the longest is used.
     ///
     /// If a matching prefix or command weren't found, then `None` is returned.
     ///
@@ -118,42 +178,142 @@ impl<'a> Parser<'a> {
             return None;
         }
 
-        let mut idx = prefix.len();
-        let command_buf = buf.get(idx..)?;
-        let command = self.find_command(command_buf)?;
-
-        idx += command.len();
+        let start = prefix.len();
+        let mut idx = start;
+        let mut commands = &self.config.commands;
+        let mut name = None;
+
+        // Match the longest path of commands and subcommands, each being
+        // looked up in the index of its parent.
+        loop {
+            let command_buf = buf.get(idx..)?;
+            let word = match command_buf.split_whitespace().next() {
+                Some(word) => word,
+                None => break,
+            };
+            let command = match commands.find(word) {
+                Some(command) => command,
+                None => break,
+            };
+
+            // Advance from the amount of whitespace that was before the name
+            // and the name itself.
+            idx += command_buf.len() - command_buf.trim_start().len() + word.len();
+
+            name.get_or_insert(command.name());
+            commands = &command.subcommands;
+        }
 
-        // Advance from the amount of whitespace that was between the prefix and
-        // the command name.
-        idx += command_buf.len() - command_buf.trim_start().len();
+        let mut arguments = Arguments::new(buf.get(idx..)?);
+        arguments.path = CommandPath::new(&self.config.commands, buf.get(start..idx)?);
 
         Some(Command {
-            arguments: Arguments::new(buf.get(idx..)?),
-            name: command,
+            arguments,
+            name: name?,
             prefix,
         })
     }
 
-    fn find_command(&'a self, buf: &'a str) -> Option<&'a str> {
-        let buf = buf.split_whitespace().next()?;
-        self.config.commands.iter().find_map(|command| {
-            if command == buf {
-                Some(command.as_ref())
-            } else {
-                None
-            }
-        })
+    /// Parse a command out of a message, with prefixes resolved for it.
+    ///
+    /// A mention of the bot, or of the configured mention prefix, is matched
+    /// first. Otherwise the prefixes returned by the resolver, allowing for
+    /// prefixes that differ per guild, are matched along with the prefixes of
+    /// the configuration, unless the resolver [overrides] them. As with
+    /// [`parse`], the longest matching prefix is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{future::Future, pin::Pin};
+    /// use twilight_command_parser::{
+    ///     prefix::{PrefixContext, PrefixResolver, ResolvedPrefixes},
+    ///     CommandParserConfig, Parser,
+    /// };
+    /// # use twilight_model::{channel::Message, id::UserId};
+    ///
+    /// struct Resolver;
+    ///
+    /// impl PrefixResolver for Resolver {
+    ///     fn prefixes<'a>(
+    ///         &'a self,
+    ///         _: PrefixContext<'a>,
+    ///     ) -> Pin<Box<dyn Future<Output = ResolvedPrefixes> + Send + 'a>> {
+    ///         Box::pin(async { ResolvedPrefixes::Additional(vec![String::from("?")]) })
+    ///     }
+    /// }
+    ///
+    /// # async fn example(message: &Message, bot_id: UserId) {
+    /// let mut config = CommandParserConfig::new();
+    /// config.add_prefix("!");
+    /// config.add_command("ping", false);
+    /// let parser = Parser::new(config);
+    ///
+    /// if let Some(command) = parser.parse_message(message, bot_id, &Resolver).await {
+    ///     println!("{} called with prefix {}", command.name, command.prefix);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`parse`]: Self::parse
+    /// [overrides]: crate::prefix::ResolvedPrefixes::Override
+    pub async fn parse_message<R: PrefixResolver + ?Sized>(
+        &'a self,
+        message: &'a Message,
+        bot_id: UserId,
+        resolver: &R,
+    ) -> Option<Command<'a>> {
+        let buf = message.content.as_str();
+        let mention = prefix::mention_len(buf, bot_id)
+            .and_then(|len| buf.get(..len))
+            .or_else(|| self.find_mention_prefix(buf));
+
+        if let Some(prefix) = mention {
+            return self.parse_with_prefix(prefix, buf);
+        }
+
+        let context = PrefixContext {
+            bot_id,
+            guild_id: message.guild_id,
+            message,
+        };
+        let prefixes = resolver.prefixes(context).await;
+        let configured = if prefixes.is_override() {
+            None
+        } else {
+            Some(self.config.prefixes.iter().map(AsRef::as_ref))
+        };
+        let len = prefixes
+            .prefixes()
+            .iter()
+            .map(String::as_str)
+            .chain(configured.into_iter().flatten())
+            .filter(|prefix| buf.starts_with(prefix))
+            .map(str::len)
+            .max()?;
+
+        // Use the prefix from the buffer, since the resolved prefixes are only
+        // valid for the duration of this call.
+        self.parse_with_prefix(buf.get(..len)?, buf)
     }
 
-    fn find_prefix(&self, buf: &str) -> Option<&str> {
-        self.config.prefixes.iter().find_map(|prefix| {
-            if buf.starts_with(prefix.as_ref()) {
-                Some(prefix.as_ref())
-            } else {
-                None
-            }
-        })
+    fn find_mention_prefix(&self, buf: &'a str) -> Option<&'a str> {
+        let len = prefix::mention_len(buf, self.config.mention_prefix?)?;
+
+        buf.get(..len)
+    }
+
+    fn find_prefix(&'a self, buf: &'a str) -> Option<&'a str> {
+        if let Some(prefix) = self.find_mention_prefix(buf) {
+            return Some(prefix);
+        }
+
+        self.config
+            .prefixes
+            .iter()
+            .map(AsRef::as_ref)
+            .filter(|prefix| buf.starts_with(prefix))
+            .max_by_key(|prefix| prefix.len())
     }
 }
 
@@ -165,12 +325,23 @@ impl<'a, T: Into<CommandParserConfig<'a>>> From<T> for Parser<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Command, CommandParserConfig, Parser};
+    use super::CommandPath;
+    use crate::{
+        prefix::{PrefixContext, PrefixResolver, ResolvedPrefixes},
+        Command, CommandDefinition, CommandParserConfig, Parser,
+    };
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::fmt::Debug;
+    use std::{fmt::Debug, future::Future, pin::Pin};
+    use twilight_model::{
+        channel::{message::MessageType, Message},
+        datetime::Timestamp,
+        id::{ChannelId, GuildId, MessageId, UserId},
+        user::User,
+    };
 
     assert_fields!(Command<'_>: arguments, name, prefix);
     assert_impl_all!(Command<'_>: Clone, Debug, Send, Sync);
+    assert_impl_all!(CommandPath<'_>: Clone, Debug, Iterator, Send, Sync);
     assert_impl_all!(Parser<'_>: Clone, Debug, Send, Sync);
 
     fn simple_config() -> Parser<'static> {
@@ -305,4 +476,207 @@ mod tests {
         assert_eq!(Some("test"), arguments.next());
         assert!(arguments.next().is_none());
     }
+
+    #[test]
+    fn test_subcommands() {
+        let mut parser = simple_config();
+        parser.config_mut().add_command_definition(
+            CommandDefinition::new("config", false)
+                .alias("cfg")
+                .subcommand(
+                    CommandDefinition::new("role", true)
+                        .subcommand(CommandDefinition::new("add", true).alias("+")),
+                ),
+        );
+
+        let command = parser.parse("!config  role add <@&1>").unwrap();
+        assert_eq!("config", command.name);
+        assert_eq!(
+            vec!["config", "role", "add"],
+            command.path().collect::<Vec<_>>()
+        );
+        assert_eq!("<@&1>", command.arguments.as_str());
+
+        let command = parser.parse("!CFG role +").unwrap();
+        assert_eq!(
+            vec!["config", "role", "add"],
+            command.path().collect::<Vec<_>>()
+        );
+        assert_eq!("", command.arguments.as_str());
+
+        // The subcommand is case sensitive, so only the group matches.
+        let command = parser.parse("!cfg ROLE add").unwrap();
+        assert_eq!(vec!["config"], command.path().collect::<Vec<_>>());
+        assert_eq!("ROLE add", command.arguments.as_str());
+
+        let command = parser.parse("!echo config").unwrap();
+        assert_eq!(vec!["echo"], command.path().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_mention_prefix() {
+        let mut parser = simple_config();
+        parser.config_mut().set_mention_prefix(UserId::new(123));
+
+        let command = parser.parse("<@123> echo foo").unwrap();
+        assert_eq!("<@123>", command.prefix);
+        assert_eq!("echo", command.name);
+        assert_eq!("foo", command.arguments.as_str());
+
+        let command = parser.parse("<@!123>echo").unwrap();
+        assert_eq!("<@!123>", command.prefix);
+
+        assert!(parser.parse("<@456> echo").is_none());
+        assert!(parser.parse("!echo").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_parse_message() {
+        struct Resolver;
+
+        impl PrefixResolver for Resolver {
+            fn prefixes<'a>(
+                &'a self,
+                context: PrefixContext<'a>,
+            ) -> Pin<Box<dyn Future<Output = ResolvedPrefixes> + Send + 'a>> {
+                Box::pin(async move {
+                    assert_eq!(UserId::new(4).expect("non zero"), context.bot_id);
+
+                    match context.guild_id.map(GuildId::get) {
+                        Some(1) => ResolvedPrefixes::Additional(vec![
+                            String::from("?"),
+                            String::from("?!"),
+                        ]),
+                        Some(2) => ResolvedPrefixes::Override(vec![String::from("?")]),
+                        _ => ResolvedPrefixes::Additional(Vec::new()),
+                    }
+                })
+            }
+        }
+
+        let bot_id = UserId::new(4).expect("non zero");
+        let parser = simple_config();
+        let mut message = message("?echo foo");
+
+        let command = parser
+            .parse_message(&message, bot_id, &Resolver)
+            .await
+            .unwrap();
+        assert_eq!("?", command.prefix);
+        assert_eq!("foo", command.arguments.as_str());
+
+        // The longest matching prefix is used.
+        message.content = String::from("?!echo foo");
+        let command = parser
+            .parse_message(&message, bot_id, &Resolver)
+            .await
+            .unwrap();
+        assert_eq!("?!", command.prefix);
+        assert_eq!("foo", command.arguments.as_str());
+
+        message.content = String::from("!echo");
+        let command = parser
+            .parse_message(&message, bot_id, &Resolver)
+            .await
+            .unwrap();
+        assert_eq!("!", command.prefix);
+
+        message.content = String::from("?echo");
+        message.guild_id = None;
+        assert!(parser
+            .parse_message(&message, bot_id, &Resolver)
+            .await
+            .is_none());
+
+        // The guild changed the prefix from "!" to "?".
+        message.guild_id = GuildId::new(2);
+        let command = parser
+            .parse_message(&message, bot_id, &Resolver)
+            .await
+            .unwrap();
+        assert_eq!("?", command.prefix);
+
+        message.content = String::from("!echo");
+        assert!(parser
+            .parse_message(&message, bot_id, &Resolver)
+            .await
+            .is_none());
+
+        // Mentions of the bot are prefixes without a configured mention
+        // prefix.
+        for content in ["<@4> echo", "<@!4>echo"] {
+            message.content = String::from(content);
+            let command = parser
+                .parse_message(&message, bot_id, &Resolver)
+                .await
+                .unwrap();
+            assert_eq!(&content[..=content.find('>').unwrap()], command.prefix);
+        }
+
+        message.content = String::from("<@5> echo");
+        assert!(parser
+            .parse_message(&message, bot_id, &Resolver)
+            .await
+            .is_none());
+    }
+
+    #[test]
+    fn test_longest_prefix() {
+        let mut parser = simple_config();
+        parser.config_mut().add_prefix("!!");
+
+        let command = parser.parse("!!echo foo").unwrap();
+        assert_eq!("!!", command.prefix);
+        assert_eq!("foo", command.arguments.as_str());
+    }
+
+    fn message(content: &str) -> Message {
+        Message {
+            activity: None,
+            application: None,
+            application_id: None,
+            attachments: Vec::new(),
+            author: User {
+                accent_color: None,
+                avatar: None,
+                banner: None,
+                bot: false,
+                discriminator: 1,
+                email: None,
+                flags: None,
+                id: UserId::new(2).expect("non zero"),
+                locale: None,
+                mfa_enabled: None,
+                name: "twilight".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            },
+            channel_id: ChannelId::new(1).expect("non zero"),
+            components: Vec::new(),
+            content: content.to_owned(),
+            edited_timestamp: None,
+            embeds: Vec::new(),
+            flags: None,
+            guild_id: GuildId::new(1),
+            id: MessageId::new(3).expect("non zero"),
+            interaction: None,
+            kind: MessageType::Regular,
+            member: None,
+            mention_channels: Vec::new(),
+            mention_everyone: false,
+            mention_roles: Vec::new(),
+            mentions: Vec::new(),
+            pinned: false,
+            reactions: Vec::new(),
+            reference: None,
+            referenced_message: None,
+            sticker_items: Vec::new(),
+            timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+            thread: None,
+            tts: false,
+            webhook_id: None,
+        }
+    }
 }
@@ -0,0 +1,150 @@
+//! Prefixes that depend on the message being parsed.
+//!
+//! Prefixes configured via [`CommandParserConfig::add_prefix`] apply
+//! everywhere. Prefixes that differ per guild, such as ones configured by
+//! guild administrators and stored in a database, can be provided by a
+//! [`PrefixResolver`] and used via [`Parser::parse_message`]. Resolved
+//! prefixes can either be used in addition to the configured prefixes or
+//! replace them.
+//!
+//! [`CommandParserConfig::add_prefix`]: crate::CommandParserConfig::add_prefix
+//! [`Parser::parse_message`]: crate::Parser::parse_message
+
+use std::{future::Future, pin::Pin};
+use twilight_model::{
+    channel::Message,
+    id::{GuildId, UserId},
+};
+
+/// Context of a message that prefixes are resolved for.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct PrefixContext<'a> {
+    /// ID of the bot user.
+    pub bot_id: UserId,
+    /// ID of the guild the message was sent in, if any.
+    pub guild_id: Option<GuildId>,
+    /// Message being parsed.
+    pub message: &'a Message,
+}
+
+/// Prefixes resolved for a message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ResolvedPrefixes {
+    /// Prefixes to use in addition to the prefixes of the configuration.
+    Additional(Vec<String>),
+    /// Prefixes to use instead of the prefixes of the configuration.
+    ///
+    /// The [mention prefix] still applies.
+    ///
+    /// [mention prefix]: crate::CommandParserConfig::set_mention_prefix
+    Override(Vec<String>),
+}
+
+impl ResolvedPrefixes {
+    /// Resolved prefixes.
+    pub fn prefixes(&self) -> &[String] {
+        match self {
+            Self::Additional(prefixes) | Self::Override(prefixes) => prefixes,
+        }
+    }
+
+    /// Whether the prefixes of the configuration are replaced.
+    pub const fn is_override(&self) -> bool {
+        matches!(self, Self::Override(_))
+    }
+}
+
+impl From<Vec<String>> for ResolvedPrefixes {
+    fn from(prefixes: Vec<String>) -> Self {
+        Self::Additional(prefixes)
+    }
+}
+
+/// Resolver of the prefixes to parse a message with.
+///
+/// # Examples
+///
+/// Resolve prefixes from a map of guild prefixes, which could be a database
+/// lookup instead, replacing the configured prefixes in guilds that have one:
+///
+/// ```
+/// use std::{collections::HashMap, future::Future, pin::Pin};
+/// use twilight_command_parser::prefix::{PrefixContext, PrefixResolver, ResolvedPrefixes};
+/// use twilight_model::id::GuildId;
+///
+/// struct GuildPrefixes(HashMap<GuildId, String>);
+///
+/// impl PrefixResolver for GuildPrefixes {
+///     fn prefixes<'a>(
+///         &'a self,
+///         context: PrefixContext<'a>,
+///     ) -> Pin<Box<dyn Future<Output = ResolvedPrefixes> + Send + 'a>> {
+///         Box::pin(async move {
+///             match context.guild_id.and_then(|guild_id| self.0.get(&guild_id)) {
+///                 Some(prefix) => ResolvedPrefixes::Override(vec![prefix.clone()]),
+///                 None => ResolvedPrefixes::Additional(Vec::new()),
+///             }
+///         })
+///     }
+/// }
+/// ```
+pub trait PrefixResolver {
+    /// Resolve the prefixes to parse a message with.
+    ///
+    /// This is called for every message that doesn't start with the mention
+    /// prefix.
+    fn prefixes<'a>(
+        &'a self,
+        context: PrefixContext<'a>,
+    ) -> Pin<Box<dyn Future<Output = ResolvedPrefixes> + Send + 'a>>;
+}
+
+/// Length of a mention of a user at the start of a buffer, if any.
+///
+/// Both `<@id>` and `<@!id>` mentions are matched.
+pub(crate) fn mention_len(buf: &str, user_id: UserId) -> Option<usize> {
+    let rest = buf.strip_prefix("<@")?;
+    let rest = rest.strip_prefix('!').unwrap_or(rest);
+    let end = rest.find('>')?;
+
+    if rest[..end].parse::<u64>().ok()? != user_id.get() {
+        return None;
+    }
+
+    Some(buf.len() - rest.len() + end + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PrefixContext, PrefixResolver, ResolvedPrefixes};
+    use static_assertions::{assert_fields, assert_impl_all, assert_obj_safe};
+    use std::fmt::Debug;
+    use twilight_model::id::UserId;
+
+    assert_fields!(PrefixContext<'_>: bot_id, guild_id, message);
+    assert_impl_all!(PrefixContext<'_>: Clone, Copy, Debug, Send, Sync);
+    assert_impl_all!(
+        ResolvedPrefixes: Clone,
+        Debug,
+        Eq,
+        From<Vec<String>>,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_obj_safe!(PrefixResolver);
+
+    #[test]
+    fn test_mention_len() {
+        let user_id = UserId::new(123).expect("non zero");
+
+        assert_eq!(Some(6), super::mention_len("<@123> ping", user_id));
+        assert_eq!(Some(7), super::mention_len("<@!123>ping", user_id));
+        assert_eq!(None, super::mention_len("<@1234> ping", user_id));
+        assert_eq!(None, super::mention_len("<@&123> ping", user_id));
+        assert_eq!(None, super::mention_len("<@123 ping", user_id));
+        assert_eq!(None, super::mention_len("!ping", user_id));
+    }
+}
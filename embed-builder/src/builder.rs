@@ -1,6 +1,6 @@
 //! Create embeds.
 
-use super::image_source::ImageSource;
+use super::{image_source::ImageSource, page};
 use std::{
     convert::TryFrom,
     error::Error,
@@ -470,6 +470,63 @@ impl EmbedBuilder {
         Ok(self.0)
     }
 
+    /// Build this into as many embeds as needed to fit its description and
+    /// fields, such as to page through them.
+    ///
+    /// The description is split over the first pages at line breaks, followed
+    /// by the fields. Each page respects [`DESCRIPTION_LENGTH_LIMIT`],
+    /// [`EMBED_FIELD_LIMIT`] and [`EMBED_LENGTH_LIMIT`], and field values
+    /// longer than [`FIELD_VALUE_LENGTH_LIMIT`] are continued in additional
+    /// fields. The rest of the embed, such as the title, author, footer and
+    /// color, is carried over to every page.
+    ///
+    /// If more than one page is needed then the page number is appended to
+    /// the footer text of every page, such as "Page 2/3".
+    ///
+    /// # Examples
+    ///
+    /// Page through a list of members:
+    ///
+    /// ```
+    /// use twilight_embed_builder::{EmbedBuilder, EmbedFieldBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pages = (1..=100)
+    ///     .fold(EmbedBuilder::new().title("Members"), |builder, index| {
+    ///         builder.field(EmbedFieldBuilder::new(format!("Member {}", index), "Joined today"))
+    ///     })
+    ///     .build_pages()?;
+    ///
+    /// assert_eq!(4, pages.len());
+    /// assert_eq!("Page 1/4", pages[0].footer.as_ref().unwrap().text);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`build`] for the content carried over to
+    /// every page, such as a title that is too long.
+    ///
+    /// Returns an [`EmbedErrorType::FieldNameEmpty`],
+    /// [`EmbedErrorType::FieldNameTooLong`] or
+    /// [`EmbedErrorType::FieldValueEmpty`] error type if a field is invalid.
+    ///
+    /// Returns an [`EmbedErrorType::FooterTextTooLong`] error type if the
+    /// footer text is too long to append the page number.
+    ///
+    /// [`DESCRIPTION_LENGTH_LIMIT`]: Self::DESCRIPTION_LENGTH_LIMIT
+    /// [`EMBED_FIELD_LIMIT`]: Self::EMBED_FIELD_LIMIT
+    /// [`EMBED_LENGTH_LIMIT`]: Self::EMBED_LENGTH_LIMIT
+    /// [`FIELD_VALUE_LENGTH_LIMIT`]: Self::FIELD_VALUE_LENGTH_LIMIT
+    /// [`build`]: Self::build
+    #[must_use = "should be used as part of something like a message"]
+    pub fn build_pages(self) -> Result<Vec<Embed>, EmbedError> {
+        page::paginate(self.0)
+            .into_iter()
+            .map(|embed| Self(embed).build())
+            .collect()
+    }
+
     /// Set the author.
     ///
     /// # Examples
@@ -67,6 +67,7 @@ mod author;
 mod builder;
 mod field;
 mod footer;
+mod page;
 
 pub use self::{
     author::EmbedAuthorBuilder,
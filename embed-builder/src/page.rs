@@ -0,0 +1,341 @@
+//! Split the content of an embed over multiple pages.
+
+use super::builder::EmbedBuilder;
+use std::mem;
+use twilight_model::channel::embed::{Embed, EmbedField, EmbedFooter};
+
+/// Name of fields continuing the value of the previous field, which is a
+/// zero-width space since names can't be empty.
+const CONTINUATION_NAME: &str = "\u{200B}";
+
+/// Separator between the text of the footer and the page number.
+const FOOTER_SEPARATOR: &str = " • ";
+
+/// Content of a page.
+#[derive(Default)]
+struct Page {
+    description: Option<String>,
+    fields: Vec<EmbedField>,
+    length: usize,
+}
+
+impl Page {
+    /// Replace the description and fields of the template with the page's.
+    fn into_embed(self, mut template: Embed) -> Embed {
+        template.description = self.description;
+        template.fields = self.fields;
+
+        template
+    }
+}
+
+/// Split the description and fields of an embed over as many pages as
+/// needed, carrying over the rest of the embed to every page.
+///
+/// Pages aren't validated, and must be built by an [`EmbedBuilder`].
+pub(crate) fn paginate(mut template: Embed) -> Vec<Embed> {
+    let description = template.description.take();
+    let fields = split_fields(mem::take(&mut template.fields));
+    let length = template_length(&template);
+
+    let pages = split_pages(description.as_deref(), &fields, length);
+
+    if pages.len() == 1 {
+        return pages
+            .into_iter()
+            .map(|page| page.into_embed(template.clone()))
+            .collect();
+    }
+
+    // Reserve room in the footer for the page numbers, increasing the number
+    // of digits reserved until it fits the number of pages.
+    let mut digits = 1;
+
+    loop {
+        let reserved = footer_suffix_length(&template, digits);
+        let pages = split_pages(description.as_deref(), &fields, length + reserved);
+        let count = pages.len();
+
+        if count >= 10_usize.pow(digits) {
+            digits += 1;
+
+            continue;
+        }
+
+        return pages
+            .into_iter()
+            .enumerate()
+            .map(|(index, page)| {
+                let mut embed = page.into_embed(template.clone());
+                embed.footer = Some(page_footer(template.footer.as_ref(), index + 1, count));
+
+                embed
+            })
+            .collect();
+    }
+}
+
+/// Footer of a page, numbered from 1.
+fn page_footer(footer: Option<&EmbedFooter>, number: usize, count: usize) -> EmbedFooter {
+    let page = format!("Page {}/{}", number, count);
+
+    match footer {
+        Some(footer) => EmbedFooter {
+            icon_url: footer.icon_url.clone(),
+            proxy_icon_url: footer.proxy_icon_url.clone(),
+            text: format!("{}{}{}", footer.text, FOOTER_SEPARATOR, page),
+        },
+        None => EmbedFooter {
+            icon_url: None,
+            proxy_icon_url: None,
+            text: page,
+        },
+    }
+}
+
+/// Number of characters added to the footer by page numbers of at most
+/// `digits` digits.
+fn footer_suffix_length(template: &Embed, digits: u32) -> usize {
+    let digits = digits as usize;
+    let separator = if template.footer.is_some() {
+        FOOTER_SEPARATOR.chars().count()
+    } else {
+        0
+    };
+
+    separator + "Page /".len() + digits * 2
+}
+
+/// Number of characters of the text carried over to every page.
+fn template_length(template: &Embed) -> usize {
+    template
+        .author
+        .as_ref()
+        .map_or(0, |author| author.name.chars().count())
+        + template
+            .footer
+            .as_ref()
+            .map_or(0, |footer| footer.text.chars().count())
+        + template
+            .title
+            .as_ref()
+            .map_or(0, |title| title.chars().count())
+}
+
+/// Split values of fields that are too long into additional fields.
+fn split_fields(fields: Vec<EmbedField>) -> Vec<EmbedField> {
+    let mut split = Vec::with_capacity(fields.len());
+
+    for field in fields {
+        if field.value.chars().count() <= EmbedBuilder::FIELD_VALUE_LENGTH_LIMIT {
+            split.push(field);
+
+            continue;
+        }
+
+        for (index, value) in split_text(&field.value, EmbedBuilder::FIELD_VALUE_LENGTH_LIMIT)
+            .into_iter()
+            .enumerate()
+        {
+            split.push(EmbedField {
+                inline: field.inline,
+                name: if index == 0 {
+                    field.name.clone()
+                } else {
+                    CONTINUATION_NAME.to_owned()
+                },
+                value: value.to_owned(),
+            });
+        }
+    }
+
+    split
+}
+
+/// Split a description and fields into pages, with `reserved` characters of
+/// every page used by the text carried over to it.
+///
+/// The description is split over the first pages, followed by the fields.
+fn split_pages(description: Option<&str>, fields: &[EmbedField], reserved: usize) -> Vec<Page> {
+    let budget = EmbedBuilder::EMBED_LENGTH_LIMIT
+        .saturating_sub(reserved)
+        .max(1);
+    let mut pages = Vec::new();
+
+    if let Some(description) = description {
+        let limit = budget.min(EmbedBuilder::DESCRIPTION_LENGTH_LIMIT);
+
+        pages.extend(
+            split_text(description, limit)
+                .into_iter()
+                .map(|chunk| Page {
+                    description: Some(chunk.to_owned()),
+                    fields: Vec::new(),
+                    length: chunk.chars().count(),
+                }),
+        );
+    }
+
+    for field in fields {
+        let length = field.name.chars().count() + field.value.chars().count();
+
+        match pages.last_mut() {
+            Some(page)
+                if page.fields.len() < EmbedBuilder::EMBED_FIELD_LIMIT
+                    && page.length + length <= budget =>
+            {
+                page.fields.push(field.clone());
+                page.length += length;
+            }
+            _ => pages.push(Page {
+                description: None,
+                fields: vec![field.clone()],
+                length,
+            }),
+        }
+    }
+
+    if pages.is_empty() {
+        pages.push(Page::default());
+    }
+
+    pages
+}
+
+/// Split text into chunks of at most `limit` characters.
+///
+/// Text is split after the last line break that fits, falling back to the
+/// last whitespace and then to any character. Whitespace around splits is
+/// trimmed.
+fn split_text(text: &str, limit: usize) -> Vec<&str> {
+    let limit = limit.max(1);
+    let mut chunks = Vec::new();
+    let mut remaining = text;
+
+    while remaining.chars().count() > limit {
+        let end = remaining
+            .char_indices()
+            .nth(limit)
+            .map_or(remaining.len(), |(index, _)| index);
+        let head = &remaining[..end];
+
+        let split = head
+            .rfind('\n')
+            .map(|index| index + 1)
+            .or_else(|| {
+                head.char_indices()
+                    .rev()
+                    .find(|(_, character)| character.is_whitespace())
+                    .map(|(index, character)| index + character.len_utf8())
+            })
+            .unwrap_or(end);
+
+        let chunk = head[..split].trim_end();
+
+        if !chunk.is_empty() {
+            chunks.push(chunk);
+        }
+
+        remaining = remaining[split..].trim_start_matches('\n');
+    }
+
+    if chunks.is_empty() {
+        chunks.push(remaining);
+    } else if !remaining.trim_end().is_empty() {
+        chunks.push(remaining.trim_end());
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_text;
+    use crate::{EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder};
+
+    #[test]
+    fn test_split_text() {
+        assert_eq!(vec!["short"], split_text("short", 10));
+        assert_eq!(
+            vec!["aaaa\nbbbb", "cccc"],
+            split_text("aaaa\nbbbb\ncccc", 10)
+        );
+        assert_eq!(vec!["aa bb", "cc dd"], split_text("aa bb cc dd", 6));
+        assert_eq!(vec!["abc", "def", "gh"], split_text("abcdefgh", 3));
+    }
+
+    #[test]
+    fn test_single_page() {
+        let embed = EmbedBuilder::new()
+            .title("title")
+            .description("description")
+            .field(EmbedFieldBuilder::new("name", "value"));
+
+        assert_eq!(
+            vec![embed.clone().build().unwrap()],
+            embed.build_pages().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_description_pages() {
+        let line = format!("{}\n", "a".repeat(99));
+        let pages = EmbedBuilder::new()
+            .title("title")
+            .color(0x00_43_ff)
+            .footer(EmbedFooterBuilder::new("footer"))
+            .description(line.repeat(100))
+            .build_pages()
+            .unwrap();
+
+        assert_eq!(3, pages.len());
+
+        for (index, page) in pages.iter().enumerate() {
+            let description = page.description.as_deref().unwrap();
+            assert!(description.chars().count() <= EmbedBuilder::DESCRIPTION_LENGTH_LIMIT);
+            assert!(description.ends_with('a'));
+            assert_eq!(Some("title"), page.title.as_deref());
+            assert_eq!(Some(0x00_43_ff), page.color);
+            assert_eq!(
+                format!("footer • Page {}/3", index + 1),
+                page.footer.as_ref().unwrap().text
+            );
+        }
+    }
+
+    #[test]
+    fn test_field_pages() {
+        let pages = (0..60)
+            .fold(EmbedBuilder::new(), |builder, index| {
+                builder.field(EmbedFieldBuilder::new(index.to_string(), "value"))
+            })
+            .field(EmbedFieldBuilder::new("long", "a".repeat(1500)))
+            .build_pages()
+            .unwrap();
+
+        assert_eq!(3, pages.len());
+        assert_eq!(EmbedBuilder::EMBED_FIELD_LIMIT, pages[0].fields.len());
+        assert_eq!("Page 1/3", pages[0].footer.as_ref().unwrap().text);
+
+        let last = &pages[2].fields;
+        assert_eq!(12, last.len());
+        assert_eq!("long", last[10].name);
+        assert_eq!(EmbedBuilder::FIELD_VALUE_LENGTH_LIMIT, last[10].value.len());
+        assert_eq!("\u{200B}", last[11].name);
+        assert_eq!(476, last[11].value.len());
+    }
+
+    #[test]
+    fn test_total_length_pages() {
+        let pages = (0..10)
+            .fold(EmbedBuilder::new(), |builder, index| {
+                builder.field(EmbedFieldBuilder::new(index.to_string(), "a".repeat(1000)))
+            })
+            .build_pages()
+            .unwrap();
+
+        assert_eq!(2, pages.len());
+        assert_eq!(5, pages[0].fields.len());
+        assert_eq!(5, pages[1].fields.len());
+    }
+}
@@ -9,6 +9,7 @@ use tokio::{
     time::{self, Instant},
 };
 use twilight_http::Client;
+use twilight_model::gateway::SessionStartLimit;
 
 /// Creating a day limiter queue failed.
 #[derive(Debug)]
@@ -56,6 +57,18 @@ pub(crate) struct DayLimiterInner {
     pub current: u64,
 }
 
+impl DayLimiterInner {
+    /// Set the state from a session start limit retrieved just now.
+    fn apply(&mut self, limit: &SessionStartLimit) {
+        debug_assert!(limit.total >= limit.remaining);
+
+        self.last_check = Instant::now();
+        self.next_reset = Duration::from_millis(limit.reset_after);
+        self.total = limit.total;
+        self.current = limit.total.saturating_sub(limit.remaining);
+    }
+}
+
 impl DayLimiter {
     pub async fn new(http: Arc<Client>) -> Result<Self, DayLimiterError> {
         let info = http
@@ -74,48 +87,56 @@ impl DayLimiter {
                 source: Some(Box::new(source)),
             })?;
 
-        let last_check = Instant::now();
-
-        let next_reset = Duration::from_millis(info.session_start_limit.reset_after);
-        let total = info.session_start_limit.total;
-        let remaining = info.session_start_limit.remaining;
-        debug_assert!(total >= remaining);
-        let current = total - remaining;
-        Ok(DayLimiter(Mutex::new(DayLimiterInner {
+        let mut inner = DayLimiterInner {
             http,
-            last_check,
-            next_reset,
-            total: info.session_start_limit.total,
-            current,
-        })))
+            last_check: Instant::now(),
+            next_reset: Duration::from_secs(0),
+            total: 0,
+            current: 0,
+        };
+        inner.apply(&info.session_start_limit);
+
+        Ok(DayLimiter(Mutex::new(inner)))
+    }
+
+    /// Replace the limiter's state with a newly retrieved session start limit.
+    pub async fn update(&self, limit: SessionStartLimit) {
+        self.0.lock().await.apply(&limit);
     }
 
-    // `clippy::needless_return` lint doesn't work on an item scope, so it needs
-    // to be applied on the function scope.
-    #[cfg_attr(not(feature = "tracing"), allow(clippy::needless_return))]
     pub async fn get(&self) {
-        let mut lock = self.0.lock().await;
-        if lock.current < lock.total {
-            lock.current += 1;
-        } else {
-            let wait = lock.last_check + lock.next_reset;
+        loop {
+            let wait = {
+                let mut lock = self.0.lock().await;
+
+                if lock.current < lock.total {
+                    lock.current += 1;
+
+                    return;
+                }
+
+                lock.last_check + lock.next_reset
+            };
+
+            // Don't hold the lock while sleeping so that updates to the limit
+            // can be applied in the meantime.
             time::sleep_until(wait).await;
+
+            let mut lock = self.0.lock().await;
+
+            // The limit was updated while sleeping, so check it again.
+            if lock.current < lock.total || lock.last_check + lock.next_reset > wait {
+                continue;
+            }
+
             if let Ok(res) = lock.http.gateway().authed().exec().await {
                 if let Ok(info) = res.model().await {
-                    let last_check = Instant::now();
-                    let next_reset = Duration::from_millis(info.session_start_limit.remaining);
+                    lock.apply(&info.session_start_limit);
 
                     #[cfg(feature = "tracing")]
-                    tracing::info!("next session start limit reset in: {:.2?}", next_reset);
+                    tracing::info!("next session start limit reset in: {:.2?}", lock.next_reset);
 
-                    let total = info.session_start_limit.total;
-                    let remaining = info.session_start_limit.remaining;
-                    assert!(total >= remaining);
-                    let current = total - remaining;
-                    lock.last_check = last_check;
-                    lock.next_reset = next_reset;
-                    lock.total = total;
-                    lock.current = current + 1;
+                    lock.current += 1;
 
                     return;
                 }
@@ -125,6 +146,8 @@ impl DayLimiter {
             tracing::warn!(
                 "unable to get new session limits, skipping (this may cause bad things)"
             );
+
+            return;
         }
     }
 }
@@ -8,6 +8,7 @@ use tokio::{
     time::sleep,
 };
 use twilight_http::Client;
+use twilight_model::gateway::SessionStartLimit;
 
 /// Queue built for single-process clusters that require identifying via
 /// [Sharding for Very Large Bots].
@@ -101,6 +102,14 @@ impl Queue for LargeBotQueue {
             let _ = rx.await;
         })
     }
+
+    /// Update the day limiter with the new session start limit.
+    fn update_session_start_limit(
+        &'_ self,
+        limit: SessionStartLimit,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(self.limiter.update(limit))
+    }
 }
 
 #[cfg(test)]
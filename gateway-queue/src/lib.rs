@@ -50,7 +50,12 @@ mod large_bot_queue;
 pub use large_bot_queue::LargeBotQueue;
 
 use day_limiter::DayLimiter;
-use std::{fmt::Debug, future::Future, pin::Pin, time::Duration};
+use std::{
+    fmt::Debug,
+    future::{self, Future},
+    pin::Pin,
+    time::Duration,
+};
 use tokio::{
     sync::{
         mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
@@ -58,6 +63,7 @@ use tokio::{
     },
     time::sleep,
 };
+use twilight_model::gateway::SessionStartLimit;
 
 /// Queue for shards to request the ability to initialize new sessions with the
 /// gateway.
@@ -74,6 +80,21 @@ pub trait Queue: Debug + Send + Sync {
     /// The returned future must resolve only when the shard can initiate the
     /// session.
     fn request<'a>(&'a self, shard_id: [u64; 2]) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+    /// New information about the bot's session start limit has been
+    /// retrieved.
+    ///
+    /// Clusters share the session start limit with their queue every time
+    /// they retrieve it, so that queues can hold back requests once the
+    /// remaining sessions are exhausted.
+    ///
+    /// The default implementation ignores the information.
+    fn update_session_start_limit<'a>(
+        &'a self,
+        _limit: SessionStartLimit,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(future::ready(()))
+    }
 }
 
 /// A local, in-process implementation of a [`Queue`] which manages the
@@ -1,14 +1,14 @@
 use super::{
     config::Config as ClusterConfig,
-    event::Events,
+    event::{Events, LazyEvents},
     r#impl::{Cluster, ClusterStartError},
     scheme::ShardScheme,
 };
 use crate::{
-    shard::{LargeThresholdError, ResumeSession, ShardBuilder},
+    shard::{LargeThresholdError, ReconnectPolicy, ResumeSession, Shard, ShardBuilder},
     EventTypeFlags,
 };
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use twilight_gateway_queue::{LocalQueue, Queue};
 use twilight_http::Client;
 use twilight_model::gateway::{
@@ -49,6 +49,7 @@ impl ClusterBuilder {
                 shard_scheme: ShardScheme::Auto,
                 queue: Arc::new(LocalQueue::new()),
                 resume_sessions: HashMap::new(),
+                session_start_limit_interval: Some(Duration::from_secs(60 * 60)),
             },
             ShardBuilder::new(token, intents),
         )
@@ -62,7 +63,38 @@ impl ClusterBuilder {
     /// there was an HTTP error Retrieving the gateway information.
     ///
     /// [`ClusterStartErrorType::RetrievingGatewayInfo`]: super::ClusterStartErrorType::RetrievingGatewayInfo
-    pub async fn build(mut self) -> Result<(Cluster, Events), ClusterStartError> {
+    pub async fn build(self) -> Result<(Cluster, Events), ClusterStartError> {
+        let this = self.resolve_gateway_url().await;
+
+        let (cluster, streams) =
+            Cluster::new_with_config(this.0, this.1 .0, Shard::new_with_config).await?;
+
+        Ok((cluster, Events::new(streams)))
+    }
+
+    /// Consume the builder and create a cluster whose shards don't deserialize
+    /// dispatch events.
+    ///
+    /// Refer to [`ShardBuilder::build_lazy`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClusterStartErrorType::RetrievingGatewayInfo`] error type if
+    /// there was an HTTP error Retrieving the gateway information.
+    ///
+    /// [`ClusterStartErrorType::RetrievingGatewayInfo`]: super::ClusterStartErrorType::RetrievingGatewayInfo
+    pub async fn build_lazy(self) -> Result<(Cluster, LazyEvents), ClusterStartError> {
+        let this = self.resolve_gateway_url().await;
+
+        let (cluster, streams) =
+            Cluster::new_with_config(this.0, this.1 .0, Shard::new_lazy_with_config).await?;
+
+        Ok((cluster, LazyEvents::new(streams)))
+    }
+
+    /// Retrieve the gateway URL if one hasn't been configured, so that every
+    /// shard doesn't need to retrieve it.
+    async fn resolve_gateway_url(mut self) -> Self {
         if (self.1).0.gateway_url.is_none() {
             let maybe_response = (self.1).0.http_client.gateway().authed().exec().await;
 
@@ -73,7 +105,7 @@ impl ClusterBuilder {
             }
         }
 
-        Cluster::new_with_config(self.0, self.1 .0).await
+        self
     }
 
     /// Set the event types to process.
@@ -98,6 +130,19 @@ impl ClusterBuilder {
         self
     }
 
+    /// Set how long shards wait for the next unavailable guild before
+    /// considering their guilds ready.
+    ///
+    /// Refer to [`ShardBuilder::guild_ready_timeout`] for the default value.
+    ///
+    /// [`ShardBuilder::guild_ready_timeout`]: crate::shard::ShardBuilder::guild_ready_timeout
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn guild_ready_timeout(mut self, guild_ready_timeout: Duration) -> Self {
+        self.1 = self.1.guild_ready_timeout(guild_ready_timeout);
+
+        self
+    }
+
     /// Set the `twilight_http` Client used by the cluster and the shards it
     /// manages.
     ///
@@ -176,6 +221,16 @@ impl ClusterBuilder {
         self
     }
 
+    /// Set the policy deciding how shards recover after they are disconnected.
+    ///
+    /// Refer to the shard's [`ShardBuilder::reconnect_policy`] for more
+    /// information.
+    pub fn reconnect_policy(mut self, reconnect_policy: Arc<dyn ReconnectPolicy>) -> Self {
+        self.1 = self.1.reconnect_policy(reconnect_policy);
+
+        self
+    }
+
     /// Set the scheme to use for shard managing.
     ///
     /// For example, [`ShardScheme::Auto`] means that the cluster will
@@ -229,6 +284,23 @@ impl ClusterBuilder {
         self
     }
 
+    /// Set the interval at which the session start limit is refreshed while
+    /// the cluster is up, or `None` to only retrieve it when bringing the
+    /// cluster up.
+    ///
+    /// Refreshed limits are shared with the [`queue`], and a warning is logged
+    /// when Discord recommends more shards than the cluster is running with.
+    ///
+    /// Defaults to an hour.
+    ///
+    /// [`queue`]: Self::queue
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn session_start_limit_interval(mut self, interval: Option<Duration>) -> Self {
+        self.0.session_start_limit_interval = interval;
+
+        self
+    }
+
     /// Set the session information to resume shards with.
     ///
     /// This requires having recovered the resume data when shutting down the
@@ -1,6 +1,6 @@
 use super::scheme::ShardScheme;
 use crate::shard::ResumeSession;
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use twilight_gateway_queue::Queue;
 
 /// Built configuration for a [`Cluster`].
@@ -11,6 +11,7 @@ pub struct Config {
     pub(super) shard_scheme: ShardScheme,
     pub(super) queue: Arc<dyn Queue>,
     pub(super) resume_sessions: HashMap<u64, ResumeSession>,
+    pub(super) session_start_limit_interval: Option<Duration>,
 }
 
 impl Config {
@@ -28,6 +29,17 @@ impl Config {
     pub fn queue(&self) -> &Arc<dyn Queue> {
         &self.queue
     }
+
+    /// Return the interval at which the session start limit is refreshed
+    /// while the cluster is up.
+    ///
+    /// Refer to [`ClusterBuilder::session_start_limit_interval`] for the
+    /// default value.
+    ///
+    /// [`ClusterBuilder::session_start_limit_interval`]: super::ClusterBuilder::session_start_limit_interval
+    pub const fn session_start_limit_interval(&self) -> Option<Duration> {
+        self.session_start_limit_interval
+    }
 }
 
 #[cfg(test)]
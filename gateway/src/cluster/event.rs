@@ -12,7 +12,7 @@
 //! [`EventType`]: twilight_model::gateway::event::EventType
 //! [`ClusterBuilder::event_types`]: crate::cluster::ClusterBuilder::event_types
 
-use crate::shard::Events as ShardEvents;
+use crate::shard::{raw_event::LazyEvent, Events as ShardEvents, LazyEvents as ShardLazyEvents};
 use futures_util::stream::{SelectAll, Stream};
 use std::{
     pin::Pin,
@@ -36,12 +36,12 @@ use twilight_model::gateway::event::Event;
 /// [`Events`]: crate::shard::Events
 #[derive(Debug)]
 pub struct Events {
-    stream: SelectAll<ShardEventsWithId>,
+    stream: SelectAll<ShardEventsWithId<ShardEvents>>,
 }
 
 impl Events {
     /// Create a new stream of shards' events.
-    pub(super) const fn new(stream: SelectAll<ShardEventsWithId>) -> Self {
+    pub(super) const fn new(stream: SelectAll<ShardEventsWithId<ShardEvents>>) -> Self {
         Self { stream }
     }
 }
@@ -54,24 +54,51 @@ impl Stream for Events {
     }
 }
 
-/// Poll a shard's [`Events`] stream, mapping the result to the shard's ID.
+/// Stream of lazy events from a [`Cluster`].
 ///
-/// [`Events`]: crate::shard::Events
+/// Dispatch events are not deserialized; refer to [`ClusterBuilder::build_lazy`]
+/// for more information.
+///
+/// This implements [`futures_util::stream::Stream`].
+///
+/// [`Cluster`]: super::Cluster
+/// [`ClusterBuilder::build_lazy`]: super::ClusterBuilder::build_lazy
 #[derive(Debug)]
-pub struct ShardEventsWithId {
+pub struct LazyEvents {
+    stream: SelectAll<ShardEventsWithId<ShardLazyEvents>>,
+}
+
+impl LazyEvents {
+    /// Create a new stream of shards' lazy events.
+    pub(super) const fn new(stream: SelectAll<ShardEventsWithId<ShardLazyEvents>>) -> Self {
+        Self { stream }
+    }
+}
+
+impl Stream for LazyEvents {
+    type Item = (u64, LazyEvent);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.stream).poll_next(cx)
+    }
+}
+
+/// Poll a shard's event stream, mapping the result to the shard's ID.
+#[derive(Debug)]
+pub struct ShardEventsWithId<S> {
     id: u64,
-    stream: ShardEvents,
+    stream: S,
 }
 
-impl ShardEventsWithId {
+impl<S> ShardEventsWithId<S> {
     /// Create a new stream with shard's ID and event stream.
-    pub(super) const fn new(id: u64, stream: ShardEvents) -> Self {
+    pub(super) const fn new(id: u64, stream: S) -> Self {
         Self { id, stream }
     }
 }
 
-impl Stream for ShardEventsWithId {
-    type Item = (u64, Event);
+impl<S: Stream + Unpin> Stream for ShardEventsWithId<S> {
+    type Item = (u64, S::Item);
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         match Pin::new(&mut self.stream).poll_next(cx) {
@@ -84,10 +111,11 @@ impl Stream for ShardEventsWithId {
 
 #[cfg(test)]
 mod tests {
-    use super::Events;
+    use super::{Events, LazyEvents};
     use futures_util::stream::Stream;
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
 
     assert_impl_all!(Events: Debug, Send, Stream, Sync);
+    assert_impl_all!(LazyEvents: Debug, Send, Stream, Sync);
 }
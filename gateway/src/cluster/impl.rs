@@ -1,4 +1,10 @@
-use super::{builder::ClusterBuilder, config::Config, event::Events, scheme::ShardScheme};
+use super::{
+    builder::ClusterBuilder,
+    config::Config,
+    event::Events,
+    scheme::ShardScheme,
+    session_start_limit::{SessionStartLimitQueue, SessionStartLimitTracker},
+};
 use crate::{
     cluster::event::ShardEventsWithId,
     shard::{
@@ -6,14 +12,20 @@ use crate::{
     },
     Intents,
 };
-use futures_util::{future, stream::SelectAll};
+use futures_util::{
+    future,
+    stream::{SelectAll, Stream},
+};
 use std::{
     collections::{hash_map::Values, HashMap},
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     iter::{FromIterator, FusedIterator},
+    sync::{Arc, Mutex},
 };
+use tokio::task::JoinHandle;
 use twilight_http::Client as HttpClient;
+use twilight_model::gateway::{connection_info::BotConnectionInfo, SessionStartLimit};
 
 /// Sending a command to a shard failed.
 #[derive(Debug)]
@@ -222,6 +234,8 @@ pub enum ClusterStartErrorType {
 #[derive(Debug)]
 pub struct Cluster {
     config: Config,
+    session_start_limit: Arc<SessionStartLimitTracker>,
+    session_start_limit_task: Mutex<Option<JoinHandle<()>>>,
     shards: HashMap<u64, Shard>,
 }
 
@@ -276,31 +290,62 @@ impl Cluster {
         Self::builder(token, intents).build().await
     }
 
-    pub(super) async fn new_with_config(
+    /// Create a cluster with the shards of the configured scheme, returning
+    /// the combined stream of the shards' events.
+    ///
+    /// Shards are constructed via the provided function, which determines
+    /// the type of the shards' event streams.
+    pub(super) async fn new_with_config<S: Stream + Unpin>(
         mut config: Config,
-        shard_config: ShardConfig,
-    ) -> Result<(Self, Events), ClusterStartError> {
-        #[derive(Default)]
-        struct ShardFold {
+        mut shard_config: ShardConfig,
+        new_shard: fn(ShardConfig) -> (Shard, S),
+    ) -> Result<(Self, SelectAll<ShardEventsWithId<S>>), ClusterStartError> {
+        struct ShardFold<S> {
             shards: HashMap<u64, Shard>,
-            streams: Vec<ShardEventsWithId>,
+            streams: Vec<ShardEventsWithId<S>>,
         }
 
-        let scheme = match config.shard_scheme() {
-            ShardScheme::Auto => Self::retrieve_shard_count(&shard_config.http_client).await?,
-            other => other.clone(),
+        let (scheme, info) = match config.shard_scheme() {
+            ShardScheme::Auto => {
+                let info = Self::retrieve_gateway_info(&shard_config.http_client).await?;
+                let scheme = ShardScheme::Range {
+                    from: 0,
+                    to: info.shards - 1,
+                    total: info.shards,
+                };
+
+                (scheme, Some(info))
+            }
+            other => (other.clone(), None),
         };
 
         let iter = scheme.iter().expect("shard scheme is not auto");
         let total = scheme.total().expect("shard scheme is not auto");
 
+        let session_start_limit = Arc::new(SessionStartLimitTracker::new(
+            Arc::clone(&shard_config.http_client),
+            Arc::clone(&config.queue),
+            total,
+        ));
+
+        if let Some(info) = info {
+            session_start_limit.update(info).await;
+        }
+
+        shard_config.queue = Arc::new(SessionStartLimitQueue(Arc::clone(&session_start_limit)));
+
         #[cfg(feature = "metrics")]
         #[allow(clippy::cast_precision_loss)]
         {
             metrics::gauge!("Cluster-Shard-Count", total as f64);
         }
 
-        let ShardFold { shards, streams } = iter.fold(ShardFold::default(), |mut fold, idx| {
+        let fold = ShardFold {
+            shards: HashMap::new(),
+            streams: Vec::new(),
+        };
+
+        let ShardFold { shards, streams } = iter.fold(fold, |mut fold, idx| {
             let mut shard_config = shard_config.clone();
             shard_config.shard = [idx, total];
 
@@ -309,7 +354,7 @@ impl Cluster {
                 shard_config.sequence = Some(data.sequence);
             }
 
-            let (shard, stream) = Shard::new_with_config(shard_config);
+            let (shard, stream) = new_shard(shard_config);
 
             fold.shards.insert(idx, shard);
             fold.streams.push(ShardEventsWithId::new(idx, stream));
@@ -320,15 +365,22 @@ impl Cluster {
         #[allow(clippy::from_iter_instead_of_collect)]
         let select_all = SelectAll::from_iter(streams);
 
-        Ok((Self { config, shards }, Events::new(select_all)))
+        let cluster = Self {
+            config,
+            session_start_limit,
+            session_start_limit_task: Mutex::new(None),
+            shards,
+        };
+
+        Ok((cluster, select_all))
     }
 
-    /// Retrieve the recommended number of shards from the HTTP API.
-    ///
-    /// The returned shard scheme is a [`ShardScheme::Range`].
-    async fn retrieve_shard_count(http: &HttpClient) -> Result<ShardScheme, ClusterStartError> {
-        let gateway = http
-            .gateway()
+    /// Retrieve the bot's gateway information, such as the recommended number
+    /// of shards, from the HTTP API.
+    async fn retrieve_gateway_info(
+        http: &HttpClient,
+    ) -> Result<BotConnectionInfo, ClusterStartError> {
+        http.gateway()
             .authed()
             .exec()
             .await
@@ -341,13 +393,7 @@ impl Cluster {
             .map_err(|source| ClusterStartError {
                 kind: ClusterStartErrorType::RetrievingGatewayInfo,
                 source: Some(Box::new(source)),
-            })?;
-
-        Ok(ShardScheme::Range {
-            from: 0,
-            to: gateway.shards - 1,
-            total: gateway.shards,
-        })
+            })
     }
 
     /// Create a builder to configure and construct a cluster.
@@ -398,6 +444,15 @@ impl Cluster {
     /// Bring up the cluster, starting all of the shards that it was configured
     /// to manage.
     ///
+    /// The session start limit is retrieved and shared with the [queue] before
+    /// starting the shards, unless it was already retrieved. If too few
+    /// sessions remain to identify the shards that don't resume a session,
+    /// then this waits until the limit resets. While the cluster is up the
+    /// limit is refreshed at the configured
+    /// [`ClusterBuilder::session_start_limit_interval`], and shards
+    /// re-identifying after reconnecting also wait until a session is
+    /// available.
+    ///
     /// # Examples
     ///
     /// Bring up a cluster, starting shards all 10 shards that a bot uses:
@@ -422,12 +477,78 @@ impl Cluster {
     /// cluster.up().await;
     /// # Ok(()) }
     /// ```
+    ///
+    /// [queue]: ClusterBuilder::queue
     pub async fn up(&self) {
+        let identifies = self
+            .shards
+            .values()
+            .filter(|shard| shard.config().session_id.is_none())
+            .count();
+
+        self.session_start_limit
+            .wait_for_sessions(identifies as u64)
+            .await;
+
         future::join_all(self.shards.values().map(Shard::start)).await;
+
+        self.start_session_start_limit_task();
+    }
+
+    /// Most recently retrieved session start limit of the bot.
+    ///
+    /// The limit's `reset_after` is relative to when this is called.
+    ///
+    /// Returns `None` if the limit hasn't been retrieved yet.
+    pub fn session_start_limit(&self) -> Option<SessionStartLimit> {
+        self.session_start_limit.limit()
+    }
+
+    /// Most recently retrieved number of shards recommended by Discord.
+    ///
+    /// If this is more than the cluster's total number of shards then the
+    /// cluster should be restarted with more shards. This is checked every
+    /// time the session start limit is refreshed, but the cluster only warns
+    /// about it when the `tracing` feature is enabled, so check it after
+    /// bringing up the cluster and periodically afterwards.
+    ///
+    /// Returns `None` if the recommendation hasn't been retrieved yet.
+    pub fn recommended_shards(&self) -> Option<u64> {
+        self.session_start_limit.recommended_shards()
+    }
+
+    /// Wait until all of the cluster's shards have received the guilds from
+    /// their sessions' [`Ready`] events.
+    ///
+    /// Refer to [`Shard::guilds_ready`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Bring up a cluster and run a startup job once all shards are ready:
+    ///
+    /// ```no_run
+    /// use std::env;
+    /// use twilight_gateway::{Cluster, Intents};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let (cluster, _events) = Cluster::new(token, Intents::GUILDS).await?;
+    /// cluster.up().await;
+    ///
+    /// cluster.guilds_ready().await;
+    /// println!("all shards have received their guilds");
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Ready`]: twilight_model::gateway::payload::incoming::Ready
+    pub async fn guilds_ready(&self) {
+        future::join_all(self.shards.values().map(Shard::guilds_ready)).await;
     }
 
     /// Bring down the cluster, stopping all of the shards that it's managing.
     pub fn down(&self) {
+        self.stop_session_start_limit_task();
+
         for shard in self.shards.values() {
             shard.shutdown();
         }
@@ -443,6 +564,8 @@ impl Cluster {
     /// disconnection. You may also not be able to resume if you missed too many
     /// events already.
     pub fn down_resumable(&self) -> HashMap<u64, ResumeSession> {
+        self.stop_session_start_limit_task();
+
         self.shards
             .values()
             .map(Shard::shutdown_resumable)
@@ -450,6 +573,38 @@ impl Cluster {
             .collect()
     }
 
+    /// Start periodically refreshing the session start limit, if configured
+    /// and not already started.
+    fn start_session_start_limit_task(&self) {
+        let interval = match self.config.session_start_limit_interval() {
+            Some(interval) => interval,
+            None => return,
+        };
+
+        let mut task = self
+            .session_start_limit_task
+            .lock()
+            .expect("session start limit task poisoned");
+
+        if task.is_none() {
+            let tracker = Arc::clone(&self.session_start_limit);
+            task.replace(tokio::spawn(tracker.run(interval)));
+        }
+    }
+
+    /// Stop periodically refreshing the session start limit.
+    fn stop_session_start_limit_task(&self) {
+        let task = self
+            .session_start_limit_task
+            .lock()
+            .expect("session start limit task poisoned")
+            .take();
+
+        if let Some(task) = task {
+            task.abort();
+        }
+    }
+
     /// Return a Shard by its ID.
     pub fn shard(&self, id: u64) -> Option<&Shard> {
         self.shards.get(&id)
@@ -613,6 +768,12 @@ impl Cluster {
     }
 }
 
+impl Drop for Cluster {
+    fn drop(&mut self) {
+        self.stop_session_start_limit_task();
+    }
+}
+
 /// Iterator over a [`Cluster`]'s managed [shards][`Shard`].
 ///
 /// This is returned by [`Cluster::shards`].
@@ -89,11 +89,12 @@ mod builder;
 mod config;
 mod event;
 mod r#impl;
+mod session_start_limit;
 
 pub use self::{
     builder::ClusterBuilder,
     config::Config,
-    event::Events,
+    event::{Events, LazyEvents},
     r#impl::{
         Cluster, ClusterCommandError, ClusterCommandErrorType, ClusterStartError,
         ClusterStartErrorType, Shards,
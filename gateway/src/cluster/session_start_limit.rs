@@ -0,0 +1,312 @@
+//! Tracking of the bot's session start limit.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::{self, Instant};
+use twilight_gateway_queue::Queue;
+use twilight_http::Client;
+use twilight_model::gateway::{connection_info::BotConnectionInfo, SessionStartLimit};
+
+/// Gateway information as of the time it was retrieved.
+#[derive(Debug)]
+struct Snapshot {
+    /// Session start limit at the time of retrieval.
+    limit: SessionStartLimit,
+    /// When the information was retrieved.
+    retrieved_at: Instant,
+    /// Recommended number of shards.
+    shards: u64,
+}
+
+/// Tracker of the bot's session start limit, shared between a cluster and the
+/// task periodically refreshing it.
+///
+/// Every time the limit is retrieved it is shared with the cluster's
+/// [`Queue`].
+#[derive(Debug)]
+pub(super) struct SessionStartLimitTracker {
+    http: Arc<Client>,
+    queue: Arc<dyn Queue>,
+    snapshot: Mutex<Option<Snapshot>>,
+    /// Total number of shards the cluster is running with.
+    #[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+    total: u64,
+}
+
+impl SessionStartLimitTracker {
+    pub fn new(http: Arc<Client>, queue: Arc<dyn Queue>, total: u64) -> Self {
+        Self {
+            http,
+            queue,
+            snapshot: Mutex::new(None),
+            total,
+        }
+    }
+
+    /// Most recently retrieved session start limit.
+    ///
+    /// The limit's `reset_after` is relative to now rather than to when it was
+    /// retrieved.
+    pub fn limit(&self) -> Option<SessionStartLimit> {
+        let snapshot = self.snapshot.lock().expect("snapshot poisoned");
+
+        snapshot.as_ref().map(|snapshot| {
+            let mut limit = snapshot.limit.clone();
+
+            #[allow(clippy::cast_possible_truncation)]
+            let elapsed = snapshot.retrieved_at.elapsed().as_millis() as u64;
+            limit.reset_after = limit.reset_after.saturating_sub(elapsed);
+
+            limit
+        })
+    }
+
+    /// Most recently retrieved recommended number of shards.
+    ///
+    /// This may exceed the total number of shards the cluster is running
+    /// with, which is only logged when the `tracing` feature is enabled.
+    pub fn recommended_shards(&self) -> Option<u64> {
+        let snapshot = self.snapshot.lock().expect("snapshot poisoned");
+
+        snapshot.as_ref().map(|snapshot| snapshot.shards)
+    }
+
+    /// Record newly retrieved gateway information and share the session start
+    /// limit with the queue.
+    pub async fn update(&self, info: BotConnectionInfo) {
+        #[cfg(feature = "tracing")]
+        if info.shards > self.total {
+            tracing::warn!(
+                recommended = info.shards,
+                total = self.total,
+                "recommended shard count exceeds the running total",
+            );
+        }
+
+        self.snapshot
+            .lock()
+            .expect("snapshot poisoned")
+            .replace(Snapshot {
+                limit: info.session_start_limit.clone(),
+                retrieved_at: Instant::now(),
+                shards: info.shards,
+            });
+
+        self.queue
+            .update_session_start_limit(info.session_start_limit)
+            .await;
+    }
+
+    /// Retrieve the gateway information, returning whether retrieval
+    /// succeeded.
+    pub async fn refresh(&self) -> bool {
+        let info = match self.http.gateway().authed().exec().await {
+            Ok(response) => response.model().await.ok(),
+            Err(_) => None,
+        };
+
+        if let Some(info) = info {
+            self.update(info).await;
+
+            true
+        } else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("retrieving the session start limit failed");
+
+            false
+        }
+    }
+
+    /// Wait until a number of sessions can be started.
+    ///
+    /// The limit is only retrieved if it hasn't been yet, since it's otherwise
+    /// kept fresh by [`run`]. If the remaining sessions are insufficient then
+    /// this waits until the limit resets and retrieves it again, until enough
+    /// sessions remain or as many as the limit ever allows.
+    ///
+    /// [`run`]: Self::run
+    pub async fn wait_for_sessions(&self, sessions: u64) {
+        if sessions == 0 {
+            return;
+        }
+
+        if self.limit().is_none() && !self.refresh().await {
+            return;
+        }
+
+        loop {
+            let limit = match self.limit() {
+                Some(limit) if limit.remaining < sessions.min(limit.total) => limit,
+                _ => return,
+            };
+
+            let reset_after = Duration::from_millis(limit.reset_after);
+
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                remaining = limit.remaining,
+                sessions,
+                "session start limit exhausted, waiting {:.2?} for it to reset",
+                reset_after,
+            );
+
+            time::sleep(reset_after).await;
+
+            if !self.refresh().await {
+                return;
+            }
+        }
+    }
+
+    /// Count a started session against the most recently retrieved limit, so
+    /// that exhaustion is noticed before the limit is next retrieved.
+    fn start_session(&self) {
+        if let Some(snapshot) = self.snapshot.lock().expect("snapshot poisoned").as_mut() {
+            snapshot.limit.remaining = snapshot.limit.remaining.saturating_sub(1);
+        }
+    }
+
+    /// Refresh the session start limit every interval.
+    pub async fn run(self: Arc<Self>, interval: Duration) {
+        loop {
+            time::sleep(interval).await;
+            self.refresh().await;
+        }
+    }
+}
+
+/// Queue of a cluster's shards, waiting for a session to be available before
+/// requesting to identify from the configured [`Queue`].
+///
+/// Shards request to identify when starting as well as when re-identifying
+/// after their sessions are invalidated, so both are held back once the
+/// session start limit is exhausted.
+#[derive(Debug)]
+pub(super) struct SessionStartLimitQueue(pub Arc<SessionStartLimitTracker>);
+
+impl Queue for SessionStartLimitQueue {
+    fn request<'a>(&'a self, shard_id: [u64; 2]) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            self.0.wait_for_sessions(1).await;
+            self.0.queue.request(shard_id).await;
+            self.0.start_session();
+        })
+    }
+
+    fn update_session_start_limit<'a>(
+        &'a self,
+        limit: SessionStartLimit,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        self.0.queue.update_session_start_limit(limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SessionStartLimitQueue, SessionStartLimitTracker};
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        future::Future,
+        pin::Pin,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+    use twilight_gateway_queue::Queue;
+    use twilight_http::Client;
+    use twilight_model::gateway::{connection_info::BotConnectionInfo, SessionStartLimit};
+
+    assert_impl_all!(SessionStartLimitQueue: Debug, Queue, Send, Sync);
+    assert_impl_all!(SessionStartLimitTracker: Debug, Send, Sync);
+
+    #[derive(Debug, Default)]
+    struct RecordingQueue(Mutex<Vec<SessionStartLimit>>);
+
+    impl Queue for RecordingQueue {
+        fn request(&'_ self, _: [u64; 2]) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+            Box::pin(async {})
+        }
+
+        fn update_session_start_limit(
+            &'_ self,
+            limit: SessionStartLimit,
+        ) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+            self.0.lock().unwrap().push(limit);
+
+            Box::pin(async {})
+        }
+    }
+
+    fn info(remaining: u64, shards: u64) -> BotConnectionInfo {
+        BotConnectionInfo {
+            session_start_limit: SessionStartLimit {
+                max_concurrency: 1,
+                remaining,
+                reset_after: 60_000,
+                total: 1000,
+            },
+            shards,
+            url: "wss://gateway.discord.gg".to_owned(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_shares_with_queue() {
+        let queue = Arc::new(RecordingQueue::default());
+        let tracker = SessionStartLimitTracker::new(
+            Arc::new(Client::new(String::new())),
+            Arc::clone(&queue) as Arc<dyn Queue>,
+            2,
+        );
+        assert!(tracker.limit().is_none());
+        assert!(tracker.recommended_shards().is_none());
+
+        tracker.update(info(998, 3)).await;
+
+        assert_eq!(Some(3), tracker.recommended_shards());
+        let limit = tracker.limit().expect("limit set");
+        assert_eq!(998, limit.remaining);
+        assert!(Duration::from_millis(limit.reset_after) <= Duration::from_secs(60));
+        assert_eq!(
+            vec![info(998, 3).session_start_limit],
+            *queue.0.lock().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_sessions_remaining() {
+        let queue = Arc::new(RecordingQueue::default());
+        let tracker = SessionStartLimitTracker::new(
+            Arc::new(Client::new(String::new())),
+            Arc::clone(&queue) as Arc<dyn Queue>,
+            2,
+        );
+        tracker.update(info(2, 2)).await;
+
+        // Enough sessions remain, so the limit isn't retrieved again.
+        tracker.wait_for_sessions(2).await;
+        assert_eq!(1, queue.0.lock().unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn test_queue_counts_sessions() {
+        let queue = Arc::new(RecordingQueue::default());
+        let tracker = Arc::new(SessionStartLimitTracker::new(
+            Arc::new(Client::new(String::new())),
+            Arc::clone(&queue) as Arc<dyn Queue>,
+            2,
+        ));
+        tracker.update(info(2, 2)).await;
+
+        let shard_queue = SessionStartLimitQueue(Arc::clone(&tracker));
+        shard_queue.request([0, 2]).await;
+        shard_queue.request([1, 2]).await;
+
+        assert_eq!(0, tracker.limit().expect("limit set").remaining);
+        assert_eq!(1, queue.0.lock().unwrap().len());
+    }
+}
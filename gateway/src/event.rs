@@ -104,6 +104,9 @@ bitflags! {
         const SHARD_CONNECTING = 1 << 34;
         /// Shard has disconnected from the gateway.
         const SHARD_DISCONNECTED = 1 << 35;
+        /// Shard has received all of the guilds from its session's ready
+        /// event.
+        const SHARD_GUILDS_READY = 1 << 63;
         /// Shard is identifying to create a session with the gateway.
         const SHARD_IDENTIFYING = 1 << 36;
         /// Incoming message has been received from the gateway.
@@ -316,7 +319,11 @@ impl From<EventType> for EventTypeFlags {
             EventType::RoleUpdate => EventTypeFlags::ROLE_UPDATE,
             EventType::ShardConnected => EventTypeFlags::SHARD_CONNECTED,
             EventType::ShardConnecting => EventTypeFlags::SHARD_CONNECTING,
+            // Shards emit their death once, whichever event types are
+            // wanted, and there's no bit left for it.
+            EventType::ShardDead => EventTypeFlags::empty(),
             EventType::ShardDisconnected => EventTypeFlags::SHARD_DISCONNECTED,
+            EventType::ShardGuildsReady => EventTypeFlags::SHARD_GUILDS_READY,
             EventType::ShardIdentifying => EventTypeFlags::SHARD_IDENTIFYING,
             EventType::ShardReconnecting => EventTypeFlags::SHARD_RECONNECTING,
             EventType::ShardPayload => EventTypeFlags::SHARD_PAYLOAD,
@@ -387,4 +394,13 @@ mod tests {
         Sync,
         TryFrom<(u8, Option<&'static str>)>
     );
+
+    #[test]
+    fn test_shard_dead() {
+        let flags = EventTypeFlags::from(EventType::ShardDead);
+
+        assert!(flags.is_empty());
+        assert!(EventTypeFlags::empty().contains(flags));
+        assert!(EventTypeFlags::SHARD_DISCONNECTED.contains(flags));
+    }
 }
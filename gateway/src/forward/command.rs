@@ -0,0 +1,80 @@
+//! Commands that consumers can send via the publisher's shards.
+
+use serde::{Deserialize, Serialize};
+use twilight_model::gateway::payload::outgoing::{
+    RequestGuildMembers, UpdatePresence, UpdateVoiceState,
+};
+
+/// Command sent by a [`Consumer`] to be sent via one of the [`Publisher`]'s
+/// shards.
+///
+/// Commands managed by the shard itself, such as heartbeats and identifies,
+/// can't be forwarded.
+///
+/// [`Consumer`]: super::Consumer
+/// [`Publisher`]: super::Publisher
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub enum ForwardedCommand {
+    /// Request members of a guild.
+    RequestGuildMembers(RequestGuildMembers),
+    /// Update the current user's presence.
+    UpdatePresence(UpdatePresence),
+    /// Update the current user's voice state.
+    UpdateVoiceState(UpdateVoiceState),
+}
+
+impl From<RequestGuildMembers> for ForwardedCommand {
+    fn from(command: RequestGuildMembers) -> Self {
+        Self::RequestGuildMembers(command)
+    }
+}
+
+impl From<UpdatePresence> for ForwardedCommand {
+    fn from(command: UpdatePresence) -> Self {
+        Self::UpdatePresence(command)
+    }
+}
+
+impl From<UpdateVoiceState> for ForwardedCommand {
+    fn from(command: UpdateVoiceState) -> Self {
+        Self::UpdateVoiceState(command)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ForwardedCommand;
+    use serde::{Deserialize, Serialize};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        gateway::payload::outgoing::{RequestGuildMembers, UpdateVoiceState},
+        id::GuildId,
+    };
+
+    assert_impl_all!(
+        ForwardedCommand: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        From<RequestGuildMembers>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn test_round_trip() {
+        let command = ForwardedCommand::from(UpdateVoiceState::new(
+            GuildId::new(1).expect("non zero"),
+            None,
+            false,
+            true,
+        ));
+
+        let json = serde_json::to_vec(&command).unwrap();
+        assert_eq!(command, serde_json::from_slice(&json).unwrap());
+    }
+}
@@ -0,0 +1,258 @@
+//! Consumer of payloads forwarded by a publisher.
+
+use super::{ForwardedCommand, Frame, Transport};
+use crate::{shard::json, Event};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str,
+    sync::Arc,
+};
+use twilight_model::gateway::event::GatewayEventDeserializer;
+
+/// Consuming events or sending commands failed.
+#[derive(Debug)]
+pub struct ConsumeError {
+    kind: ConsumeErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ConsumeError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ConsumeErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ConsumeErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for ConsumeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ConsumeErrorType::ParsingPayload { shard_id } => {
+                f.write_str("payload forwarded from shard ")?;
+                Display::fmt(shard_id, f)?;
+
+                f.write_str(" could not be parsed")
+            }
+            ConsumeErrorType::Receiving => f.write_str("receiving a frame failed"),
+            ConsumeErrorType::Sending => f.write_str("sending a frame failed"),
+            ConsumeErrorType::SerializingCommand => f.write_str("serializing the command failed"),
+        }
+    }
+}
+
+impl Error for ConsumeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ConsumeError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ConsumeErrorType {
+    /// Forwarded payload could not be parsed into an event.
+    ParsingPayload {
+        /// ID of the shard that received the payload.
+        shard_id: u64,
+    },
+    /// Receiving a frame from the transport failed.
+    Receiving,
+    /// Sending a frame over the transport failed.
+    Sending,
+    /// Serializing the command to send failed.
+    SerializingCommand,
+}
+
+/// Receiver of payloads forwarded by a [`Publisher`].
+///
+/// Payloads are parsed into [`Event`]s as the shard would have parsed them.
+/// Commands may be sent back to the publisher to be sent via one of its
+/// shards.
+///
+/// Refer to [`SocketTransport`] for an example of how to use this.
+///
+/// [`Publisher`]: super::Publisher
+/// [`SocketTransport`]: super::SocketTransport
+#[derive(Debug)]
+pub struct Consumer {
+    transport: Arc<dyn Transport>,
+}
+
+impl Consumer {
+    /// Create a new consumer over a transport.
+    pub fn new(transport: Arc<dyn Transport>) -> Self {
+        Self { transport }
+    }
+
+    /// Receive the next event and the ID of the shard that received it.
+    ///
+    /// Returns `None` once the transport is closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConsumeErrorType::ParsingPayload`] error type if the
+    /// payload could not be parsed into an event.
+    ///
+    /// Returns a [`ConsumeErrorType::Receiving`] error type if receiving a
+    /// frame from the transport failed.
+    pub async fn next_event(&self) -> Result<Option<(u64, Event)>, ConsumeError> {
+        loop {
+            let frame = self
+                .transport
+                .receive()
+                .await
+                .map_err(|source| ConsumeError {
+                    kind: ConsumeErrorType::Receiving,
+                    source: Some(source),
+                })?;
+
+            match frame {
+                Some(Frame::Event {
+                    mut payload,
+                    shard_id,
+                }) => return Self::parse(shard_id, &mut payload).map(Some),
+                Some(_) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!("received non-event frame from publisher");
+                }
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// Send a command via one of the publisher's shards.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConsumeErrorType::Sending`] error type if sending the
+    /// command over the transport failed.
+    ///
+    /// Returns a [`ConsumeErrorType::SerializingCommand`] error type if the
+    /// command could not be serialized.
+    pub async fn command(
+        &self,
+        shard_id: u64,
+        command: impl Into<ForwardedCommand>,
+    ) -> Result<(), ConsumeError> {
+        let payload = serde_json::to_vec(&command.into()).map_err(|source| ConsumeError {
+            kind: ConsumeErrorType::SerializingCommand,
+            source: Some(Box::new(source)),
+        })?;
+
+        self.transport
+            .send(Frame::Command { payload, shard_id })
+            .await
+            .map_err(|source| ConsumeError {
+                kind: ConsumeErrorType::Sending,
+                source: Some(source),
+            })
+    }
+
+    /// Parse a payload into an event.
+    fn parse(shard_id: u64, payload: &mut [u8]) -> Result<(u64, Event), ConsumeError> {
+        let error = |source: Option<Box<dyn Error + Send + Sync>>| ConsumeError {
+            kind: ConsumeErrorType::ParsingPayload { shard_id },
+            source,
+        };
+
+        let text = str::from_utf8(payload).map_err(|source| error(Some(Box::new(source))))?;
+        let (op, seq, event_type) = GatewayEventDeserializer::from_json(text)
+            .ok_or_else(|| error(None))?
+            .into_parts();
+        let event_type = event_type.map(ToOwned::to_owned);
+
+        let event = json::parse_gateway_event(op, seq, event_type.as_deref(), payload)
+            .map_err(|source| error(Some(Box::new(source))))?;
+
+        Ok((shard_id, Event::from(event)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConsumeError, ConsumeErrorType, Consumer};
+    use crate::{
+        forward::{ForwardedCommand, Frame, SocketTransport, Transport},
+        Event,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, sync::Arc};
+    use twilight_model::{
+        gateway::payload::outgoing::UpdateVoiceState,
+        id::{ChannelId, GuildId},
+    };
+
+    assert_impl_all!(ConsumeErrorType: Debug, Send, Sync);
+    assert_impl_all!(ConsumeError: Error, Send, Sync);
+    assert_impl_all!(Consumer: Debug, Send, Sync);
+
+    #[tokio::test]
+    async fn test_consume() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (publisher, consumer) = tokio::io::duplex(1024);
+        let publisher = SocketTransport::new(publisher);
+        let consumer = Consumer::new(Arc::new(SocketTransport::new(consumer)));
+
+        publisher
+            .send(Frame::Event {
+                payload: br#"{"op":0,"s":2,"t":"GUILD_DELETE","d":{"id":"5"}}"#.to_vec(),
+                shard_id: 3,
+            })
+            .await?;
+        publisher
+            .send(Frame::Event {
+                payload: b"{}".to_vec(),
+                shard_id: 4,
+            })
+            .await?;
+
+        let (shard_id, event) = consumer.next_event().await?.expect("event frame");
+        assert_eq!(3, shard_id);
+        assert!(
+            matches!(event, Event::GuildDelete(guild) if guild.id == GuildId::new(5).expect("non zero"))
+        );
+
+        assert!(matches!(
+            consumer.next_event().await.unwrap_err().kind(),
+            ConsumeErrorType::ParsingPayload { shard_id: 4 }
+        ));
+
+        let command = UpdateVoiceState::new(
+            GuildId::new(1).expect("non zero"),
+            ChannelId::new(2),
+            false,
+            false,
+        );
+        consumer.command(1, command.clone()).await?;
+
+        match publisher.receive().await? {
+            Some(Frame::Command { payload, shard_id }) => {
+                assert_eq!(1, shard_id);
+                assert_eq!(
+                    ForwardedCommand::from(command),
+                    serde_json::from_slice(&payload)?
+                );
+            }
+            other => panic!("expected command frame: {:?}", other),
+        }
+
+        drop(publisher);
+        assert!(consumer.next_event().await?.is_none());
+
+        Ok(())
+    }
+}
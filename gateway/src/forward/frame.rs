@@ -0,0 +1,220 @@
+//! Frames sent between publishers and consumers over a transport.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Kind byte of a [`Frame::Command`].
+const KIND_COMMAND: u8 = 0;
+
+/// Kind byte of a [`Frame::Event`].
+const KIND_EVENT: u8 = 1;
+
+/// Length of a frame's header: the kind byte and the shard ID.
+const HEADER_LENGTH: usize = 1 + 8;
+
+/// Decoding a [`Frame`] from bytes failed.
+#[derive(Debug)]
+pub struct FrameDecodeError {
+    kind: FrameDecodeErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl FrameDecodeError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &FrameDecodeErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (FrameDecodeErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for FrameDecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            FrameDecodeErrorType::KindUnknown { kind } => {
+                f.write_str("frame kind ")?;
+                Display::fmt(kind, f)?;
+
+                f.write_str(" is unknown")
+            }
+            FrameDecodeErrorType::TooShort { len } => {
+                f.write_str("frame is ")?;
+                Display::fmt(len, f)?;
+
+                f.write_str(" bytes long, which is shorter than the header")
+            }
+        }
+    }
+}
+
+impl Error for FrameDecodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`FrameDecodeError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum FrameDecodeErrorType {
+    /// Kind of the frame is not known.
+    KindUnknown {
+        /// Provided kind byte.
+        kind: u8,
+    },
+    /// Frame is too short to contain a header.
+    TooShort {
+        /// Length of the provided bytes.
+        len: usize,
+    },
+}
+
+/// Unit of data sent over a [`Transport`].
+///
+/// Frames can be encoded into bytes via [`encode`] and decoded via [`decode`],
+/// which transports that only carry bytes, such as message brokers, may use.
+/// The encoding is a byte denoting the kind of frame, followed by the shard ID
+/// as a big endian `u64`, followed by the payload.
+///
+/// [`Transport`]: super::Transport
+/// [`decode`]: Self::decode
+/// [`encode`]: Self::encode
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Frame {
+    /// Serialized [`ForwardedCommand`] to send via a shard.
+    ///
+    /// [`ForwardedCommand`]: super::ForwardedCommand
+    Command {
+        /// Serialized command.
+        payload: Vec<u8>,
+        /// ID of the shard to send the command via.
+        shard_id: u64,
+    },
+    /// Payload received by a shard from the gateway.
+    Event {
+        /// Payload as received from the gateway.
+        payload: Vec<u8>,
+        /// ID of the shard that received the payload.
+        shard_id: u64,
+    },
+}
+
+impl Frame {
+    /// Decode a frame from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FrameDecodeErrorType::KindUnknown`] error type if the kind
+    /// of frame is unknown.
+    ///
+    /// Returns a [`FrameDecodeErrorType::TooShort`] error type if the bytes
+    /// are too short to contain a header.
+    pub fn decode(bytes: &[u8]) -> Result<Self, FrameDecodeError> {
+        if bytes.len() < HEADER_LENGTH {
+            return Err(FrameDecodeError {
+                kind: FrameDecodeErrorType::TooShort { len: bytes.len() },
+                source: None,
+            });
+        }
+
+        let (header, payload) = bytes.split_at(HEADER_LENGTH);
+        let mut shard_id = [0; 8];
+        shard_id.copy_from_slice(&header[1..]);
+        let shard_id = u64::from_be_bytes(shard_id);
+        let payload = payload.to_vec();
+
+        match header[0] {
+            KIND_COMMAND => Ok(Self::Command { payload, shard_id }),
+            KIND_EVENT => Ok(Self::Event { payload, shard_id }),
+            kind => Err(FrameDecodeError {
+                kind: FrameDecodeErrorType::KindUnknown { kind },
+                source: None,
+            }),
+        }
+    }
+
+    /// Encode the frame into bytes.
+    pub fn encode(&self) -> Vec<u8> {
+        let (kind, payload, shard_id) = match self {
+            Self::Command { payload, shard_id } => (KIND_COMMAND, payload, shard_id),
+            Self::Event { payload, shard_id } => (KIND_EVENT, payload, shard_id),
+        };
+
+        let mut bytes = Vec::with_capacity(HEADER_LENGTH + payload.len());
+        bytes.push(kind);
+        bytes.extend_from_slice(&shard_id.to_be_bytes());
+        bytes.extend_from_slice(payload);
+
+        bytes
+    }
+
+    /// ID of the shard the frame is about.
+    pub const fn shard_id(&self) -> u64 {
+        match self {
+            Self::Command { shard_id, .. } | Self::Event { shard_id, .. } => *shard_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Frame, FrameDecodeError, FrameDecodeErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(FrameDecodeErrorType: Debug, Send, Sync);
+    assert_impl_all!(FrameDecodeError: Error, Send, Sync);
+    assert_impl_all!(Frame: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_round_trip() -> Result<(), FrameDecodeError> {
+        let command = Frame::Command {
+            payload: b"{}".to_vec(),
+            shard_id: 3,
+        };
+        let event = Frame::Event {
+            payload: Vec::new(),
+            shard_id: u64::MAX,
+        };
+
+        assert_eq!(
+            [0, 0, 0, 0, 0, 0, 0, 0, 3, b'{', b'}'].as_ref(),
+            command.encode()
+        );
+        assert_eq!(command, Frame::decode(&command.encode())?);
+        assert_eq!(event, Frame::decode(&event.encode())?);
+        assert_eq!(u64::MAX, event.shard_id());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert!(matches!(
+            Frame::decode(&[1, 0, 0]).unwrap_err().kind(),
+            FrameDecodeErrorType::TooShort { len: 3 }
+        ));
+        assert!(matches!(
+            Frame::decode(&[7, 0, 0, 0, 0, 0, 0, 0, 0])
+                .unwrap_err()
+                .kind(),
+            FrameDecodeErrorType::KindUnknown { kind: 7 }
+        ));
+    }
+}
@@ -0,0 +1,34 @@
+//! Forwarding of gateway payloads between processes.
+//!
+//! Large bots may want to run their shards in one process and their logic in
+//! many stateless worker processes. The [`Publisher`] runs alongside a
+//! [`Cluster`] and forwards the bytes of every [`Event::ShardPayload`] along
+//! with the ID of the shard that received it. On the other side a [`Consumer`]
+//! receives those payloads and parses them into [`Event`]s. Consumers may
+//! send [`ForwardedCommand`]s back, which the publisher sends via
+//! [`Cluster::command`].
+//!
+//! Payloads and commands are carried as [`Frame`]s over a [`Transport`]. The
+//! transport is pluggable so that payloads can be sent over a message broker;
+//! a [`SocketTransport`] over TCP and Unix sockets is provided.
+//!
+//! [`Cluster`]: crate::Cluster
+//! [`Cluster::command`]: crate::Cluster::command
+//! [`Event`]: crate::Event
+//! [`Event::ShardPayload`]: crate::Event::ShardPayload
+
+mod command;
+mod consumer;
+mod frame;
+mod publisher;
+mod socket;
+mod transport;
+
+pub use self::{
+    command::ForwardedCommand,
+    consumer::{ConsumeError, ConsumeErrorType, Consumer},
+    frame::{Frame, FrameDecodeError, FrameDecodeErrorType},
+    publisher::{PublishError, PublishErrorType, Publisher},
+    socket::SocketTransport,
+    transport::{Transport, TransportFuture},
+};
@@ -0,0 +1,268 @@
+//! Publisher forwarding a cluster's payloads to consumers.
+
+use super::{ForwardedCommand, Frame, Transport};
+use crate::{cluster::Cluster, Event};
+use futures_util::{
+    future::{self, Either},
+    stream::{Stream, StreamExt},
+};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+
+/// Publishing payloads or relaying commands failed.
+#[derive(Debug)]
+pub struct PublishError {
+    kind: PublishErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl PublishError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &PublishErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (PublishErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for PublishError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            PublishErrorType::Receiving => f.write_str("receiving a frame failed"),
+            PublishErrorType::Sending => f.write_str("sending a frame failed"),
+        }
+    }
+}
+
+impl Error for PublishError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`PublishError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PublishErrorType {
+    /// Receiving a frame from the transport failed.
+    Receiving,
+    /// Sending a frame over the transport failed.
+    Sending,
+}
+
+/// Forwarder of a [`Cluster`]'s payloads to [`Consumer`]s.
+///
+/// The publisher sends the bytes of [`Event::ShardPayload`]s over a
+/// [`Transport`] along with the ID of the shard that received them, and sends
+/// commands received from consumers via the cluster's shards.
+///
+/// The cluster must be configured to emit shard payloads via
+/// [`EventTypeFlags::SHARD_PAYLOAD`]. Other event types may be disabled so
+/// that the cluster doesn't deserialize events the process doesn't use itself.
+///
+/// # Examples
+///
+/// Forward the payloads of a cluster to a consumer connecting over TCP:
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::{env, sync::Arc};
+/// use tokio::net::TcpListener;
+/// use twilight_gateway::{
+///     forward::{Publisher, SocketTransport},
+///     Cluster, EventTypeFlags, Intents,
+/// };
+///
+/// let token = env::var("DISCORD_TOKEN")?;
+/// let (cluster, events) = Cluster::builder(token, Intents::GUILD_MESSAGES)
+///     .event_types(EventTypeFlags::SHARD_PAYLOAD)
+///     .build()
+///     .await?;
+/// let cluster = Arc::new(cluster);
+///
+/// let listener = TcpListener::bind("127.0.0.1:5000").await?;
+/// let (stream, _) = listener.accept().await?;
+/// let publisher = Publisher::new(Arc::clone(&cluster), Arc::new(SocketTransport::new(stream)));
+///
+/// let cluster_spawn = Arc::clone(&cluster);
+/// tokio::spawn(async move {
+///     cluster_spawn.up().await;
+/// });
+///
+/// publisher.run(events).await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`Consumer`]: super::Consumer
+/// [`EventTypeFlags::SHARD_PAYLOAD`]: crate::EventTypeFlags::SHARD_PAYLOAD
+#[derive(Debug)]
+pub struct Publisher {
+    cluster: Arc<Cluster>,
+    transport: Arc<dyn Transport>,
+}
+
+impl Publisher {
+    /// Create a new publisher for a cluster over a transport.
+    pub fn new(cluster: Arc<Cluster>, transport: Arc<dyn Transport>) -> Self {
+        Self { cluster, transport }
+    }
+
+    /// Immutable reference to the cluster whose payloads are published.
+    pub const fn cluster(&self) -> &Arc<Cluster> {
+        &self.cluster
+    }
+
+    /// Publish an event of a shard.
+    ///
+    /// Only [`Event::ShardPayload`]s are published; other events are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PublishErrorType::Sending`] error type if sending the
+    /// payload over the transport failed.
+    pub async fn publish(&self, shard_id: u64, event: Event) -> Result<(), PublishError> {
+        let payload = match event {
+            Event::ShardPayload(payload) => payload.bytes,
+            _ => return Ok(()),
+        };
+
+        self.transport
+            .send(Frame::Event { payload, shard_id })
+            .await
+            .map_err(|source| PublishError {
+                kind: PublishErrorType::Sending,
+                source: Some(source),
+            })
+    }
+
+    /// Publish all events of a stream, such as a cluster's [`Events`], until
+    /// the stream ends.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PublishErrorType::Sending`] error type if sending a payload
+    /// over the transport failed.
+    ///
+    /// [`Events`]: crate::cluster::Events
+    pub async fn publish_events(
+        &self,
+        mut events: impl Stream<Item = (u64, Event)> + Unpin,
+    ) -> Result<(), PublishError> {
+        while let Some((shard_id, event)) = events.next().await {
+            self.publish(shard_id, event).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Send commands received from consumers via the cluster's shards until
+    /// the transport is closed.
+    ///
+    /// Commands that can't be deserialized or sent are logged and skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PublishErrorType::Receiving`] error type if receiving a
+    /// frame from the transport failed.
+    pub async fn relay_commands(&self) -> Result<(), PublishError> {
+        loop {
+            let frame = self
+                .transport
+                .receive()
+                .await
+                .map_err(|source| PublishError {
+                    kind: PublishErrorType::Receiving,
+                    source: Some(source),
+                })?;
+
+            let (payload, shard_id) = match frame {
+                Some(Frame::Command { payload, shard_id }) => (payload, shard_id),
+                Some(_) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!("received non-command frame from consumer");
+
+                    continue;
+                }
+                None => return Ok(()),
+            };
+
+            let command = match serde_json::from_slice::<ForwardedCommand>(&payload) {
+                Ok(command) => command,
+                Err(_source) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(shard_id, "invalid forwarded command: {}", _source);
+
+                    continue;
+                }
+            };
+
+            let result = match &command {
+                ForwardedCommand::RequestGuildMembers(command) => {
+                    self.cluster.command(shard_id, command).await
+                }
+                ForwardedCommand::UpdatePresence(command) => {
+                    self.cluster.command(shard_id, command).await
+                }
+                ForwardedCommand::UpdateVoiceState(command) => {
+                    self.cluster.command(shard_id, command).await
+                }
+            };
+
+            if let Err(_source) = result {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(shard_id, "sending forwarded command failed: {}", _source);
+            }
+        }
+    }
+
+    /// Publish all events of a stream while relaying commands from consumers.
+    ///
+    /// Returns once either the stream ends or the transport is closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PublishErrorType::Receiving`] error type if receiving a
+    /// frame from the transport failed.
+    ///
+    /// Returns a [`PublishErrorType::Sending`] error type if sending a payload
+    /// over the transport failed.
+    pub async fn run(
+        &self,
+        events: impl Stream<Item = (u64, Event)> + Unpin,
+    ) -> Result<(), PublishError> {
+        let publishing = Box::pin(self.publish_events(events));
+        let relaying = Box::pin(self.relay_commands());
+
+        match future::select(publishing, relaying).await {
+            Either::Left((result, _)) | Either::Right((result, _)) => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PublishError, PublishErrorType, Publisher};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(PublishErrorType: Debug, Send, Sync);
+    assert_impl_all!(PublishError: Error, Send, Sync);
+    assert_impl_all!(Publisher: Debug, Send, Sync);
+}
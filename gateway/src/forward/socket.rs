@@ -0,0 +1,269 @@
+//! Transport over a TCP or Unix socket.
+
+use super::{Frame, Transport, TransportFuture};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    io::{Error as IoError, ErrorKind as IoErrorKind},
+};
+use tokio::{
+    io::{self, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadHalf, WriteHalf},
+    net::{TcpStream, ToSocketAddrs},
+    sync::Mutex,
+};
+
+#[cfg(unix)]
+use std::path::Path;
+#[cfg(unix)]
+use tokio::net::UnixStream;
+
+/// [`Transport`] over a stream, such as a TCP or Unix socket.
+///
+/// Frames are sent prefixed by their length as a big endian `u32`, followed by
+/// the [encoded frame]. Frames longer than [`FRAME_LENGTH_MAX`] are rejected
+/// when sending and receiving, so that a peer can't make the transport
+/// allocate an arbitrary amount of memory.
+///
+/// A socket connects one publisher to one consumer. Listening for connections
+/// is left to the user: accept a stream via a listener and wrap it with
+/// [`new`].
+///
+/// # Examples
+///
+/// Connect a consumer to a publisher listening on a TCP socket:
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::sync::Arc;
+/// use twilight_gateway::forward::{Consumer, SocketTransport};
+///
+/// let transport = SocketTransport::connect_tcp("127.0.0.1:5000").await?;
+/// let consumer = Consumer::new(Arc::new(transport));
+///
+/// while let Some((shard_id, event)) = consumer.next_event().await? {
+///     println!("shard {} received {:?}", shard_id, event.kind());
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [encoded frame]: Frame::encode
+/// [`FRAME_LENGTH_MAX`]: Self::FRAME_LENGTH_MAX
+/// [`new`]: Self::new
+pub struct SocketTransport<S> {
+    reader: Mutex<ReadHalf<S>>,
+    writer: Mutex<WriteHalf<S>>,
+}
+
+impl<S> SocketTransport<S> {
+    /// Maximum length of an encoded frame in bytes, which is 128 MiB.
+    pub const FRAME_LENGTH_MAX: u32 = 128 * 1024 * 1024;
+
+    /// Create an error for a frame that's longer than the maximum.
+    fn frame_too_long(len: usize) -> IoError {
+        IoError::new(
+            IoErrorKind::InvalidData,
+            format!(
+                "frame length {} exceeds the maximum of {}",
+                len,
+                Self::FRAME_LENGTH_MAX
+            ),
+        )
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite> SocketTransport<S> {
+    /// Create a new transport over a connected stream.
+    pub fn new(stream: S) -> Self {
+        let (reader, writer) = io::split(stream);
+
+        Self {
+            reader: Mutex::new(reader),
+            writer: Mutex::new(writer),
+        }
+    }
+}
+
+impl SocketTransport<TcpStream> {
+    /// Connect to a TCP socket.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if connecting to the socket failed.
+    pub async fn connect_tcp(address: impl ToSocketAddrs) -> Result<Self, IoError> {
+        let stream = TcpStream::connect(address).await?;
+        stream.set_nodelay(true)?;
+
+        Ok(Self::new(stream))
+    }
+}
+
+#[cfg(unix)]
+impl SocketTransport<UnixStream> {
+    /// Connect to a Unix socket.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if connecting to the socket failed.
+    pub async fn connect_unix(path: impl AsRef<Path>) -> Result<Self, IoError> {
+        UnixStream::connect(path).await.map(Self::new)
+    }
+}
+
+impl<S> Debug for SocketTransport<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("SocketTransport").finish()
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Send + 'static> Transport for SocketTransport<S> {
+    fn send(&self, frame: Frame) -> TransportFuture<'_, ()> {
+        Box::pin(async move {
+            let bytes = frame.encode();
+            let len = u32::try_from(bytes.len())
+                .ok()
+                .filter(|len| *len <= Self::FRAME_LENGTH_MAX)
+                .ok_or_else(|| Self::frame_too_long(bytes.len()))?;
+
+            let mut writer = self.writer.lock().await;
+            writer.write_all(&len.to_be_bytes()).await?;
+            writer.write_all(&bytes).await?;
+            writer.flush().await?;
+
+            Ok(())
+        })
+    }
+
+    /// Receive the next frame from the socket.
+    ///
+    /// Returns an error with a kind of [`IoErrorKind::InvalidData`] if the
+    /// length of the frame exceeds [`FRAME_LENGTH_MAX`].
+    ///
+    /// The returned future is not cancellation safe: dropping it while a frame
+    /// is being read leaves the socket in an undefined state.
+    ///
+    /// [`FRAME_LENGTH_MAX`]: Self::FRAME_LENGTH_MAX
+    fn receive(&self) -> TransportFuture<'_, Option<Frame>> {
+        Box::pin(async move {
+            let mut reader = self.reader.lock().await;
+
+            let mut len = [0; 4];
+
+            match reader.read_exact(&mut len).await {
+                Ok(_) => {}
+                Err(source) if source.kind() == IoErrorKind::UnexpectedEof => return Ok(None),
+                Err(source) => return Err(Box::new(source) as Box<dyn Error + Send + Sync>),
+            }
+
+            let len = u32::from_be_bytes(len);
+
+            if len > Self::FRAME_LENGTH_MAX {
+                return Err(
+                    Box::new(Self::frame_too_long(len as usize)) as Box<dyn Error + Send + Sync>
+                );
+            }
+
+            let mut bytes = vec![0; len as usize];
+            reader.read_exact(&mut bytes).await?;
+
+            Ok(Some(Frame::decode(&bytes)?))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SocketTransport;
+    use crate::forward::{Frame, Transport};
+    use static_assertions::assert_impl_all;
+    use std::io::{Error as IoError, ErrorKind as IoErrorKind};
+    use std::{error::Error, fmt::Debug};
+    use tokio::{
+        io::{AsyncWriteExt, DuplexStream},
+        net::{TcpListener, TcpStream},
+    };
+
+    assert_impl_all!(SocketTransport<DuplexStream>: Debug, Send, Sync, Transport);
+    assert_impl_all!(SocketTransport<TcpStream>: Debug, Send, Sync, Transport);
+
+    #[tokio::test]
+    async fn test_duplex() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (left, right) = tokio::io::duplex(64);
+        let left = SocketTransport::new(left);
+        let right = SocketTransport::new(right);
+
+        let frames = vec![
+            Frame::Event {
+                payload: vec![b'a'; 200],
+                shard_id: 1,
+            },
+            Frame::Command {
+                payload: b"{}".to_vec(),
+                shard_id: 2,
+            },
+        ];
+
+        let sending = async {
+            for frame in frames.clone() {
+                left.send(frame).await?;
+            }
+
+            drop(left);
+
+            Ok::<_, Box<dyn Error + Send + Sync>>(())
+        };
+        let receiving = async {
+            let mut received = Vec::new();
+
+            while let Some(frame) = right.receive().await? {
+                received.push(frame);
+            }
+
+            Ok::<_, Box<dyn Error + Send + Sync>>(received)
+        };
+
+        let (sent, received) = tokio::join!(sending, receiving);
+        sent?;
+        assert_eq!(frames, received?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_frame_too_long() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (mut left, right) = tokio::io::duplex(64);
+        let right = SocketTransport::new(right);
+
+        let len = SocketTransport::<DuplexStream>::FRAME_LENGTH_MAX + 1;
+        left.write_all(&len.to_be_bytes()).await?;
+
+        let error = right.receive().await.unwrap_err();
+        let error = error.downcast_ref::<IoError>().unwrap();
+        assert_eq!(IoErrorKind::InvalidData, error.kind());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tcp() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        let client = SocketTransport::connect_tcp(address).await?;
+        let (stream, _) = listener.accept().await?;
+        let server = SocketTransport::new(stream);
+
+        let frame = Frame::Event {
+            payload: b"{\"op\":11,\"d\":null}".to_vec(),
+            shard_id: 0,
+        };
+        server.send(frame.clone()).await?;
+        assert_eq!(Some(frame), client.receive().await?);
+
+        drop(server);
+        assert!(client.receive().await?.is_none());
+
+        Ok(())
+    }
+}
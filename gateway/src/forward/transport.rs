@@ -0,0 +1,31 @@
+//! Transport trait for carrying frames between publishers and consumers.
+
+use super::Frame;
+use std::{error::Error, fmt::Debug, future::Future, pin::Pin};
+
+/// Future returned by [`Transport`] methods, resolving to a value or the
+/// transport's error.
+pub type TransportFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<T, Box<dyn Error + Send + Sync>>> + Send + 'a>>;
+
+/// Carrier of [`Frame`]s between a [`Publisher`] and a [`Consumer`].
+///
+/// Implementations may be anything that can move bytes between processes,
+/// such as a socket or a message broker. Frames may be converted to and from
+/// bytes via [`Frame::encode`] and [`Frame::decode`].
+///
+/// A [`SocketTransport`] over TCP or Unix sockets is provided.
+///
+/// [`Consumer`]: super::Consumer
+/// [`Publisher`]: super::Publisher
+/// [`SocketTransport`]: super::SocketTransport
+pub trait Transport: Debug + Send + Sync {
+    /// Send a frame to the other side.
+    fn send(&self, frame: Frame) -> TransportFuture<'_, ()>;
+
+    /// Receive the next frame from the other side.
+    ///
+    /// The returned future must resolve to `None` once the transport is
+    /// closed and no more frames will be received.
+    fn receive(&self) -> TransportFuture<'_, Option<Frame>>;
+}
@@ -89,6 +89,13 @@
 //! Enabling **only** `zlib-simd` will make the library use [`zlib-ng`] which is a modern
 //! fork of zlib that is faster and more effective, but it needs `cmake` to compile.
 //!
+//! ### Forwarding
+//!
+//! The `forward` feature enables the `forward` module, which forwards gateway
+//! payloads between processes.
+//!
+//! This is disabled by default.
+//!
 //! ### Tracing
 //!
 //! The `tracing` feature enables logging via the [`tracing`] crate.
@@ -146,6 +153,8 @@
 )]
 
 pub mod cluster;
+#[cfg(feature = "forward")]
+pub mod forward;
 pub mod shard;
 
 mod event;
@@ -1,9 +1,10 @@
-use super::{config::Config, Events, Shard};
+use super::{config::Config, BackoffReconnectPolicy, Events, LazyEvents, ReconnectPolicy, Shard};
 use crate::EventTypeFlags;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     sync::Arc,
+    time::Duration,
 };
 use twilight_gateway_queue::{LocalQueue, Queue};
 use twilight_http::Client as HttpClient;
@@ -179,12 +180,14 @@ impl ShardBuilder {
         Self(Config {
             event_types: EventTypeFlags::default(),
             gateway_url: None,
+            guild_ready_timeout: Duration::from_secs(10),
             http_client: Arc::new(HttpClient::new(token.clone())),
             identify_properties: None,
             intents,
             large_threshold: 50,
             presence: None,
             queue: Arc::new(LocalQueue::new()),
+            reconnect_policy: Arc::new(BackoffReconnectPolicy::default()),
             shard: [0, 1],
             token: token.into_boxed_str(),
             session_id: None,
@@ -197,6 +200,50 @@ impl ShardBuilder {
         Shard::new_with_config(self.0)
     }
 
+    /// Consume the builder, constructing a shard that doesn't deserialize
+    /// dispatch events.
+    ///
+    /// Dispatch events are emitted as [`LazyEvent::Raw`] events exposing the
+    /// payload's header and raw JSON, and are only deserialized when
+    /// requested. Refer to the [`raw_event`] module for more information.
+    ///
+    /// # Examples
+    ///
+    /// Route events by their guild ID and only deserialize message create
+    /// events:
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use std::env;
+    /// use twilight_gateway::{
+    ///     shard::raw_event::LazyEvent,
+    ///     EventType, Intents, Shard,
+    /// };
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let (shard, mut events) = Shard::builder(token, Intents::GUILD_MESSAGES).build_lazy();
+    /// shard.start().await?;
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     if let LazyEvent::Raw(raw) = event {
+    ///         println!("event {:?} in guild {:?}", raw.event_type(), raw.guild_id()?);
+    ///
+    ///         if raw.kind() == Some(EventType::MessageCreate) {
+    ///             let message = raw.message_create()?;
+    ///             println!("message content: {}", message.content);
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`LazyEvent::Raw`]: super::raw_event::LazyEvent::Raw
+    /// [`raw_event`]: super::raw_event
+    pub fn build_lazy(self) -> (Shard, LazyEvents) {
+        Shard::new_lazy_with_config(self.0)
+    }
+
     /// Set the event types to process.
     ///
     /// This is an optimization technique; all events not included in the
@@ -218,6 +265,24 @@ impl ShardBuilder {
         self
     }
 
+    /// Set how long to wait for the next unavailable guild after a [`Ready`]
+    /// event before considering the shard's guilds ready.
+    ///
+    /// The timer is reset each time one of the guilds from the ready event is
+    /// received. Once it elapses, or once all guilds have been received, the
+    /// shard emits an [`Event::ShardGuildsReady`] listing the guilds that are
+    /// still unavailable.
+    ///
+    /// Default is 10 seconds.
+    ///
+    /// [`Event::ShardGuildsReady`]: crate::Event::ShardGuildsReady
+    /// [`Ready`]: twilight_model::gateway::payload::incoming::Ready
+    pub const fn guild_ready_timeout(mut self, guild_ready_timeout: Duration) -> Self {
+        self.0.guild_ready_timeout = guild_ready_timeout;
+
+        self
+    }
+
     /// Set the HTTP client to be used by the shard for getting gateway
     /// information.
     ///
@@ -363,6 +428,25 @@ impl ShardBuilder {
         self
     }
 
+    /// Set the policy deciding how the shard recovers after it is
+    /// disconnected.
+    ///
+    /// The policy decides whether to resume or re-identify, how long to wait
+    /// before doing so, and when to give up on the shard. A shard that is given
+    /// up on emits a [`ShardDead`] event, regardless of the [event types] it
+    /// emits, and stops.
+    ///
+    /// The default value is the default [`BackoffReconnectPolicy`], which
+    /// resumes when possible and never gives up.
+    ///
+    /// [`ShardDead`]: twilight_model::gateway::event::Event::ShardDead
+    /// [event types]: Self::event_types
+    pub fn reconnect_policy(mut self, reconnect_policy: Arc<dyn ReconnectPolicy>) -> Self {
+        self.0.reconnect_policy = reconnect_policy;
+
+        self
+    }
+
     /// Set the shard ID to connect as, and the total number of shards used by
     /// the bot.
     ///
@@ -1,5 +1,6 @@
+use super::ReconnectPolicy;
 use crate::EventTypeFlags;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use twilight_gateway_queue::Queue;
 use twilight_http::Client;
 use twilight_model::gateway::{
@@ -17,12 +18,14 @@ use twilight_model::gateway::{
 pub struct Config {
     pub(crate) event_types: EventTypeFlags,
     pub(crate) gateway_url: Option<Box<str>>,
+    pub(super) guild_ready_timeout: Duration,
     pub(crate) http_client: Arc<Client>,
     pub(super) identify_properties: Option<IdentifyProperties>,
     pub(super) intents: Intents,
     pub(super) large_threshold: u64,
     pub(super) presence: Option<UpdatePresencePayload>,
-    pub(super) queue: Arc<dyn Queue>,
+    pub(crate) queue: Arc<dyn Queue>,
+    pub(super) reconnect_policy: Arc<dyn ReconnectPolicy>,
     pub(crate) shard: [u64; 2],
     pub(super) token: Box<str>,
     pub(crate) session_id: Option<Box<str>>,
@@ -40,6 +43,16 @@ impl Config {
         self.gateway_url.as_deref()
     }
 
+    /// Return how long to wait for the next unavailable guild before
+    /// considering the shard's guilds ready.
+    ///
+    /// Refer to [`ShardBuilder::guild_ready_timeout`] for the default value.
+    ///
+    /// [`ShardBuilder::guild_ready_timeout`]: super::ShardBuilder::guild_ready_timeout
+    pub const fn guild_ready_timeout(&self) -> Duration {
+        self.guild_ready_timeout
+    }
+
     /// Return an immutable reference to the `twilight_http` client to be used
     /// by the shard.
     pub fn http_client(&self) -> &Client {
@@ -72,6 +85,16 @@ impl Config {
         self.presence.as_ref()
     }
 
+    /// Return an immutable reference to the policy deciding how the shard
+    /// recovers after it is disconnected.
+    ///
+    /// Refer to [`ShardBuilder::reconnect_policy`] for the default value.
+    ///
+    /// [`ShardBuilder::reconnect_policy`]: super::ShardBuilder::reconnect_policy
+    pub fn reconnect_policy(&self) -> &dyn ReconnectPolicy {
+        self.reconnect_policy.as_ref()
+    }
+
     /// The shard's ID and the total number of shards used by the bot.
     pub const fn shard(&self) -> [u64; 2] {
         self.shard
@@ -1,4 +1,7 @@
-use super::json;
+use super::{
+    json,
+    raw_event::{LazyEvent, RawEvent},
+};
 use crate::{Event, EventTypeFlags};
 use std::{
     convert::TryFrom,
@@ -60,12 +63,21 @@ pub enum EmitJsonErrorType {
     Parsing,
 }
 
+/// Sender to the listener of an [`Emitter`].
+#[derive(Clone, Debug)]
+enum EmitterSender {
+    /// Listener receives deserialized events.
+    Events(UnboundedSender<Event>),
+    /// Listener receives dispatch events without deserializing them.
+    Lazy(UnboundedSender<LazyEvent>),
+}
+
 /// Emitter over a listener with some useful things on top to abstract common
 /// operations.
 #[derive(Clone, Debug)]
 pub struct Emitter {
     event_types: EventTypeFlags,
-    tx: UnboundedSender<Event>,
+    tx: EmitterSender,
 }
 
 impl Emitter {
@@ -73,7 +85,27 @@ impl Emitter {
     pub fn new(event_types: EventTypeFlags) -> (Self, UnboundedReceiver<Event>) {
         let (tx, rx) = mpsc::unbounded_channel();
 
-        (Self { event_types, tx }, rx)
+        (
+            Self {
+                event_types,
+                tx: EmitterSender::Events(tx),
+            },
+            rx,
+        )
+    }
+
+    /// Create a new emitter for events and bytes that doesn't deserialize
+    /// dispatch events.
+    pub fn new_lazy(event_types: EventTypeFlags) -> (Self, UnboundedReceiver<LazyEvent>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        (
+            Self {
+                event_types,
+                tx: EmitterSender::Lazy(tx),
+            },
+            rx,
+        )
     }
 
     /// Whether the configured event types include an individual event type.
@@ -112,6 +144,9 @@ impl Emitter {
     /// Emit a JSON payload that hasn't been deserialized yet, but only if the
     /// listener wants the event type.
     ///
+    /// If the listener is lazy then the payload is emitted as a [`RawEvent`]
+    /// without being deserialized.
+    ///
     /// # Errors
     ///
     /// Returns a [`EmitJsonErrorType::EventTypeUnknown`] error type if the
@@ -138,6 +173,17 @@ impl Emitter {
         })?;
 
         if self.wants(flag) {
+            if let EmitterSender::Lazy(tx) = &self.tx {
+                let _res = tx.send(LazyEvent::Raw(RawEvent::new(
+                    op,
+                    seq,
+                    event_type,
+                    json.to_vec(),
+                )));
+
+                return Ok(());
+            }
+
             let gateway_event =
                 json::parse_gateway_event(op, seq, event_type, json).map_err(|source| {
                     EmitJsonError {
@@ -152,14 +198,17 @@ impl Emitter {
     }
 
     fn send(&self, event: Event) {
-        let _res = self.tx.send(event);
+        let _res = match &self.tx {
+            EmitterSender::Events(tx) => tx.send(event).map_err(drop),
+            EmitterSender::Lazy(tx) => tx.send(LazyEvent::Event(event)).map_err(drop),
+        };
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Emitter;
-    use crate::{Event, EventTypeFlags};
+    use crate::{shard::raw_event::LazyEvent, Event, EventTypeFlags};
 
     #[test]
     fn test_bytes_send() {
@@ -180,4 +229,25 @@ mod tests {
         // now check that the event didn't send the event twice
         assert!(rx.try_recv().is_err());
     }
+
+    #[test]
+    fn test_lazy_json_not_deserialized() {
+        let (emitter, mut rx) = Emitter::new_lazy(EventTypeFlags::default());
+        // Invalid for a typing start event, so it would fail to deserialize.
+        let mut json = br#"{"op":0,"s":1,"t":"TYPING_START","d":{}}"#.to_vec();
+        emitter
+            .json(0, Some(1), Some("TYPING_START"), &mut json)
+            .unwrap();
+        emitter.event(Event::GatewayReconnect);
+
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(LazyEvent::Raw(raw)) if raw.event_type() == Some("TYPING_START")
+        ));
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(LazyEvent::Event(Event::GatewayReconnect))
+        ));
+        assert!(rx.try_recv().is_err());
+    }
 }
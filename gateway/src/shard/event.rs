@@ -12,6 +12,7 @@
 //! [`EventType`]: ::twilight_model::gateway::event::EventType
 //! [`ShardBuilder::event_types`]: crate::shard::ShardBuilder::event_types
 
+use super::raw_event::LazyEvent;
 use crate::EventTypeFlags;
 use futures_util::stream::Stream;
 use std::{
@@ -61,12 +62,49 @@ impl Stream for Events {
     }
 }
 
+/// A stream of lazily deserialized events from a [`Shard`].
+///
+/// This is the equivalent of [`Events`] for shards built via
+/// [`ShardBuilder::build_lazy`]. Dispatch events are emitted as
+/// [`LazyEvent::Raw`] and are only deserialized when requested.
+///
+/// This implements [`futures::stream::Stream`].
+///
+/// [`Shard`]: super::Shard
+/// [`ShardBuilder::build_lazy`]: super::ShardBuilder::build_lazy
+/// [`futures::stream::Stream`]: https://docs.rs/futures/*/futures/stream/trait.Stream.html
+#[derive(Debug)]
+pub struct LazyEvents {
+    event_types: EventTypeFlags,
+    rx: UnboundedReceiver<LazyEvent>,
+}
+
+impl LazyEvents {
+    pub(super) const fn new(event_types: EventTypeFlags, rx: UnboundedReceiver<LazyEvent>) -> Self {
+        Self { event_types, rx }
+    }
+
+    /// Returns the event types that can be passed to this stream.
+    pub const fn event_types(&self) -> EventTypeFlags {
+        self.event_types
+    }
+}
+
+impl Stream for LazyEvents {
+    type Item = LazyEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Events;
+    use super::{Events, LazyEvents};
     use futures_util::stream::Stream;
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
 
     assert_impl_all!(Events: Debug, Send, Stream, Sync);
+    assert_impl_all!(LazyEvents: Debug, Send, Stream, Sync);
 }
@@ -3,7 +3,7 @@ use super::{
     command::Command,
     config::Config,
     emitter::Emitter,
-    event::Events,
+    event::{Events, LazyEvents},
     json,
     processor::{ConnectingErrorType, Latency, Session, ShardProcessor},
     raw_message::Message,
@@ -18,7 +18,10 @@ use std::{
     sync::{atomic::Ordering, Arc, Mutex},
 };
 use tokio::{
-    sync::{watch::Receiver as WatchReceiver, OnceCell},
+    sync::{
+        watch::{channel as watch_channel, Receiver as WatchReceiver, Sender as WatchSender},
+        OnceCell,
+    },
     task::JoinHandle,
 };
 use tokio_tungstenite::tungstenite::protocol::{
@@ -388,6 +391,8 @@ pub struct ResumeSession {
 pub struct Shard {
     config: Arc<Config>,
     emitter: Mutex<Option<Emitter>>,
+    guilds_ready: WatchReceiver<bool>,
+    guilds_ready_tx: Mutex<Option<WatchSender<bool>>>,
     processor_handle: OnceCell<JoinHandle<()>>,
     session: OnceCell<WatchReceiver<Arc<Session>>>,
 }
@@ -428,19 +433,36 @@ impl Shard {
     }
 
     pub(crate) fn new_with_config(config: Config) -> (Self, Events) {
-        let config = Arc::new(config);
         let event_types = config.event_types();
-
         let (emitter, rx) = Emitter::new(event_types);
 
-        let this = Self {
-            config,
+        (
+            Self::with_emitter(config, emitter),
+            Events::new(event_types, rx),
+        )
+    }
+
+    pub(crate) fn new_lazy_with_config(config: Config) -> (Self, LazyEvents) {
+        let event_types = config.event_types();
+        let (emitter, rx) = Emitter::new_lazy(event_types);
+
+        (
+            Self::with_emitter(config, emitter),
+            LazyEvents::new(event_types, rx),
+        )
+    }
+
+    fn with_emitter(config: Config, emitter: Emitter) -> Self {
+        let (guilds_ready_tx, guilds_ready) = watch_channel(false);
+
+        Self {
+            config: Arc::new(config),
             emitter: Mutex::new(Some(emitter)),
+            guilds_ready,
+            guilds_ready_tx: Mutex::new(Some(guilds_ready_tx)),
             processor_handle: OnceCell::new(),
             session: OnceCell::new(),
-        };
-
-        (this, Events::new(event_types, rx))
+        }
     }
 
     /// Create a builder to configure and construct a shard.
@@ -455,6 +477,64 @@ impl Shard {
         &self.config
     }
 
+    /// Wait until the shard has received all of the guilds from its session's
+    /// [`Ready`] event.
+    ///
+    /// This resolves at the same time that the shard emits an
+    /// [`Event::ShardGuildsReady`], which includes the guilds that stayed
+    /// unavailable. Refer to [`ShardBuilder::guild_ready_timeout`] for how long
+    /// the shard waits for unavailable guilds.
+    ///
+    /// Resolves immediately if the shard's guilds are already ready. When the
+    /// shard starts a new session the guilds are no longer considered ready
+    /// until they've been received again.
+    ///
+    /// Also resolves if the shard stops before its guilds are ready, and
+    /// immediately if the shard hasn't been [started].
+    ///
+    /// # Examples
+    ///
+    /// Start a shard and wait until its guilds are ready:
+    ///
+    /// ```no_run
+    /// use std::env;
+    /// use twilight_gateway::{Intents, Shard};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let token = env::var("DISCORD_TOKEN")?;
+    /// let (shard, _events) = Shard::new(token, Intents::GUILDS);
+    /// shard.start().await?;
+    ///
+    /// shard.guilds_ready().await;
+    /// println!("all guilds have been received");
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Event::ShardGuildsReady`]: crate::Event::ShardGuildsReady
+    /// [`Ready`]: twilight_model::gateway::payload::incoming::Ready
+    /// [started]: Self::start
+    pub async fn guilds_ready(&self) {
+        // The processor takes the sender when the shard starts, so until then
+        // nothing would ever mark the guilds as ready.
+        if self
+            .guilds_ready_tx
+            .lock()
+            .expect("guilds ready sender poisoned")
+            .is_some()
+        {
+            return;
+        }
+
+        let mut guilds_ready = self.guilds_ready.clone();
+
+        while !*guilds_ready.borrow() {
+            // The processor has stopped.
+            if guilds_ready.changed().await.is_err() {
+                break;
+            }
+        }
+    }
+
     /// Start the shard, connecting it to the gateway and starting the process
     /// of receiving and processing events.
     ///
@@ -513,25 +593,34 @@ impl Shard {
                 source: None,
             })?;
 
+        let guilds_ready_tx = self
+            .guilds_ready_tx
+            .lock()
+            .expect("guilds ready sender poisoned")
+            .take()
+            .ok_or(ShardStartError {
+                kind: ShardStartErrorType::AlreadyStarted,
+                source: None,
+            })?;
+
         let config = Arc::clone(&self.config);
-        let (processor, wrx) =
-            ShardProcessor::new(config, url, emitter)
-                .await
-                .map_err(|source| {
-                    let (kind, source) = source.into_parts();
-
-                    let new_kind = match kind {
-                        ConnectingErrorType::Establishing => ShardStartErrorType::Establishing,
-                        ConnectingErrorType::ParsingUrl { url } => {
-                            ShardStartErrorType::ParsingGatewayUrl { url }
-                        }
-                    };
-
-                    ShardStartError {
-                        source,
-                        kind: new_kind,
+        let (processor, wrx) = ShardProcessor::new(config, url, emitter, guilds_ready_tx)
+            .await
+            .map_err(|source| {
+                let (kind, source) = source.into_parts();
+
+                let new_kind = match kind {
+                    ConnectingErrorType::Establishing => ShardStartErrorType::Establishing,
+                    ConnectingErrorType::ParsingUrl { url } => {
+                        ShardStartErrorType::ParsingGatewayUrl { url }
                     }
-                })?;
+                };
+
+                ShardStartError {
+                    source,
+                    kind: new_kind,
+                }
+            })?;
 
         let handle = tokio::spawn(async move {
             processor.run().await;
@@ -793,4 +882,11 @@ mod tests {
     assert_impl_all!(ShardStartErrorType: Debug, Send, Sync);
     assert_impl_all!(ShardStartError: Error, Send, Sync);
     assert_impl_all!(Shard: Debug, Send, Sync);
+
+    #[tokio::test]
+    async fn test_guilds_ready_not_started() {
+        let (shard, _events) = Shard::new("token", twilight_model::gateway::Intents::GUILDS);
+
+        shard.guilds_ready().await;
+    }
 }
@@ -19,6 +19,7 @@
 //! [information about itself]: Shard::info
 //! [new messages]: ::twilight_model::gateway::event::Event::MessageCreate
 
+pub mod raw_event;
 pub mod raw_message;
 pub mod stage;
 
@@ -28,8 +29,9 @@ mod config;
 mod emitter;
 mod event;
 mod r#impl;
-mod json;
+pub(crate) mod json;
 mod processor;
+mod reconnect;
 
 pub use self::{
     builder::{
@@ -37,12 +39,16 @@ pub use self::{
     },
     command::Command,
     config::Config,
-    event::Events,
+    event::{Events, LazyEvents},
     processor::heartbeat::Latency,
     r#impl::{
         CommandError, CommandErrorType, Information, ResumeSession, SendError, SendErrorType,
         SessionInactiveError, Shard, ShardStartError, ShardStartErrorType,
     },
+    reconnect::{
+        Backoff, BackoffReconnectPolicy, DisconnectCause, ReconnectAction, ReconnectContext,
+        ReconnectPolicy,
+    },
     stage::Stage,
 };
 
@@ -0,0 +1,154 @@
+use std::{collections::HashSet, time::Duration};
+use tokio::time::Instant;
+use twilight_model::id::GuildId;
+
+/// Tracker of the guilds that a shard is still waiting to receive after a
+/// `Ready` event.
+///
+/// Discord sends a guild create event for every guild that was listed as
+/// unavailable in the ready event. The tracker considers the shard's guilds
+/// ready once all of them have been received, or once no guild has been
+/// received within the configured timeout.
+#[derive(Debug)]
+pub struct GuildsReadyTracker {
+    /// Time at which the remaining guilds are considered unavailable, if
+    /// tracking.
+    deadline: Option<Instant>,
+    /// Guilds that have not yet been received.
+    pending: HashSet<GuildId>,
+    /// How long to wait for the next guild before timing out.
+    timeout: Duration,
+    /// Guilds that Discord has explicitly marked as unavailable.
+    unavailable: Vec<GuildId>,
+}
+
+impl GuildsReadyTracker {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            deadline: None,
+            pending: HashSet::new(),
+            timeout,
+            unavailable: Vec::new(),
+        }
+    }
+
+    /// Time at which the remaining guilds are considered unavailable.
+    ///
+    /// Returns `None` if the tracker isn't waiting on any guilds.
+    pub const fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Start tracking the guilds of a new session, returning whether the
+    /// guilds are already ready.
+    pub fn start(&mut self, guilds: impl IntoIterator<Item = GuildId>) -> bool {
+        self.pending.clear();
+        self.unavailable.clear();
+        self.pending.extend(guilds);
+        self.deadline = Some(Instant::now() + self.timeout);
+
+        self.pending.is_empty()
+    }
+
+    /// Mark a guild as received, returning whether all guilds are now ready.
+    pub fn receive(&mut self, guild_id: GuildId) -> bool {
+        if self.pending.remove(&guild_id) {
+            self.deadline = Some(Instant::now() + self.timeout);
+        }
+
+        self.pending.is_empty()
+    }
+
+    /// Mark a guild as unavailable, returning whether all guilds are now
+    /// ready.
+    ///
+    /// Unavailable guilds are no longer waited on, and are reported when the
+    /// tracker finishes.
+    pub fn unavailable(&mut self, guild_id: GuildId) -> bool {
+        if self.pending.remove(&guild_id) {
+            self.unavailable.push(guild_id);
+        }
+
+        self.pending.is_empty()
+    }
+
+    /// Remove a guild that the current user is no longer in, returning whether
+    /// all guilds are now ready.
+    pub fn remove(&mut self, guild_id: GuildId) -> bool {
+        self.pending.remove(&guild_id);
+
+        self.pending.is_empty()
+    }
+
+    /// Stop tracking, returning the guilds that are unavailable.
+    ///
+    /// Guilds that were never received are considered unavailable.
+    pub fn finish(&mut self) -> Vec<GuildId> {
+        self.deadline = None;
+
+        let mut unavailable = std::mem::take(&mut self.unavailable);
+        unavailable.extend(self.pending.drain());
+        unavailable.sort_unstable();
+
+        unavailable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildsReadyTracker;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+    use twilight_model::id::GuildId;
+
+    assert_impl_all!(GuildsReadyTracker: Debug, Send, Sync);
+
+    fn guild_id(id: u64) -> GuildId {
+        GuildId::new(id).expect("non zero")
+    }
+
+    #[test]
+    fn test_no_guilds() {
+        let mut tracker = GuildsReadyTracker::new(Duration::from_secs(10));
+        assert!(tracker.deadline().is_none());
+        assert!(tracker.start(Vec::new()));
+        assert!(tracker.finish().is_empty());
+        assert!(tracker.deadline().is_none());
+    }
+
+    #[test]
+    fn test_all_received() {
+        let mut tracker = GuildsReadyTracker::new(Duration::from_secs(10));
+        assert!(!tracker.start(vec![guild_id(1), guild_id(2)]));
+        assert!(tracker.deadline().is_some());
+
+        assert!(!tracker.receive(guild_id(1)));
+        // Guilds that weren't in the ready event don't affect tracking.
+        assert!(!tracker.receive(guild_id(3)));
+        assert!(tracker.receive(guild_id(2)));
+        assert!(tracker.finish().is_empty());
+    }
+
+    #[test]
+    fn test_unavailable_and_removed() {
+        let mut tracker = GuildsReadyTracker::new(Duration::from_secs(10));
+        tracker.start(vec![guild_id(1), guild_id(2), guild_id(3), guild_id(4)]);
+
+        assert!(!tracker.unavailable(guild_id(3)));
+        assert!(!tracker.remove(guild_id(2)));
+        assert!(!tracker.receive(guild_id(1)));
+
+        assert_eq!(vec![guild_id(3), guild_id(4)], tracker.finish());
+        assert!(tracker.deadline().is_none());
+    }
+
+    #[test]
+    fn test_restart_clears_previous_session() {
+        let mut tracker = GuildsReadyTracker::new(Duration::from_secs(10));
+        tracker.start(vec![guild_id(1)]);
+        tracker.unavailable(guild_id(1));
+
+        assert!(!tracker.start(vec![guild_id(2)]));
+        assert_eq!(vec![guild_id(2)], tracker.finish());
+    }
+}
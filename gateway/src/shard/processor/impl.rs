@@ -3,10 +3,12 @@ use super::{
         config::Config,
         emitter::{EmitJsonErrorType, Emitter},
         json::{self, GatewayEventParsingError, GatewayEventParsingErrorType},
+        reconnect::{DisconnectCause, ReconnectAction, ReconnectContext},
         stage::Stage,
         ShardStream,
     },
     compression::{self, Compression},
+    guilds_ready::GuildsReadyTracker,
     session::{Session, SessionSendError, SessionSendErrorType},
     socket_forwarder::SocketForwarder,
 };
@@ -19,7 +21,6 @@ use std::{
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     str,
     sync::{atomic::Ordering, Arc},
-    time::Duration,
 };
 use tokio::sync::{
     mpsc::UnboundedReceiver,
@@ -31,7 +32,10 @@ use tokio_tungstenite::tungstenite::{
 };
 use twilight_model::gateway::{
     event::{
-        shard::{Connected, Connecting, Disconnected, Identifying, Reconnecting, Resuming},
+        shard::{
+            Connected, Connecting, Dead, Disconnected, GuildsReady, Identifying, Reconnecting,
+            Resuming,
+        },
         DispatchEvent, Event, GatewayEvent, GatewayEventDeserializer,
     },
     payload::{
@@ -43,6 +47,7 @@ use twilight_model::gateway::{
     },
     Intents, OpCode,
 };
+use twilight_model::id::GuildId;
 use url::Url;
 
 /// Connecting to the gateway failed.
@@ -173,15 +178,17 @@ impl ReceivingEventError {
             ReceivingEventErrorType::AuthorizationInvalid { .. }
                 | ReceivingEventErrorType::IntentsDisallowed { .. }
                 | ReceivingEventErrorType::IntentsInvalid { .. }
+                | ReceivingEventErrorType::ReconnectEscalated { .. }
         )
     }
 
-    const fn reconnectable(&self) -> bool {
-        matches!(self.kind, ReceivingEventErrorType::Decompressing)
-    }
-
-    const fn resumable(&self) -> bool {
-        matches!(self.kind, ReceivingEventErrorType::EventStreamEnded)
+    /// Cause of the disconnect to recover from, if the error is recoverable.
+    const fn disconnect_cause(&self) -> Option<DisconnectCause> {
+        match self.kind {
+            ReceivingEventErrorType::Decompressing => Some(DisconnectCause::Decompressing),
+            ReceivingEventErrorType::EventStreamEnded => Some(DisconnectCause::ConnectionLost),
+            _ => None,
+        }
     }
 }
 
@@ -216,6 +223,11 @@ impl Display for ReceivingEventError {
             ReceivingEventErrorType::EventStreamEnded => {
                 f.write_str("event stream from gateway ended")
             }
+            ReceivingEventErrorType::ReconnectEscalated { shard_id } => {
+                f.write_str("the reconnect policy gave up on shard ")?;
+
+                Display::fmt(shard_id, f)
+            }
         }
     }
 }
@@ -256,6 +268,11 @@ pub enum ReceivingEventErrorType {
         /// ID of the shard.
         shard_id: u64,
     },
+    /// Reconnect policy gave up on reconnecting the shard.
+    ReconnectEscalated {
+        /// ID of the shard.
+        shard_id: u64,
+    },
 }
 
 #[derive(Deserialize)]
@@ -263,6 +280,18 @@ struct ReadyMinimal {
     d: Ready,
 }
 
+#[derive(Deserialize)]
+struct GuildAvailabilityMinimal {
+    d: GuildAvailability,
+}
+
+#[derive(Deserialize)]
+struct GuildAvailability {
+    id: GuildId,
+    #[serde(default)]
+    unavailable: Option<bool>,
+}
+
 /// Runs in the background and processes incoming events, and then broadcasts
 /// to all listeners.
 #[derive(Debug)]
@@ -272,9 +301,17 @@ pub struct ShardProcessor {
     pub rx: UnboundedReceiver<Message>,
     pub session: Arc<Session>,
     compression: Compression,
+    guilds_ready: GuildsReadyTracker,
+    guilds_ready_tx: WatchSender<bool>,
     url: Box<str>,
     resume: Option<(u64, Box<str>)>,
     wtx: WatchSender<Arc<Session>>,
+    /// Number of reconnect attempts since the last successful session.
+    reconnect_attempts: u32,
+    /// Code of the most recent close frame since the last successful session.
+    last_close_code: Option<u16>,
+    /// Whether the shard has stopped and won't reconnect.
+    dead: bool,
 }
 
 impl ShardProcessor {
@@ -282,6 +319,7 @@ impl ShardProcessor {
         config: Arc<Config>,
         mut url: String,
         emitter: Emitter,
+        guilds_ready_tx: WatchSender<bool>,
     ) -> Result<(Self, WatchReceiver<Arc<Session>>), ConnectingError> {
         //if we got resume info we don't need to wait
         let shard_id = config.shard();
@@ -332,6 +370,8 @@ impl ShardProcessor {
 
         let mut processor = Self {
             compression: Compression::new(shard_id),
+            guilds_ready: GuildsReadyTracker::new(config.guild_ready_timeout()),
+            guilds_ready_tx,
             config,
             emitter,
             rx,
@@ -339,36 +379,43 @@ impl ShardProcessor {
             url: url.into_boxed_str(),
             resume: None,
             wtx,
+            reconnect_attempts: 0,
+            last_close_code: None,
+            dead: false,
         };
 
         if resumable {
             #[cfg(feature = "tracing")]
             tracing::debug!("resuming shard {:?}", shard_id);
 
-            processor.resume().await;
+            let seq = processor.session.seq();
+            let resumed = match processor.session.id() {
+                Some(id) => processor.resume(seq, id).await.is_ok(),
+                None => false,
+            };
+
+            if !resumed {
+                processor.recover(DisconnectCause::ConnectFailed).await;
+            }
         }
 
         Ok((processor, wrx))
     }
 
     pub async fn run(mut self) {
-        loop {
+        while !self.dead {
             if let Err(source) = self.next_payload().await {
                 #[cfg(feature = "tracing")]
                 tracing::warn!("{}", source);
 
-                self.emit_disconnected(None, None).await;
-
                 if source.fatal() {
                     break;
                 }
 
-                if source.reconnectable() {
-                    self.reconnect().await;
-                }
+                self.emit_disconnected(None, None).await;
 
-                if source.resumable() {
-                    self.resume().await;
+                if let Some(cause) = source.disconnect_cause() {
+                    self.recover(cause).await;
                 }
 
                 continue;
@@ -388,7 +435,7 @@ impl ShardProcessor {
                     tracing::debug!("error processing event; reconnecting");
                     self.emit_disconnected(None, None).await;
 
-                    self.reconnect().await;
+                    self.recover(DisconnectCause::SendingFailed).await;
                 }
             }
         }
@@ -500,10 +547,18 @@ impl ShardProcessor {
                     })),
                 })?;
 
-                self.process_ready(&ready.d);
+                let guilds_ready = self.process_ready(&ready.d);
                 emitter.event(Event::Ready(Box::new(ready.d)));
 
+                if guilds_ready {
+                    self.emit_guilds_ready();
+                }
+
                 return Ok(());
+            } else if self.guilds_ready.deadline().is_some()
+                && matches!(event_type.as_deref(), Some("GUILD_CREATE" | "GUILD_DELETE"))
+            {
+                self.process_guild_availability(event_type.as_deref() == Some("GUILD_DELETE"))?;
             }
 
             self.session.set_seq(seq);
@@ -532,26 +587,70 @@ impl ShardProcessor {
             })
     }
 
-    fn process_ready(&mut self, ready: &Ready) {
+    /// Process a new session, returning whether all of the session's guilds
+    /// are already available.
+    fn process_ready(&mut self, ready: &Ready) -> bool {
         #[cfg(feature = "metrics")]
         metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "Dispatch");
 
         self.session.set_stage(Stage::Connected);
         self.session
             .set_id(ready.session_id.clone().into_boxed_str());
+        self.reconnect_attempts = 0;
+        self.last_close_code = None;
 
         self.emitter.event(Event::ShardConnected(Connected {
             heartbeat_interval: self.session.heartbeat_interval(),
             shard_id: self.config.shard()[0],
         }));
+
+        // A new session re-sends all of the guilds, so the shard is no longer
+        // ready until they've been received.
+        let _res = self.guilds_ready_tx.send(false);
+
+        self.guilds_ready
+            .start(ready.guilds.iter().map(|guild| guild.id))
+    }
+
+    /// Process a guild create or delete event while waiting for the guilds of
+    /// the session's ready event.
+    ///
+    /// Only the guild's ID and availability are deserialized, via `serde_json`
+    /// even if `simd-json` is enabled since it doesn't modify the payload, so
+    /// that the event can still be emitted as usual.
+    fn process_guild_availability(&mut self, deleted: bool) -> Result<(), ProcessError> {
+        let guild =
+            serde_json::from_slice::<GuildAvailabilityMinimal>(self.compression.buffer_slice_mut())
+                .map_err(|source| ProcessError {
+                    kind: ProcessErrorType::ParsingPayload,
+                    source: Some(Box::new(GatewayEventParsingError {
+                        kind: GatewayEventParsingErrorType::Deserializing,
+                        source: Some(Box::new(source)),
+                    })),
+                })?
+                .d;
+
+        let ready = match (deleted, guild.unavailable.unwrap_or_default()) {
+            (_, true) => self.guilds_ready.unavailable(guild.id),
+            (false, false) => self.guilds_ready.receive(guild.id),
+            (true, false) => self.guilds_ready.remove(guild.id),
+        };
+
+        if ready {
+            self.emit_guilds_ready();
+        }
+
+        Ok(())
     }
 
-    fn process_resumed(&self, seq: u64) {
+    fn process_resumed(&mut self, seq: u64) {
         #[cfg(feature = "metrics")]
         metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "Dispatch");
 
         self.session.set_seq(seq);
         self.session.set_stage(Stage::Connected);
+        self.reconnect_attempts = 0;
+        self.last_close_code = None;
         self.emitter.event(Event::ShardConnected(Connected {
             heartbeat_interval: self.session.heartbeat_interval(),
             shard_id: self.config.shard()[0],
@@ -586,7 +685,7 @@ impl ShardProcessor {
         metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "Heartbeat");
 
         if seq > self.session.seq() + 1 {
-            self.resume().await;
+            self.recover(DisconnectCause::SequenceSkipped).await;
         }
 
         if let Err(_source) = self.session.heartbeat() {
@@ -595,7 +694,7 @@ impl ShardProcessor {
 
             self.emit_disconnected(None, None).await;
 
-            self.reconnect().await;
+            self.recover(DisconnectCause::SendingFailed).await;
         }
     }
 
@@ -654,17 +753,16 @@ impl ShardProcessor {
 
             #[cfg(feature = "tracing")]
             tracing::debug!("got request to resume the session");
-
-            self.resume().await;
         } else {
             #[cfg(feature = "metrics")]
             metrics::counter!("GatewayEvent", 1, "GatewayEvent" => "InvalidateSessionFalse");
 
             #[cfg(feature = "tracing")]
             tracing::debug!("got request to invalidate the session and reconnect");
-
-            self.reconnect().await;
         }
+
+        self.recover(DisconnectCause::InvalidSession { resumable })
+            .await;
     }
 
     async fn process_reconnect(&mut self) -> Result<(), ProcessError> {
@@ -686,7 +784,7 @@ impl ShardProcessor {
             })?;
         self.emit_disconnected(Some(frame.code.into()), Some(frame.reason.to_string()))
             .await;
-        self.resume().await;
+        self.recover(DisconnectCause::ReconnectRequested).await;
 
         Ok(())
     }
@@ -699,7 +797,7 @@ impl ShardProcessor {
             if matches!(source.kind(), SessionSendErrorType::Sending { .. }) {
                 self.emit_disconnected(None, None).await;
 
-                self.reconnect().await;
+                self.recover(DisconnectCause::SendingFailed).await;
             }
 
             return Err(source);
@@ -721,9 +819,23 @@ impl ShardProcessor {
         self.compression.clear();
 
         loop {
+            // Stop waiting on the next message once the session's guilds
+            // are considered ready.
+            let msg = if let Some(deadline) = self.guilds_ready.deadline() {
+                if let Ok(msg) = tokio::time::timeout_at(deadline, self.rx.recv()).await {
+                    msg
+                } else {
+                    self.emit_guilds_ready();
+
+                    continue;
+                }
+            } else {
+                self.rx.recv().await
+            };
+
             // Returns None when the socket forwarder has ended, meaning the
             // connection was dropped.
-            let mut msg = self.rx.recv().await.ok_or(ReceivingEventError {
+            let mut msg = msg.ok_or(ReceivingEventError {
                 kind: ReceivingEventErrorType::EventStreamEnded,
                 source: None,
             })?;
@@ -804,7 +916,17 @@ impl ShardProcessor {
         )
         .await;
 
+        let code = close_frame.map(|c| c.code.into());
+
+        if code.is_some() {
+            self.last_close_code = code;
+        }
+
         if let Some(close_frame) = close_frame {
+            if matches!(close_frame.code, CloseCode::Library(4004 | 4013 | 4014)) {
+                self.escalate();
+            }
+
             match close_frame.code {
                 CloseCode::Library(4004) => {
                     return Err(ReceivingEventError {
@@ -837,7 +959,16 @@ impl ShardProcessor {
             }
         }
 
-        self.resume().await;
+        self.recover(DisconnectCause::Closed { code }).await;
+
+        if self.dead {
+            return Err(ReceivingEventError {
+                kind: ReceivingEventErrorType::ReconnectEscalated {
+                    shard_id: self.config.shard()[0],
+                },
+                source: None,
+            });
+        }
 
         Ok(())
     }
@@ -902,91 +1033,136 @@ impl ShardProcessor {
         self.send(identify).await
     }
 
-    /// Perform a full reconnect to the gateway, instantiating a new session.
-    async fn reconnect(&mut self) {
-        #[cfg(feature = "tracing")]
-        tracing::info!("reconnection started");
+    /// Recover from a disconnect as decided by the reconnect policy.
+    ///
+    /// Attempts are made until the shard is connected or the policy escalates,
+    /// in which case the shard is marked as dead.
+    async fn recover(&mut self, mut cause: DisconnectCause) {
+        if self.dead {
+            return;
+        }
 
-        let mut wait = Duration::from_secs(1);
+        self.session.stop_heartbeater();
 
         loop {
+            self.reconnect_attempts = self.reconnect_attempts.saturating_add(1);
+
+            let seq = self.session.seq();
+            let id = self.session.id();
+            let context = ReconnectContext::new(
+                self.reconnect_attempts,
+                cause,
+                id.is_some(),
+                self.config.shard(),
+            );
+
+            let action = self.config.reconnect_policy().decide(&context);
+
             #[cfg(feature = "tracing")]
             tracing::debug!(
                 shard_id = self.config.shard()[0],
                 shard_total = self.config.shard()[1],
-                wait_in_seconds = wait.as_secs(),
-                "waiting before attempting a reconnect",
+                attempt = self.reconnect_attempts,
+                ?cause,
+                ?action,
+                "recovering from disconnect",
             );
 
-            tokio::time::sleep(wait).await;
-
-            // Await allowance when doing a full reconnect.
-            self.config.queue.request(self.config.shard()).await;
+            let result = match (action, id) {
+                (ReconnectAction::Escalate, _) => {
+                    self.reconnect_attempts -= 1;
+                    self.escalate();
 
-            self.emitter.event(Event::ShardReconnecting(Reconnecting {
-                shard_id: self.config.shard()[0],
-            }));
-
-            let stream = match Self::connect(&self.url).await {
-                Ok(s) => s,
-                Err(_source) => {
-                    #[cfg(feature = "tracing")]
-                    tracing::warn!("reconnecting failed: {:?}", _source);
+                    return;
+                }
+                (ReconnectAction::Resume { delay }, Some(id)) => {
+                    tokio::time::sleep(delay).await;
 
-                    if wait < Duration::from_secs(128) {
-                        wait *= 2;
-                    }
+                    self.resume(seq, id).await
+                }
+                (ReconnectAction::Reidentify { delay } | ReconnectAction::Resume { delay }, _) => {
+                    tokio::time::sleep(delay).await;
 
-                    continue;
+                    self.reconnect().await
                 }
             };
 
-            self.set_session(stream, Stage::Connected);
+            if let Err(_source) = result {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    shard_id = self.config.shard()[0],
+                    "reconnecting failed: {:?}",
+                    _source,
+                );
+
+                cause = DisconnectCause::ConnectFailed;
+
+                continue;
+            }
 
             break;
         }
+    }
+
+    /// Perform a full reconnect to the gateway, instantiating a new session.
+    async fn reconnect(&mut self) -> Result<(), ConnectingError> {
+        #[cfg(feature = "tracing")]
+        tracing::info!("reconnection started");
+
+        // Await allowance when doing a full reconnect.
+        self.config.queue.request(self.config.shard()).await;
+
+        self.emitter.event(Event::ShardReconnecting(Reconnecting {
+            shard_id: self.config.shard()[0],
+        }));
+
+        let stream = Self::connect(&self.url).await?;
+        self.set_session(stream, Stage::Connected);
 
         self.emitter.event(Event::ShardConnecting(Connecting {
             gateway: self.url.clone().into_string(),
             shard_id: self.config.shard()[0],
         }));
+
+        Ok(())
     }
 
-    /// Resume a session if possible, defaulting to instantiating a new
-    /// connection.
-    async fn resume(&mut self) {
+    /// Resume a session.
+    async fn resume(&mut self, seq: u64, id: Box<str>) -> Result<(), ConnectingError> {
         #[cfg(feature = "tracing")]
-        tracing::debug!("resuming shard {:?}", self.config.shard());
+        tracing::debug!(
+            seq,
+            session_id = %id,
+            shard_id = self.config.shard()[0],
+            "resuming session",
+        );
 
         self.session.set_stage(Stage::Resuming);
         self.session.stop_heartbeater();
-
-        let seq = self.session.seq();
-
-        let id = if let Some(id) = self.session.id() {
-            id
-        } else {
-            #[cfg(feature = "tracing")]
-            tracing::info!("session id unavailable, reconnecting");
-
-            self.reconnect().await;
-            return;
-        };
-
         self.resume = Some((seq, id));
 
-        if let Err(_source) = self.try_resume().await {
-            #[cfg(feature = "tracing")]
-            tracing::warn!(
-                seq = seq,
-                session_id = ?self.session.id(),
-                shard_id = self.config.shard()[0],
-                "failed to resume session: {:?}",
-                _source,
-            );
+        self.try_resume().await
+    }
 
-            self.reconnect().await;
-        }
+    /// Stop the shard, notifying listeners that it won't reconnect.
+    fn escalate(&mut self) {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            shard_id = self.config.shard()[0],
+            attempts = self.reconnect_attempts,
+            code = ?self.last_close_code,
+            "shard is dead and won't reconnect",
+        );
+
+        self.dead = true;
+        self.session.stop_heartbeater();
+        self.session.set_stage(Stage::Disconnected);
+
+        self.emitter.event(Event::ShardDead(Dead {
+            attempts: self.reconnect_attempts,
+            code: self.last_close_code,
+            shard_id: self.config.shard()[0],
+        }));
     }
 
     /// Attempt to resume a session.
@@ -1024,6 +1200,26 @@ impl ShardProcessor {
         self.compression.reset();
     }
 
+    /// Stop waiting on the session's guilds and notify listeners that the
+    /// shard's guilds are ready.
+    fn emit_guilds_ready(&mut self) {
+        let unavailable_guilds = self.guilds_ready.finish();
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            shard_id = self.config.shard()[0],
+            unavailable = unavailable_guilds.len(),
+            "guilds ready",
+        );
+
+        let _res = self.guilds_ready_tx.send(true);
+
+        self.emitter.event(Event::ShardGuildsReady(GuildsReady {
+            shard_id: self.config.shard()[0],
+            unavailable_guilds,
+        }));
+    }
+
     async fn emit_disconnected(&self, code: Option<u16>, reason: Option<String>) {
         self.emitter.event(Event::ShardDisconnected(Disconnected {
             code,
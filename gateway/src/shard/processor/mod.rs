@@ -1,6 +1,7 @@
 pub mod heartbeat;
 
 mod compression;
+mod guilds_ready;
 mod r#impl;
 mod session;
 mod socket_forwarder;
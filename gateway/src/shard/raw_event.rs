@@ -0,0 +1,459 @@
+//! Lazily deserialized events emitted by shards built in lazy mode.
+//!
+//! Shards built via [`ShardBuilder::build_lazy`] don't deserialize dispatch
+//! events into [`Event`]s. Instead they emit a [`RawEvent`] containing the
+//! payload's header (its opcode, sequence, and event type) and the raw JSON
+//! bytes. The event can then be routed based on its header, and deserialized
+//! into an [`Event`] only when needed.
+//!
+//! For frequently received events, such as [`MessageCreate`] and
+//! [`PresenceUpdate`], borrowed variants are provided that only deserialize a
+//! few fields and borrow strings from the payload.
+//!
+//! [`MessageCreate`]: twilight_model::gateway::payload::incoming::MessageCreate
+//! [`PresenceUpdate`]: twilight_model::gateway::payload::incoming::PresenceUpdate
+//! [`ShardBuilder::build_lazy`]: super::ShardBuilder::build_lazy
+
+use super::json::{self, GatewayEventParsingError};
+use serde::Deserialize;
+use std::{
+    borrow::Cow,
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    gateway::{
+        event::{Event, EventType},
+        presence::Status,
+    },
+    id::{ChannelId, GuildId, MessageId, UserId, WebhookId},
+};
+
+/// Deserializing a [`RawEvent`] failed.
+#[derive(Debug)]
+pub struct RawEventDeserializeError {
+    kind: RawEventDeserializeErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl RawEventDeserializeError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &RawEventDeserializeErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        RawEventDeserializeErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for RawEventDeserializeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            RawEventDeserializeErrorType::Deserializing => {
+                f.write_str("deserializing the event's payload failed")
+            }
+            RawEventDeserializeErrorType::EventTypeMismatch { expected } => {
+                f.write_str("event is not of the expected type ")?;
+
+                f.write_str(expected.name().unwrap_or_default())
+            }
+        }
+    }
+}
+
+impl Error for RawEventDeserializeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`RawEventDeserializeError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RawEventDeserializeErrorType {
+    /// Deserializing the payload failed.
+    Deserializing,
+    /// Event is not of the type required by the borrowed variant.
+    EventTypeMismatch {
+        /// Event type required by the borrowed variant.
+        expected: EventType,
+    },
+}
+
+/// Event emitted by a shard in lazy mode.
+///
+/// Events that the shard deserializes itself, such as [`Event::Ready`] and
+/// shard state events like [`Event::ShardConnected`], are emitted as-is.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum LazyEvent {
+    /// Event that has already been deserialized.
+    Event(Event),
+    /// Dispatch event that has not been deserialized yet.
+    Raw(RawEvent),
+}
+
+impl LazyEvent {
+    /// Type of the event.
+    ///
+    /// Returns `None` if the event is a raw event of a type unknown to
+    /// [`EventType`].
+    pub fn kind(&self) -> Option<EventType> {
+        match self {
+            Self::Event(event) => Some(event.kind()),
+            Self::Raw(raw) => raw.kind(),
+        }
+    }
+
+    /// Deserialize the event if it hasn't been already.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RawEventDeserializeErrorType::Deserializing`] error type if
+    /// the event is a raw event whose payload could not be deserialized.
+    pub fn into_event(self) -> Result<Event, RawEventDeserializeError> {
+        match self {
+            Self::Event(event) => Ok(event),
+            Self::Raw(raw) => raw.deserialize(),
+        }
+    }
+}
+
+/// Dispatch event whose payload has not been deserialized.
+///
+/// The header of the payload is available without any further parsing, while
+/// the payload itself may be deserialized into an [`Event`] via
+/// [`deserialize`], or partially via [`guild_id`] and the borrowed variants.
+///
+/// [`deserialize`]: Self::deserialize
+/// [`guild_id`]: Self::guild_id
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RawEvent {
+    event_type: Option<Box<str>>,
+    json: Vec<u8>,
+    op: u8,
+    seq: Option<u64>,
+}
+
+impl RawEvent {
+    pub(super) fn new(op: u8, seq: Option<u64>, event_type: Option<&str>, json: Vec<u8>) -> Self {
+        Self {
+            event_type: event_type.map(Box::from),
+            json,
+            op,
+            seq,
+        }
+    }
+
+    /// Name of the dispatch event, the `t` field of the payload.
+    pub fn event_type(&self) -> Option<&str> {
+        self.event_type.as_deref()
+    }
+
+    /// Type of the event, if it's known.
+    pub fn kind(&self) -> Option<EventType> {
+        self.event_type
+            .as_deref()
+            .and_then(|event_type| EventType::try_from(event_type).ok())
+    }
+
+    /// Opcode of the payload, the `op` field of the payload.
+    pub const fn op(&self) -> u8 {
+        self.op
+    }
+
+    /// Sequence of the payload, the `s` field of the payload.
+    pub const fn seq(&self) -> Option<u64> {
+        self.seq
+    }
+
+    /// Immutable reference to the raw JSON bytes of the payload.
+    pub fn json(&self) -> &[u8] {
+        &self.json
+    }
+
+    /// Consume the event, returning the raw JSON bytes of the payload.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_json(self) -> Vec<u8> {
+        self.json
+    }
+
+    /// Consume the event, deserializing it into an [`Event`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RawEventDeserializeErrorType::Deserializing`] error type if
+    /// the payload could not be deserialized.
+    pub fn deserialize(mut self) -> Result<Event, RawEventDeserializeError> {
+        json::parse_gateway_event(
+            self.op,
+            self.seq,
+            self.event_type.as_deref(),
+            &mut self.json,
+        )
+        .map(Event::from)
+        .map_err(
+            |source: GatewayEventParsingError| RawEventDeserializeError {
+                kind: RawEventDeserializeErrorType::Deserializing,
+                source: Some(Box::new(source)),
+            },
+        )
+    }
+
+    /// ID of the guild the event is in, if any.
+    ///
+    /// Only the `guild_id` field of the event's data is deserialized. Events
+    /// whose data doesn't have a `guild_id` field, such as guild create
+    /// events, return `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RawEventDeserializeErrorType::Deserializing`] error type if
+    /// the payload could not be deserialized.
+    pub fn guild_id(&self) -> Result<Option<GuildId>, RawEventDeserializeError> {
+        #[derive(Deserialize)]
+        struct GuildIdMinimal {
+            #[serde(default)]
+            guild_id: Option<GuildId>,
+        }
+
+        self.data::<GuildIdMinimal>().map(|data| data.guild_id)
+    }
+
+    /// Deserialize the event's data into a [`MessageCreateRef`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RawEventDeserializeErrorType::EventTypeMismatch`] error type
+    /// if the event is not a message create event.
+    ///
+    /// Returns a [`RawEventDeserializeErrorType::Deserializing`] error type if
+    /// the payload could not be deserialized.
+    pub fn message_create(&self) -> Result<MessageCreateRef<'_>, RawEventDeserializeError> {
+        self.check_kind(EventType::MessageCreate)?;
+
+        self.data()
+    }
+
+    /// Deserialize the event's data into a [`PresenceUpdateRef`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RawEventDeserializeErrorType::EventTypeMismatch`] error type
+    /// if the event is not a presence update event.
+    ///
+    /// Returns a [`RawEventDeserializeErrorType::Deserializing`] error type if
+    /// the payload could not be deserialized.
+    pub fn presence_update(&self) -> Result<PresenceUpdateRef<'_>, RawEventDeserializeError> {
+        self.check_kind(EventType::PresenceUpdate)?;
+
+        self.data()
+    }
+
+    fn check_kind(&self, expected: EventType) -> Result<(), RawEventDeserializeError> {
+        if self.event_type.as_deref() == expected.name() {
+            Ok(())
+        } else {
+            Err(RawEventDeserializeError {
+                kind: RawEventDeserializeErrorType::EventTypeMismatch { expected },
+                source: None,
+            })
+        }
+    }
+
+    /// Deserialize the `d` field of the payload, borrowing from the payload.
+    ///
+    /// This always uses `serde_json`, because `simd-json` mutates the input
+    /// while parsing and so can't borrow from an immutable payload.
+    fn data<'a, T: Deserialize<'a>>(&'a self) -> Result<T, RawEventDeserializeError> {
+        #[derive(Deserialize)]
+        struct DataMinimal<T> {
+            d: T,
+        }
+
+        serde_json::from_slice::<DataMinimal<T>>(&self.json)
+            .map(|payload| payload.d)
+            .map_err(|source| RawEventDeserializeError {
+                kind: RawEventDeserializeErrorType::Deserializing,
+                source: Some(Box::new(source)),
+            })
+    }
+}
+
+/// Borrowed variant of a [`MessageCreate`] containing only the most commonly
+/// used fields.
+///
+/// Strings are borrowed from the payload unless they contain escape sequences.
+///
+/// [`MessageCreate`]: twilight_model::gateway::payload::incoming::MessageCreate
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct MessageCreateRef<'a> {
+    /// Author of the message.
+    #[serde(borrow)]
+    pub author: UserRef<'a>,
+    /// ID of the channel the message was sent in.
+    pub channel_id: ChannelId,
+    /// Content of the message.
+    #[serde(borrow)]
+    pub content: Cow<'a, str>,
+    /// ID of the guild the message was sent in, if any.
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
+    /// ID of the message.
+    pub id: MessageId,
+    /// ID of the webhook that sent the message, if any.
+    #[serde(default)]
+    pub webhook_id: Option<WebhookId>,
+}
+
+/// Borrowed variant of a [`User`] containing only the most commonly used
+/// fields.
+///
+/// [`User`]: twilight_model::user::User
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct UserRef<'a> {
+    /// Whether the user is a bot.
+    #[serde(default)]
+    pub bot: bool,
+    /// Discriminator of the user.
+    #[serde(borrow)]
+    pub discriminator: Cow<'a, str>,
+    /// ID of the user.
+    pub id: UserId,
+    /// Name of the user.
+    #[serde(borrow, rename = "username")]
+    pub name: Cow<'a, str>,
+}
+
+/// Borrowed variant of a [`PresenceUpdate`] containing only the most commonly
+/// used fields.
+///
+/// [`PresenceUpdate`]: twilight_model::gateway::payload::incoming::PresenceUpdate
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct PresenceUpdateRef<'a> {
+    /// ID of the guild the presence was updated in.
+    pub guild_id: GuildId,
+    /// New status of the user.
+    pub status: Status,
+    /// User whose presence was updated.
+    #[serde(borrow)]
+    pub user: PresenceUserRef<'a>,
+}
+
+/// User of a [`PresenceUpdateRef`].
+///
+/// Only the ID of the user is always present.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct PresenceUserRef<'a> {
+    /// ID of the user.
+    pub id: UserId,
+    /// Name of the user, if it changed.
+    #[serde(borrow, default, rename = "username")]
+    pub name: Option<Cow<'a, str>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        LazyEvent, MessageCreateRef, PresenceUpdateRef, RawEvent, RawEventDeserializeError,
+        RawEventDeserializeErrorType,
+    };
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{borrow::Cow, error::Error, fmt::Debug};
+    use twilight_model::{
+        gateway::{event::EventType, presence::Status},
+        id::{ChannelId, GuildId, MessageId, UserId},
+    };
+
+    assert_impl_all!(LazyEvent: Clone, Debug, Send, Sync);
+    assert_impl_all!(RawEvent: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(RawEventDeserializeErrorType: Debug, Send, Sync);
+    assert_fields!(RawEventDeserializeErrorType::EventTypeMismatch: expected);
+    assert_impl_all!(RawEventDeserializeError: Error, Send, Sync);
+
+    const MESSAGE_CREATE: &str = r#"{"op":0,"s":3,"t":"MESSAGE_CREATE","d":{"attachments":[],"author":{"avatar":null,"discriminator":"0001","id":"2","username":"twilight"},"channel_id":"3","content":"hello \"world\"","edited_timestamp":null,"embeds":[],"guild_id":"1","id":"4","mention_everyone":false,"mention_roles":[],"mentions":[],"pinned":false,"timestamp":"2021-01-01T00:00:00.000000+00:00","tts":false,"type":0}}"#;
+
+    const PRESENCE_UPDATE: &str = r#"{"op":0,"s":4,"t":"PRESENCE_UPDATE","d":{"activities":[],"client_status":{"desktop":"online"},"guild_id":"1","status":"online","user":{"id":"2"}}}"#;
+
+    fn raw(json: &str, seq: u64, event_type: &str) -> RawEvent {
+        RawEvent::new(0, Some(seq), Some(event_type), json.as_bytes().to_vec())
+    }
+
+    #[test]
+    fn test_header() {
+        let event = raw(MESSAGE_CREATE, 3, "MESSAGE_CREATE");
+
+        assert_eq!(Some("MESSAGE_CREATE"), event.event_type());
+        assert_eq!(Some(EventType::MessageCreate), event.kind());
+        assert_eq!(0, event.op());
+        assert_eq!(Some(3), event.seq());
+        assert_eq!(MESSAGE_CREATE.as_bytes(), event.json());
+        assert_eq!(
+            Some(GuildId::new(1).expect("non zero")),
+            event.guild_id().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_message_create_ref() {
+        let event = raw(MESSAGE_CREATE, 3, "MESSAGE_CREATE");
+        let message: MessageCreateRef<'_> = event.message_create().unwrap();
+
+        assert_eq!(UserId::new(2).expect("non zero"), message.author.id);
+        assert!(matches!(message.author.name, Cow::Borrowed("twilight")));
+        assert_eq!(ChannelId::new(3).expect("non zero"), message.channel_id);
+        // Content with escape sequences can't be borrowed.
+        assert_eq!("hello \"world\"", message.content);
+        assert_eq!(MessageId::new(4).expect("non zero"), message.id);
+        assert!(message.webhook_id.is_none());
+
+        assert!(matches!(
+            event.presence_update().unwrap_err().kind(),
+            RawEventDeserializeErrorType::EventTypeMismatch {
+                expected: EventType::PresenceUpdate
+            }
+        ));
+    }
+
+    #[test]
+    fn test_presence_update_ref() {
+        let event = raw(PRESENCE_UPDATE, 4, "PRESENCE_UPDATE");
+        let presence: PresenceUpdateRef<'_> = event.presence_update().unwrap();
+
+        assert_eq!(GuildId::new(1).expect("non zero"), presence.guild_id);
+        assert_eq!(Status::Online, presence.status);
+        assert_eq!(UserId::new(2).expect("non zero"), presence.user.id);
+        assert!(presence.user.name.is_none());
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let event = LazyEvent::Raw(raw(MESSAGE_CREATE, 3, "MESSAGE_CREATE"));
+        assert_eq!(Some(EventType::MessageCreate), event.kind());
+
+        let event = event.into_event().unwrap();
+        assert_eq!(EventType::MessageCreate, event.kind());
+    }
+}
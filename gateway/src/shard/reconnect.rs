@@ -0,0 +1,382 @@
+//! Policies deciding how shards recover from disconnects.
+
+use std::{fmt::Debug, time::Duration};
+
+/// Cause of a shard needing to reconnect.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum DisconnectCause {
+    /// Gateway closed the connection with a non-fatal close code.
+    Closed {
+        /// Close code sent by the gateway, if any.
+        code: Option<u16>,
+    },
+    /// Connecting to the gateway failed while reconnecting.
+    ConnectFailed,
+    /// Connection to the gateway was lost.
+    ConnectionLost,
+    /// Message from the gateway could not be decompressed.
+    Decompressing,
+    /// Gateway invalidated the session.
+    InvalidSession {
+        /// Whether the gateway indicated that the session may be resumed.
+        resumable: bool,
+    },
+    /// Gateway requested that the shard reconnect.
+    ReconnectRequested,
+    /// Sending a message to the gateway failed.
+    SendingFailed,
+    /// Gateway sent a heartbeat with a sequence ahead of the shard's, meaning
+    /// events were missed.
+    SequenceSkipped,
+}
+
+impl DisconnectCause {
+    /// Whether a session interrupted by this cause may be resumed.
+    pub const fn resumable(self) -> bool {
+        match self {
+            Self::Closed { .. }
+            | Self::ConnectionLost
+            | Self::ReconnectRequested
+            | Self::SequenceSkipped => true,
+            Self::InvalidSession { resumable } => resumable,
+            Self::ConnectFailed | Self::Decompressing | Self::SendingFailed => false,
+        }
+    }
+}
+
+/// Information about a disconnect that a [`ReconnectPolicy`] decides on.
+#[derive(Clone, Debug)]
+pub struct ReconnectContext {
+    attempt: u32,
+    cause: DisconnectCause,
+    has_session: bool,
+    shard_id: [u64; 2],
+}
+
+impl ReconnectContext {
+    /// Create a new context.
+    ///
+    /// This is mainly useful for testing policies.
+    pub const fn new(
+        attempt: u32,
+        cause: DisconnectCause,
+        has_session: bool,
+        shard_id: [u64; 2],
+    ) -> Self {
+        Self {
+            attempt,
+            cause,
+            has_session,
+            shard_id,
+        }
+    }
+
+    /// Number of the attempt, starting at 1 for the first attempt after the
+    /// shard's last successful session.
+    pub const fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Cause of the disconnect.
+    pub const fn cause(&self) -> DisconnectCause {
+        self.cause
+    }
+
+    /// Whether the shard has a session that may be resumed.
+    ///
+    /// If a policy decides to resume without a session then the shard
+    /// re-identifies instead.
+    pub const fn has_session(&self) -> bool {
+        self.has_session
+    }
+
+    /// ID of the shard and the total number of shards.
+    pub const fn shard_id(&self) -> [u64; 2] {
+        self.shard_id
+    }
+}
+
+/// Action to take to recover from a disconnect.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ReconnectAction {
+    /// Stop the shard and emit a [`ShardDead`] event.
+    ///
+    /// [`ShardDead`]: twilight_model::gateway::event::Event::ShardDead
+    Escalate,
+    /// Wait and then identify to create a new session.
+    Reidentify {
+        /// Duration to wait before connecting.
+        delay: Duration,
+    },
+    /// Wait and then resume the current session.
+    Resume {
+        /// Duration to wait before connecting.
+        delay: Duration,
+    },
+}
+
+/// Policy deciding how a shard recovers after it is disconnected.
+///
+/// The policy is consulted for every attempt, including when connecting
+/// during an attempt fails. Attempts are counted from the shard's last
+/// successful session, so a policy may give up on shards that flap.
+///
+/// Fatal close codes, such as for an invalid token, always stop the shard
+/// without consulting the policy.
+///
+/// # Examples
+///
+/// Resume immediately, but stop the shard after 5 failed attempts:
+///
+/// ```
+/// use std::time::Duration;
+/// use twilight_gateway::shard::{
+///     ReconnectAction, ReconnectContext, ReconnectPolicy,
+/// };
+///
+/// #[derive(Debug)]
+/// struct Policy;
+///
+/// impl ReconnectPolicy for Policy {
+///     fn decide(&self, context: &ReconnectContext) -> ReconnectAction {
+///         if context.attempt() > 5 {
+///             ReconnectAction::Escalate
+///         } else {
+///             ReconnectAction::Resume {
+///                 delay: Duration::from_secs(0),
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub trait ReconnectPolicy: Debug + Send + Sync {
+    /// Decide how to recover from a disconnect.
+    fn decide(&self, context: &ReconnectContext) -> ReconnectAction;
+}
+
+/// Curve of delays between reconnect attempts.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Backoff {
+    /// Wait the same duration between each attempt.
+    Constant(Duration),
+    /// Double the duration after each attempt.
+    Exponential {
+        /// Duration to wait before the first attempt.
+        initial: Duration,
+        /// Maximum duration to wait.
+        max: Duration,
+    },
+    /// Increase the duration by a step after each attempt.
+    Linear {
+        /// Duration to wait before the first attempt.
+        initial: Duration,
+        /// Maximum duration to wait.
+        max: Duration,
+        /// Duration added after each attempt.
+        step: Duration,
+    },
+}
+
+impl Backoff {
+    /// Duration to wait before an attempt, where the first attempt is 0.
+    pub fn delay(self, attempt: u32) -> Duration {
+        match self {
+            Self::Constant(delay) => delay,
+            Self::Exponential { initial, max } => initial
+                .checked_mul(2_u32.saturating_pow(attempt))
+                .map_or(max, |delay| delay.min(max)),
+            Self::Linear { initial, max, step } => step
+                .checked_mul(attempt)
+                .and_then(|steps| initial.checked_add(steps))
+                .map_or(max, |delay| delay.min(max)),
+        }
+    }
+}
+
+/// Reconnect policy resuming immediately when possible and re-identifying
+/// after a backoff otherwise.
+///
+/// The default policy resumes sessions when the [cause] allows it and
+/// otherwise re-identifies after an exponential backoff from 1 to 128
+/// seconds, without ever escalating.
+///
+/// # Examples
+///
+/// Always re-identify after a linear backoff and give up after 10 attempts:
+///
+/// ```
+/// use std::time::Duration;
+/// use twilight_gateway::shard::{Backoff, BackoffReconnectPolicy};
+///
+/// let policy = BackoffReconnectPolicy::new(Backoff::Linear {
+///     initial: Duration::from_secs(1),
+///     max: Duration::from_secs(30),
+///     step: Duration::from_secs(5),
+/// })
+/// .max_attempts(Some(10))
+/// .resume(false);
+/// ```
+///
+/// [cause]: DisconnectCause::resumable
+#[derive(Clone, Debug)]
+pub struct BackoffReconnectPolicy {
+    backoff: Backoff,
+    max_attempts: Option<u32>,
+    resume: bool,
+}
+
+impl BackoffReconnectPolicy {
+    /// Create a new policy with a backoff curve for re-identifying.
+    pub const fn new(backoff: Backoff) -> Self {
+        Self {
+            backoff,
+            max_attempts: None,
+            resume: true,
+        }
+    }
+
+    /// Set the maximum number of attempts before escalating.
+    ///
+    /// Defaults to `None`, never escalating.
+    #[must_use = "has no effect if the policy is left unused"]
+    pub const fn max_attempts(mut self, max_attempts: Option<u32>) -> Self {
+        self.max_attempts = max_attempts;
+
+        self
+    }
+
+    /// Set whether to resume sessions when possible.
+    ///
+    /// Defaults to `true`.
+    #[must_use = "has no effect if the policy is left unused"]
+    pub const fn resume(mut self, resume: bool) -> Self {
+        self.resume = resume;
+
+        self
+    }
+}
+
+impl Default for BackoffReconnectPolicy {
+    fn default() -> Self {
+        Self::new(Backoff::Exponential {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(128),
+        })
+    }
+}
+
+impl ReconnectPolicy for BackoffReconnectPolicy {
+    fn decide(&self, context: &ReconnectContext) -> ReconnectAction {
+        if matches!(self.max_attempts, Some(max) if context.attempt() > max) {
+            return ReconnectAction::Escalate;
+        }
+
+        if self.resume && context.has_session() && context.cause().resumable() {
+            return ReconnectAction::Resume {
+                delay: Duration::from_secs(0),
+            };
+        }
+
+        ReconnectAction::Reidentify {
+            delay: self.backoff.delay(context.attempt().saturating_sub(1)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Backoff, BackoffReconnectPolicy, DisconnectCause, ReconnectAction, ReconnectContext,
+        ReconnectPolicy,
+    };
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{fmt::Debug, hash::Hash, time::Duration};
+
+    assert_impl_all!(Backoff: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(
+        BackoffReconnectPolicy: Clone,
+        Debug,
+        Default,
+        ReconnectPolicy,
+        Send,
+        Sync
+    );
+    assert_impl_all!(DisconnectCause: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(ReconnectAction: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(ReconnectContext: Clone, Debug, Send, Sync);
+    assert_obj_safe!(ReconnectPolicy);
+
+    const fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn test_backoff_delay() {
+        let exponential = Backoff::Exponential {
+            initial: secs(1),
+            max: secs(128),
+        };
+        assert_eq!(secs(1), exponential.delay(0));
+        assert_eq!(secs(8), exponential.delay(3));
+        assert_eq!(secs(128), exponential.delay(7));
+        assert_eq!(secs(128), exponential.delay(u32::MAX));
+
+        let linear = Backoff::Linear {
+            initial: secs(1),
+            max: secs(10),
+            step: secs(2),
+        };
+        assert_eq!(secs(1), linear.delay(0));
+        assert_eq!(secs(5), linear.delay(2));
+        assert_eq!(secs(10), linear.delay(u32::MAX));
+
+        assert_eq!(secs(3), Backoff::Constant(secs(3)).delay(50));
+    }
+
+    #[test]
+    fn test_default_policy() {
+        let policy = BackoffReconnectPolicy::default();
+
+        let resumable = ReconnectContext::new(1, DisconnectCause::ConnectionLost, true, [0, 1]);
+        assert_eq!(
+            ReconnectAction::Resume { delay: secs(0) },
+            policy.decide(&resumable)
+        );
+
+        let no_session = ReconnectContext::new(1, DisconnectCause::ConnectionLost, false, [0, 1]);
+        assert_eq!(
+            ReconnectAction::Reidentify { delay: secs(1) },
+            policy.decide(&no_session)
+        );
+
+        let invalidated = ReconnectContext::new(
+            3,
+            DisconnectCause::InvalidSession { resumable: false },
+            true,
+            [0, 1],
+        );
+        assert_eq!(
+            ReconnectAction::Reidentify { delay: secs(4) },
+            policy.decide(&invalidated)
+        );
+    }
+
+    #[test]
+    fn test_max_attempts_and_resume() {
+        let policy = BackoffReconnectPolicy::new(Backoff::Constant(secs(2)))
+            .max_attempts(Some(2))
+            .resume(false);
+
+        let context = ReconnectContext::new(2, DisconnectCause::ReconnectRequested, true, [0, 1]);
+        assert_eq!(
+            ReconnectAction::Reidentify { delay: secs(2) },
+            policy.decide(&context)
+        );
+
+        let context = ReconnectContext::new(3, DisconnectCause::ConnectFailed, true, [0, 1]);
+        assert_eq!(ReconnectAction::Escalate, policy.decide(&context));
+    }
+}
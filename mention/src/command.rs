@@ -0,0 +1,93 @@
+//! Mentions of application commands, which clients display as a clickable
+//! link that fills in the command.
+//!
+//! # Examples
+//!
+//! Mention the `add` subcommand of the `/role` command:
+//!
+//! ```
+//! use twilight_mention::{command::CommandMention, Mention};
+//! use twilight_model::id::CommandId;
+//!
+//! let id = CommandId::new(123).expect("non zero");
+//! let mention = CommandMention::subcommand(id, "role", "add");
+//!
+//! assert_eq!("</role add:123>", mention.mention().to_string());
+//! ```
+
+use twilight_model::id::CommandId;
+
+/// Mention of an application command, subcommand or subcommand of a
+/// subcommand group.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CommandMention {
+    /// ID of the command.
+    id: CommandId,
+    /// Full name of the command, including the names of its subcommand group
+    /// and subcommand separated by spaces.
+    name: String,
+}
+
+impl CommandMention {
+    /// Create a mention of a command with its ID and full name.
+    ///
+    /// The full name includes the names of the mentioned subcommand group and
+    /// subcommand separated by spaces, such as `config log channel`.
+    #[must_use = "creating a command mention does nothing on its own"]
+    pub fn new(id: CommandId, name: impl Into<String>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+        }
+    }
+
+    /// Create a mention of a subcommand of a command.
+    #[must_use = "creating a command mention does nothing on its own"]
+    pub fn subcommand(id: CommandId, command: &str, subcommand: &str) -> Self {
+        Self::new(id, format!("{} {}", command, subcommand))
+    }
+
+    /// Create a mention of a subcommand in a subcommand group of a command.
+    #[must_use = "creating a command mention does nothing on its own"]
+    pub fn subcommand_group(id: CommandId, command: &str, group: &str, subcommand: &str) -> Self {
+        Self::new(id, format!("{} {} {}", command, group, subcommand))
+    }
+
+    /// ID of the command.
+    #[must_use = "retrieving the ID has no effect if left unused"]
+    pub const fn id(&self) -> CommandId {
+        self.id
+    }
+
+    /// Full name of the mentioned command.
+    #[must_use = "retrieving the name has no effect if left unused"]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandMention;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+    use twilight_model::id::CommandId;
+
+    assert_impl_all!(CommandMention: Clone, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_names() {
+        let id = CommandId::new(123).expect("non zero");
+
+        assert_eq!("ping", CommandMention::new(id, "ping").name());
+        assert_eq!(
+            "role add",
+            CommandMention::subcommand(id, "role", "add").name()
+        );
+        assert_eq!(
+            "config log channel",
+            CommandMention::subcommand_group(id, "config", "log", "channel").name()
+        );
+        assert_eq!(id, CommandMention::new(id, "ping").id());
+    }
+}
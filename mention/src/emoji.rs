@@ -0,0 +1,96 @@
+//! Custom emojis with their name and whether they're animated.
+//!
+//! Unlike an [`EmojiId`], a [`CustomEmoji`] contains all of the data of an
+//! emoji mention, such that it can be formatted into a mention that clients
+//! display with the emoji's name and animation.
+//!
+//! # Examples
+//!
+//! Format an animated emoji via its implementation of [`Mention`]:
+//!
+//! ```
+//! use twilight_mention::{emoji::CustomEmoji, Mention};
+//! use twilight_model::id::EmojiId;
+//!
+//! let emoji = CustomEmoji::new(EmojiId::new(123).expect("non zero"), "dance", true);
+//! assert_eq!("<a:dance:123>", emoji.mention().to_string());
+//! ```
+//!
+//! [`Mention`]: super::fmt::Mention
+
+use twilight_model::id::EmojiId;
+
+/// Custom emoji with its ID, name, and whether it's animated.
+///
+/// # Examples
+///
+/// Parse an animated emoji mention:
+///
+/// ```
+/// use twilight_mention::{emoji::CustomEmoji, ParseMention};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let emoji = CustomEmoji::parse("<a:dance:123>")?;
+/// assert!(emoji.animated());
+/// assert_eq!(123, emoji.id().get());
+/// assert_eq!("dance", emoji.name());
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CustomEmoji {
+    /// Whether the emoji is animated.
+    animated: bool,
+    /// ID of the emoji.
+    id: EmojiId,
+    /// Name of the emoji.
+    name: String,
+}
+
+impl CustomEmoji {
+    /// Create a new custom emoji with its ID, name, and whether it's animated.
+    #[must_use = "creating a custom emoji does nothing on its own"]
+    pub fn new(id: EmojiId, name: impl Into<String>, animated: bool) -> Self {
+        Self {
+            animated,
+            id,
+            name: name.into(),
+        }
+    }
+
+    /// Whether the emoji is animated.
+    #[must_use = "retrieving whether the emoji is animated has no effect if left unused"]
+    pub const fn animated(&self) -> bool {
+        self.animated
+    }
+
+    /// ID of the emoji.
+    #[must_use = "retrieving the ID has no effect if left unused"]
+    pub const fn id(&self) -> EmojiId {
+        self.id
+    }
+
+    /// Name of the emoji.
+    #[must_use = "retrieving the name has no effect if left unused"]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CustomEmoji;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+    use twilight_model::id::EmojiId;
+
+    assert_impl_all!(CustomEmoji: Clone, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_getters() {
+        let emoji = CustomEmoji::new(EmojiId::new(123).expect("non zero"), "name", false);
+
+        assert!(!emoji.animated());
+        assert_eq!(EmojiId::new(123).expect("non zero"), emoji.id());
+        assert_eq!("name", emoji.name());
+    }
+}
@@ -1,6 +1,6 @@
 //! Formatters for creating mentions.
 
-use super::timestamp::Timestamp;
+use super::{command::CommandMention, emoji::CustomEmoji, timestamp::Timestamp};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use twilight_model::{
     channel::{
@@ -36,6 +36,31 @@ impl Display for MentionFormat<ChannelId> {
     }
 }
 
+/// Mention a command. This will format as `</NAME:ID>`.
+impl Display for MentionFormat<CommandMention> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("</")?;
+        f.write_str(self.0.name())?;
+        f.write_str(":")?;
+        Display::fmt(&self.0.id(), f)?;
+
+        f.write_str(">")
+    }
+}
+
+/// Mention a custom emoji. This will format as `<:NAME:ID>` or `<a:NAME:ID>`
+/// if the emoji is animated.
+impl Display for MentionFormat<CustomEmoji> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(if self.0.animated() { "<a:" } else { "<:" })?;
+        f.write_str(self.0.name())?;
+        f.write_str(":")?;
+        Display::fmt(&self.0.id(), f)?;
+
+        f.write_str(">")
+    }
+}
+
 /// Mention an emoji. This will format as `<:emoji:ID>`.
 impl Display for MentionFormat<EmojiId> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -130,6 +155,13 @@ impl Mention<ChannelId> for Channel {
     }
 }
 
+/// Mention a command. This will format as `</NAME:ID>`.
+impl Mention<Self> for CommandMention {
+    fn mention(&self) -> MentionFormat<Self> {
+        MentionFormat(self.clone())
+    }
+}
+
 /// Mention the current user. This will format as `<@ID>`.
 impl Mention<UserId> for CurrentUser {
     fn mention(&self) -> MentionFormat<UserId> {
@@ -137,6 +169,14 @@ impl Mention<UserId> for CurrentUser {
     }
 }
 
+/// Mention a custom emoji. This will format as `<:NAME:ID>` or `<a:NAME:ID>`
+/// if the emoji is animated.
+impl Mention<Self> for CustomEmoji {
+    fn mention(&self) -> MentionFormat<Self> {
+        MentionFormat(self.clone())
+    }
+}
+
 /// Mention an emoji. This will format as `<:emoji:ID>`.
 impl Mention<EmojiId> for EmojiId {
     fn mention(&self) -> MentionFormat<EmojiId> {
@@ -231,7 +271,11 @@ impl Mention<ChannelId> for VoiceChannel {
 
 #[cfg(test)]
 mod tests {
-    use crate::timestamp::{Timestamp, TimestampStyle};
+    use crate::{
+        command::CommandMention,
+        emoji::CustomEmoji,
+        timestamp::{Timestamp, TimestampStyle},
+    };
 
     use super::{Mention, MentionFormat};
     use static_assertions::assert_impl_all;
@@ -242,12 +286,14 @@ mod tests {
             VoiceChannel,
         },
         guild::{Emoji, Member, Role},
-        id::{ChannelId, EmojiId, RoleId, UserId},
+        id::{ChannelId, CommandId, EmojiId, RoleId, UserId},
         user::{CurrentUser, User},
     };
 
     assert_impl_all!(MentionFormat<()>: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<ChannelId>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(MentionFormat<CommandMention>: Clone, Debug, Display, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(MentionFormat<CustomEmoji>: Clone, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<EmojiId>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<RoleId>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<UserId>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
@@ -257,8 +303,12 @@ mod tests {
     assert_impl_all!(&'static CategoryChannel: Mention<ChannelId>);
     assert_impl_all!(Channel: Mention<ChannelId>);
     assert_impl_all!(&'static Channel: Mention<ChannelId>);
+    assert_impl_all!(CommandMention: Mention<CommandMention>);
+    assert_impl_all!(&'static CommandMention: Mention<CommandMention>);
     assert_impl_all!(CurrentUser: Mention<UserId>);
     assert_impl_all!(&'static CurrentUser: Mention<UserId>);
+    assert_impl_all!(CustomEmoji: Mention<CustomEmoji>);
+    assert_impl_all!(&'static CustomEmoji: Mention<CustomEmoji>);
     assert_impl_all!(EmojiId: Mention<EmojiId>);
     assert_impl_all!(&'static EmojiId: Mention<EmojiId>);
     assert_impl_all!(Emoji: Mention<EmojiId>);
@@ -292,6 +342,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mention_format_command() {
+        let id = CommandId::new(123).expect("non zero");
+
+        assert_eq!(
+            "</ping:123>",
+            CommandMention::new(id, "ping").mention().to_string()
+        );
+        assert_eq!(
+            "</role add:123>",
+            CommandMention::subcommand(id, "role", "add")
+                .mention()
+                .to_string()
+        );
+        assert_eq!(
+            "</config log channel:123>",
+            CommandMention::subcommand_group(id, "config", "log", "channel")
+                .mention()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_mention_format_custom_emoji() {
+        let id = EmojiId::new(123).expect("non zero");
+
+        assert_eq!(
+            "<:name:123>",
+            CustomEmoji::new(id, "name", false).mention().to_string()
+        );
+        assert_eq!(
+            "<a:name:123>",
+            CustomEmoji::new(id, "name", true).mention().to_string()
+        );
+    }
+
     #[test]
     fn test_mention_format_emoji_id() {
         assert_eq!(
@@ -6,7 +6,11 @@
 //! ecosystem to mention its model types and parse those mentions.
 //!
 //! With this library, you can create mentions for various types, such as users,
-//! emojis, roles, members, or channels.
+//! emojis, roles, members, channels, or commands.
+//!
+//! Mentions in content, such as user input, can also be sanitized so
+//! that they don't notify anyone, and markdown can be escaped, wrapped in
+//! code blocks, and split into chunks that fit in a message.
 //!
 //! ## Examples
 //!
@@ -46,8 +50,12 @@
 )]
 #![allow(clippy::module_name_repetitions)]
 
+pub mod command;
+pub mod emoji;
 pub mod fmt;
+pub mod markdown;
 pub mod parse;
+pub mod sanitize;
 pub mod timestamp;
 
 #[doc(no_inline)]
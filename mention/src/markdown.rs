@@ -0,0 +1,521 @@
+//! Formatting for Discord's markdown dialect.
+//!
+//! User content can be [escaped] so that it's displayed as-is, content can be
+//! wrapped in a [code block] without embedded backticks ending it early, and
+//! long content can be split into [chunks] that fit in a message.
+//!
+//! # Examples
+//!
+//! Send the output of a command in code blocks, split over as many messages as
+//! needed:
+//!
+//! ```
+//! use twilight_mention::markdown::{self, CodeBlock};
+//!
+//! let output = "fn main() {\n    println!(\"hi\");\n}\n".repeat(200);
+//! let content = CodeBlock::new(&output).language("rust").to_string();
+//!
+//! for chunk in markdown::chunks(&content, markdown::CONTENT_LENGTH_MAX) {
+//!     assert!(chunk.starts_with("```rust\n"));
+//!     assert!(chunk.chars().count() <= markdown::CONTENT_LENGTH_MAX);
+//! }
+//! ```
+//!
+//! [chunks]: chunks
+//! [code block]: CodeBlock
+//! [escaped]: escape
+
+use crate::{
+    parse::{MentionType, ParseMention},
+    sanitize::ZERO_WIDTH_SPACE,
+};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    iter::FusedIterator,
+};
+
+/// Minimum number of characters in a chunk of content.
+///
+/// This fits a reopened code block without a language containing one
+/// character, such as `` ```\nx\n``` ``.
+pub const CHUNK_LENGTH_MIN: usize = 9;
+
+/// Maximum number of characters in the content of a message.
+pub const CONTENT_LENGTH_MAX: usize = 2000;
+
+/// Characters that are escaped by [`escape`].
+const ESCAPED: &[char] = &['\\', '*', '_', '~', '`', '|', '>', '[', ']'];
+
+/// Fence opening and closing a code block.
+const FENCE: &str = "```";
+
+/// Escape markdown in content so that it's displayed as-is.
+///
+/// Bold, italics, underline, strikethrough, code, spoiler, quote and masked
+/// link syntax is escaped with backslashes. Mentions, such as custom emojis
+/// with underscores in their names, are left intact.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_mention::markdown;
+///
+/// assert_eq!(
+///     r"\*\*not bold\*\* <:big_smile:123>",
+///     markdown::escape("**not bold** <:big_smile:123>"),
+/// );
+/// ```
+#[must_use = "escaping content has no effect if left unused"]
+pub fn escape(content: &str) -> String {
+    let mut escaped = String::with_capacity(content.len());
+    let mut position = 0;
+
+    for (_, start, end) in MentionType::iter(content) {
+        escape_into(&mut escaped, &content[position..start]);
+        escaped.push_str(&content[start..=end]);
+        position = end + 1;
+    }
+
+    escape_into(&mut escaped, &content[position..]);
+
+    escaped
+}
+
+/// Push content with markdown characters escaped.
+fn escape_into(buf: &mut String, content: &str) {
+    for character in content.chars() {
+        if ESCAPED.contains(&character) {
+            buf.push('\\');
+        }
+
+        buf.push(character);
+    }
+}
+
+/// Formatter to wrap content in a code block, optionally with a language to
+/// highlight the content as.
+///
+/// Backticks in the content are separated by zero-width spaces so that they
+/// don't end the code block early.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_mention::markdown::CodeBlock;
+///
+/// let block = CodeBlock::new("let x = 1;").language("rust");
+/// assert_eq!("```rust\nlet x = 1;\n```", block.to_string());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CodeBlock<'a> {
+    content: &'a str,
+    language: Option<&'a str>,
+}
+
+impl<'a> CodeBlock<'a> {
+    /// Create a new code block formatter for content.
+    #[must_use = "creating a code block does nothing on its own"]
+    pub const fn new(content: &'a str) -> Self {
+        Self {
+            content,
+            language: None,
+        }
+    }
+
+    /// Set the language to highlight the content as, such as `rust`.
+    ///
+    /// The language must not contain whitespace.
+    #[must_use = "creating a code block does nothing on its own"]
+    pub const fn language(self, language: &'a str) -> Self {
+        Self {
+            content: self.content,
+            language: Some(language),
+        }
+    }
+}
+
+impl Display for CodeBlock<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(FENCE)?;
+
+        if let Some(language) = self.language {
+            f.write_str(language)?;
+        }
+
+        f.write_str("\n")?;
+
+        let mut remaining = self.content;
+
+        while let Some(index) = remaining.find("``") {
+            f.write_str(&remaining[..=index])?;
+            f.write_char(ZERO_WIDTH_SPACE)?;
+            remaining = &remaining[index + 1..];
+        }
+
+        f.write_str(remaining)?;
+        f.write_str("\n")?;
+
+        f.write_str(FENCE)
+    }
+}
+
+/// Split content into chunks of at most `limit` characters.
+///
+/// Content is split after the last line break that fits, falling back to the
+/// last whitespace and then to any character. Content isn't split inside of
+/// mentions, and code blocks are only split if they don't fit in a chunk on
+/// their own, in which case the code block is closed at the end of a chunk and
+/// reopened with its language at the start of the next. Code blocks are
+/// never split inside of their fences or language.
+///
+/// Use [`CONTENT_LENGTH_MAX`] as the limit to split content into messages.
+/// Languages too long to reopen a code block with within the limit are
+/// treated as part of the code.
+///
+/// # Panics
+///
+/// Panics if the limit is less than [`CHUNK_LENGTH_MIN`].
+///
+/// # Examples
+///
+/// ```
+/// use twilight_mention::markdown;
+///
+/// let mut chunks = markdown::chunks("first line\nsecond line", 16);
+///
+/// assert_eq!(Some("first line\n"), chunks.next().as_deref());
+/// assert_eq!(Some("second line"), chunks.next().as_deref());
+/// assert!(chunks.next().is_none());
+/// ```
+#[must_use = "iterators do nothing unless consumed"]
+pub fn chunks(content: &str, limit: usize) -> Chunks<'_> {
+    assert!(
+        limit >= CHUNK_LENGTH_MIN,
+        "chunk limit must be at least {}",
+        CHUNK_LENGTH_MIN
+    );
+
+    let mentions = MentionType::iter(content)
+        .map(|(_, start, end)| (start, end + 1))
+        .collect();
+
+    Chunks {
+        blocks: code_blocks(content, limit),
+        content,
+        limit,
+        mentions,
+        position: 0,
+    }
+}
+
+/// Find the code blocks in content, with languages that can be reopened
+/// within a limit.
+fn code_blocks(content: &str, limit: usize) -> Vec<CodeBlockRange<'_>> {
+    let mut blocks = Vec::new();
+    let mut position = 0;
+
+    while let Some(start) = content[position..]
+        .find(FENCE)
+        .map(|index| index + position)
+    {
+        let header_start = start + FENCE.len();
+
+        let close = match content[header_start..].find(FENCE) {
+            Some(index) => header_start + index,
+            None => break,
+        };
+
+        let body = &content[header_start..close];
+
+        let (language, header_end) = match body.find('\n') {
+            Some(index) if is_language(&body[..index]) && index + CHUNK_LENGTH_MIN <= limit => {
+                (&body[..index], header_start + index + 1)
+            }
+            _ => ("", header_start),
+        };
+
+        position = close + FENCE.len();
+
+        blocks.push(CodeBlockRange {
+            close,
+            header_end,
+            language,
+            start,
+        });
+    }
+
+    blocks
+}
+
+/// Whether the first line of a code block is a language.
+fn is_language(line: &str) -> bool {
+    line.chars()
+        .all(|c| c.is_ascii_alphanumeric() || "#+-._".contains(c))
+}
+
+/// Byte index of the end of the first `count` characters of a string.
+fn char_end(value: &str, count: usize) -> usize {
+    value
+        .char_indices()
+        .nth(count)
+        .map_or(value.len(), |(index, _)| index)
+}
+
+/// Range of a code block in content.
+#[derive(Clone, Debug)]
+struct CodeBlockRange<'a> {
+    /// Index of the closing fence.
+    close: usize,
+    /// Index after the opening fence and language.
+    header_end: usize,
+    /// Language of the code block, which may be empty.
+    language: &'a str,
+    /// Index of the opening fence.
+    start: usize,
+}
+
+impl CodeBlockRange<'_> {
+    /// Whether an index is inside of the code block, including its fences.
+    const fn contains(&self, index: usize) -> bool {
+        self.start < index && index < self.close + FENCE.len()
+    }
+
+    /// Whether content can be split at an index by closing the code block and
+    /// reopening it in the next chunk.
+    const fn splits_at(&self, index: usize) -> bool {
+        self.header_end < index && index < self.close
+    }
+}
+
+/// Iterator over chunks of content, created via [`chunks`].
+#[derive(Clone, Debug)]
+pub struct Chunks<'a> {
+    blocks: Vec<CodeBlockRange<'a>>,
+    content: &'a str,
+    limit: usize,
+    /// Ranges of mentions, with exclusive ends.
+    mentions: Vec<(usize, usize)>,
+    position: usize,
+}
+
+impl<'a> Chunks<'a> {
+    /// Return an immutable reference to the underlying content of the
+    /// iterator.
+    #[must_use = "retrieving the content has no effect if left unused"]
+    pub const fn as_str(&self) -> &'a str {
+        self.content
+    }
+
+    /// Find the code block that can be split at an index.
+    fn split_block(&self, index: usize) -> Option<&CodeBlockRange<'a>> {
+        self.blocks.iter().find(|block| block.splits_at(index))
+    }
+
+    /// Find the index to end the next chunk at, given the maximum number of
+    /// characters in the chunk excluding a reopened code block.
+    fn split(&self, budget: usize) -> usize {
+        // Candidate indexes, from most to least preferred: after line breaks
+        // outside of code blocks, after whitespace outside of code blocks,
+        // after line breaks inside of code blocks, after any other character
+        // outside of code blocks, after any other character inside of code
+        // blocks, and after any character at all, including within mentions.
+        //
+        // Code blocks are never split inside of their fences or language, or
+        // where there's no room left to close them.
+        let mut candidates = [None; 6];
+        let remaining = &self.content[self.position..];
+        let end = char_end(remaining, budget);
+        // Leave room to close a split code block.
+        let block_end = char_end(remaining, budget.saturating_sub(FENCE.len() + 1));
+
+        for (index, character) in remaining[..end].char_indices() {
+            let relative = index + character.len_utf8();
+            let index = self.position + relative;
+
+            let tier = match self.blocks.iter().find(|block| block.contains(index)) {
+                None if character == '\n' => 0,
+                None if character.is_whitespace() => 1,
+                None => 3,
+                Some(block) if block.splits_at(index) && relative <= block_end => {
+                    if character == '\n' {
+                        2
+                    } else {
+                        4
+                    }
+                }
+                Some(_) => continue,
+            };
+
+            candidates[5] = Some(index);
+
+            if self
+                .mentions
+                .iter()
+                .any(|(start, end)| *start < index && index < *end)
+            {
+                continue;
+            }
+
+            candidates[tier] = Some(index);
+        }
+
+        candidates
+            .iter()
+            .find_map(|candidate| *candidate)
+            .unwrap_or_else(|| {
+                // Always make progress, although there's always a candidate
+                // with a limit of at least `CHUNK_LENGTH_MIN`.
+                self.position + remaining.chars().next().map_or(0, char::len_utf8)
+            })
+    }
+}
+
+impl Iterator for Chunks<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = &self.content[self.position..];
+
+        if remaining.is_empty() {
+            return None;
+        }
+
+        let mut chunk = String::new();
+
+        if let Some(block) = self.split_block(self.position) {
+            chunk.push_str(FENCE);
+            chunk.push_str(block.language);
+            chunk.push('\n');
+        }
+
+        let budget = self.limit.saturating_sub(chunk.chars().count());
+
+        if remaining.chars().count() <= budget {
+            chunk.push_str(remaining);
+            self.position = self.content.len();
+
+            return Some(chunk);
+        }
+
+        let end = self.split(budget);
+        chunk.push_str(&self.content[self.position..end]);
+
+        if self.split_block(end).is_some() {
+            if !chunk.ends_with('\n') {
+                chunk.push('\n');
+            }
+
+            chunk.push_str(FENCE);
+        }
+
+        self.position = end;
+
+        Some(chunk)
+    }
+}
+
+impl FusedIterator for Chunks<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{chunks, escape, Chunks, CodeBlock, CHUNK_LENGTH_MIN};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(Chunks<'_>: Clone, Debug, Iterator, Send, Sync);
+    assert_impl_all!(CodeBlock<'_>: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    fn assert_chunks(content: &str, limit: usize, expected: &[&str]) {
+        let chunks = chunks(content, limit).collect::<Vec<_>>();
+
+        assert_eq!(expected, chunks.as_slice());
+        assert!(chunks.iter().all(|chunk| chunk.chars().count() <= limit));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            r"\*a\* \_b\_ \~\~c\~\~ \`d\` \|\|e\|\| \> f \[g\](h) \\",
+            escape(r"*a* _b_ ~~c~~ `d` ||e|| > f [g](h) \")
+        );
+        assert_eq!(
+            r"<:big_smile:1> <@2> \_ <t:3:R>",
+            escape(r"<:big_smile:1> <@2> _ <t:3:R>")
+        );
+        assert_eq!("nothing to escape", escape("nothing to escape"));
+    }
+
+    #[test]
+    fn test_code_block() {
+        assert_eq!("```\ncode\n```", CodeBlock::new("code").to_string());
+        assert_eq!(
+            "```rust\nlet x = 1;\n```",
+            CodeBlock::new("let x = 1;").language("rust").to_string()
+        );
+        assert_eq!(
+            "```md\na `b` `\u{200B}`\u{200B}`c`\u{200B}`\u{200B}`\n```",
+            CodeBlock::new("a `b` ```c```").language("md").to_string()
+        );
+    }
+
+    #[test]
+    fn test_chunks_fits() {
+        assert_chunks("short", 2000, &["short"]);
+        assert_chunks("", 2000, &[]);
+    }
+
+    #[test]
+    fn test_chunks_lines() {
+        assert_chunks("aaaa\nbbbb\ncccc", 10, &["aaaa\nbbbb\n", "cccc"]);
+        assert_chunks("aa bb cc dd ee ff", 9, &["aa bb cc ", "dd ee ff"]);
+        assert_chunks("abcdefghijklmnopqrst", 9, &["abcdefghi", "jklmnopqr", "st"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk limit must be at least 9")]
+    fn test_chunks_limit_min() {
+        let _chunks = chunks("```\nabcdefgh\n```", CHUNK_LENGTH_MIN - 1);
+    }
+
+    #[test]
+    fn test_chunks_mentions() {
+        assert_chunks("abcdef<@1234>gh", 9, &["abcdef", "<@1234>gh"]);
+    }
+
+    #[test]
+    fn test_chunks_unicode() {
+        assert_chunks("ééééé\nééééé", 9, &["ééééé\n", "ééééé"]);
+    }
+
+    #[test]
+    fn test_chunks_code_blocks() {
+        // The code block is moved to its own chunk rather than split.
+        assert_chunks("text\n```\ncode\n```", 12, &["text\n", "```\ncode\n```"]);
+
+        // The code block is too long and is split between lines.
+        assert_chunks(
+            "```rs\nline 1\nline 2\nline 3\n```",
+            20,
+            &[
+                "```rs\nline 1\n```",
+                "```rs\nline 2\n```",
+                "```rs\nline 3\n```",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_chunks_code_blocks_limit_min() {
+        let expected = "abcdefgh"
+            .chars()
+            .map(|c| format!("```\n{}\n```", c))
+            .collect::<Vec<_>>();
+        let expected = expected.iter().map(String::as_str).collect::<Vec<_>>();
+
+        assert_chunks("```\nabcdefgh\n```", CHUNK_LENGTH_MIN, &expected);
+
+        // The language doesn't fit in a reopened code block, and the closing
+        // fence isn't split.
+        assert_chunks("```rust\nx\n```", 12, &["```rust\n```", "```\nx\n```"]);
+    }
+}
@@ -1,7 +1,10 @@
-use crate::timestamp::{Timestamp, TimestampStyle};
+use crate::{
+    emoji::CustomEmoji,
+    timestamp::{Timestamp, TimestampStyle},
+};
 
 use super::{MentionIter, MentionType, ParseMentionError, ParseMentionErrorType};
-use std::{convert::TryFrom, num::NonZeroU64, str::Chars};
+use std::{convert::TryFrom, num::NonZeroU64};
 use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 
 /// Parse mentions out of buffers.
@@ -74,40 +77,67 @@ impl ParseMention for ChannelId {
     where
         Self: Sized,
     {
-        parse_mention(buf, Self::SIGILS).map(|(id, _, _)| ChannelId(id))
+        parse_mention(buf, Self::SIGILS).map(|parts| ChannelId(parts.id))
+    }
+}
+
+impl ParseMention for CustomEmoji {
+    /// Sigils for custom emoji mentions.
+    ///
+    /// Static emojis have the sigil `:` and animated emojis the sigil `a:`.
+    const SIGILS: &'static [&'static str] = &[":", "a:"];
+
+    fn parse(buf: &str) -> Result<Self, ParseMentionError<'_>>
+    where
+        Self: Sized,
+    {
+        parse_mention(buf, Self::SIGILS).map(|parts| parts.emoji())
     }
 }
 
 impl ParseMention for EmojiId {
-    const SIGILS: &'static [&'static str] = &[":"];
+    /// Sigils for emoji ID mentions.
+    ///
+    /// Static emojis have the sigil `:` and animated emojis the sigil `a:`.
+    const SIGILS: &'static [&'static str] = &[":", "a:"];
 
     fn parse(buf: &str) -> Result<Self, ParseMentionError<'_>>
     where
         Self: Sized,
     {
-        parse_mention(buf, Self::SIGILS).map(|(id, _, _)| EmojiId(id))
+        parse_mention(buf, Self::SIGILS).map(|parts| EmojiId(parts.id))
     }
 }
 
 impl ParseMention for MentionType {
     /// Sigils for any type of mention.
     ///
-    /// Contains all of the sigils of every other type of mention.
-    const SIGILS: &'static [&'static str] = &["#", ":", "@&", "@!", "@", "t:"];
+    /// Contains all of the sigils of every other type of mention. `@everyone`
+    /// and `@here` mentions have no sigil since they aren't wrapped in arrows.
+    const SIGILS: &'static [&'static str] = &["#", ":", "a:", "@&", "@!", "@", "t:"];
 
     /// Parse a mention from a string slice.
     ///
-    /// # Examples
+    /// `@everyone` and `@here` are parsed into [`MentionType::Everyone`] and
+    /// [`MentionType::Here`].
+    ///
+    /// # Errors
     ///
     /// Returns [`ParseMentionErrorType::TimestampStyleInvalid`] if a timestamp
     /// style value is invalid.
-    ///
-    /// [`ParseMentionError::TimestampStyleInvalid`]: super::error::ParseMentionErrorType::TimestampStyleInvalid
     fn parse(buf: &str) -> Result<Self, ParseMentionError<'_>>
     where
         Self: Sized,
     {
-        let (id, maybe_modifier, found) = parse_mention(buf, Self::SIGILS)?;
+        match buf {
+            EVERYONE => return Ok(MentionType::Everyone),
+            HERE => return Ok(MentionType::Here),
+            _ => {}
+        }
+
+        let parts = parse_mention(buf, Self::SIGILS)?;
+        let id = parts.id;
+        let found = parts.sigil;
 
         for sigil in ChannelId::SIGILS {
             if *sigil == found {
@@ -129,7 +159,7 @@ impl ParseMention for MentionType {
 
         for sigil in Timestamp::SIGILS {
             if *sigil == found {
-                let maybe_style = parse_maybe_style(maybe_modifier)?;
+                let maybe_style = parse_maybe_style(parts.modifier)?;
 
                 return Ok(MentionType::Timestamp(Timestamp::new(
                     id.get(),
@@ -155,7 +185,7 @@ impl ParseMention for RoleId {
     where
         Self: Sized,
     {
-        parse_mention(buf, Self::SIGILS).map(|(id, _, _)| RoleId(id))
+        parse_mention(buf, Self::SIGILS).map(|parts| RoleId(parts.id))
     }
 }
 
@@ -174,11 +204,11 @@ impl ParseMention for Timestamp {
     where
         Self: Sized,
     {
-        let (unix, maybe_modifier, _) = parse_mention(buf, Self::SIGILS)?;
+        let parts = parse_mention(buf, Self::SIGILS)?;
 
         Ok(Timestamp::new(
-            unix.get(),
-            parse_maybe_style(maybe_modifier)?,
+            parts.id.get(),
+            parse_maybe_style(parts.modifier)?,
         ))
     }
 }
@@ -193,7 +223,7 @@ impl ParseMention for UserId {
     where
         Self: Sized,
     {
-        parse_mention(buf, Self::SIGILS).map(|(id, _, _)| UserId(id))
+        parse_mention(buf, Self::SIGILS).map(|parts| UserId(parts.id))
     }
 }
 
@@ -216,6 +246,36 @@ fn parse_maybe_style(value: Option<&str>) -> Result<Option<TimestampStyle>, Pars
     })
 }
 
+/// Mention of everyone in a channel.
+const EVERYONE: &str = "@everyone";
+
+/// Mention of everyone online in a channel.
+const HERE: &str = "@here";
+
+/// Parts of a parsed mention.
+#[derive(Debug)]
+struct MentionParts<'a> {
+    /// ID of the mention, or the Unix timestamp of a timestamp.
+    id: NonZeroU64,
+    /// Additional information after the ID, such as a timestamp style.
+    modifier: Option<&'a str>,
+    /// Name before the ID, present for emojis.
+    name: Option<&'a str>,
+    /// Sigil that was found.
+    sigil: &'a str,
+}
+
+impl MentionParts<'_> {
+    /// Custom emoji of parts of an emoji mention.
+    fn emoji(&self) -> CustomEmoji {
+        CustomEmoji::new(
+            EmojiId(self.id),
+            self.name.unwrap_or_default(),
+            self.sigil == "a:",
+        )
+    }
+}
+
 /// # Errors
 ///
 /// Returns [`ParseMentionErrorType::LeadingArrow`] if the leading arrow is not
@@ -229,7 +289,7 @@ fn parse_maybe_style(value: Option<&str>) -> Result<Option<TimestampStyle>, Pars
 fn parse_mention<'a>(
     buf: &'a str,
     sigils: &'a [&'a str],
-) -> Result<(NonZeroU64, Option<&'a str>, &'a str), ParseMentionError<'a>> {
+) -> Result<MentionParts<'a>, ParseMentionError<'a>> {
     let mut chars = buf.chars();
 
     let c = chars.next();
@@ -265,15 +325,21 @@ fn parse_mention<'a>(
         });
     };
 
-    if sigil == ":" && !separator_sigil_present(&mut chars) {
-        return Err(ParseMentionError {
+    let name = if sigil == ":" || sigil == "a:" {
+        let remaining = chars.as_str();
+        let separator_position = remaining.find(':').ok_or(ParseMentionError {
             kind: ParseMentionErrorType::PartMissing {
                 found: 1,
                 expected: 2,
             },
             source: None,
-        });
-    }
+        })?;
+        chars = remaining[separator_position + 1..].chars();
+
+        Some(&remaining[..separator_position])
+    } else {
+        None
+    };
 
     let end_position = chars
         .as_str()
@@ -304,19 +370,12 @@ fn parse_mention<'a>(
         chars.as_str().get(split_position..style_end_position)
     });
 
-    Ok((num, style, sigil))
-}
-
-// Don't use `Iterator::skip_while` so we can mutate `chars` in-place;
-// `skip_while` is consuming.
-fn separator_sigil_present(chars: &mut Chars<'_>) -> bool {
-    for c in chars {
-        if c == ':' {
-            return true;
-        }
-    }
-
-    false
+    Ok(MentionParts {
+        id: num,
+        modifier: style,
+        name,
+        sigil,
+    })
 }
 
 /// Rust doesn't allow leaking private implementations, but if we make the trait
@@ -326,16 +385,22 @@ fn separator_sigil_present(chars: &mut Chars<'_>) -> bool {
 /// Yes, this is the correct way of sealing a trait:
 ///
 /// <https://rust-lang.github.io/api-guidelines/future-proofing.html>
-mod private {
-    use super::super::MentionType;
-    use crate::timestamp::Timestamp;
+pub(super) mod private {
+    use super::{super::MentionType, EVERYONE, HERE};
+    use crate::{emoji::CustomEmoji, timestamp::Timestamp};
     use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 
-    pub trait Sealed {}
+    pub trait Sealed {
+        /// Mentions that aren't wrapped in arrows, such as `@everyone`.
+        const KEYWORDS: &'static [&'static str] = &[];
+    }
 
     impl Sealed for ChannelId {}
+    impl Sealed for CustomEmoji {}
     impl Sealed for EmojiId {}
-    impl Sealed for MentionType {}
+    impl Sealed for MentionType {
+        const KEYWORDS: &'static [&'static str] = &[EVERYONE, HERE];
+    }
     impl Sealed for RoleId {}
     impl Sealed for Timestamp {}
     impl Sealed for UserId {}
@@ -349,6 +414,7 @@ mod tests {
         ParseMention,
     };
     use crate::{
+        emoji::CustomEmoji,
         parse::ParseMentionError,
         timestamp::{Timestamp, TimestampStyle},
     };
@@ -356,6 +422,7 @@ mod tests {
     use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 
     assert_impl_all!(ChannelId: ParseMention, Sealed);
+    assert_impl_all!(CustomEmoji: ParseMention, Sealed);
     assert_impl_all!(EmojiId: ParseMention, Sealed);
     assert_impl_all!(MentionType: ParseMention, Sealed);
     assert_impl_all!(RoleId: ParseMention, Sealed);
@@ -364,8 +431,12 @@ mod tests {
     #[test]
     fn test_sigils() {
         assert_eq!(&["#"], ChannelId::SIGILS);
-        assert_eq!(&[":"], EmojiId::SIGILS);
-        assert_eq!(&["#", ":", "@&", "@!", "@", "t:"], MentionType::SIGILS);
+        assert_eq!(&[":", "a:"], CustomEmoji::SIGILS);
+        assert_eq!(&[":", "a:"], EmojiId::SIGILS);
+        assert_eq!(
+            &["#", ":", "a:", "@&", "@!", "@", "t:"],
+            MentionType::SIGILS
+        );
         assert_eq!(&["@&"], RoleId::SIGILS);
         assert_eq!(&["@!", "@"], UserId::SIGILS);
     }
@@ -385,15 +456,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_keywords() {
+        assert!(<ChannelId as Sealed>::KEYWORDS.is_empty());
+        assert_eq!(&["@everyone", "@here"], <MentionType as Sealed>::KEYWORDS);
+    }
+
+    #[test]
+    fn test_parse_custom_emoji() {
+        let id = EmojiId::new(123).expect("non zero");
+
+        assert_eq!(
+            CustomEmoji::new(id, "name", false),
+            CustomEmoji::parse("<:name:123>").unwrap()
+        );
+        assert_eq!(
+            CustomEmoji::new(id, "dance", true),
+            CustomEmoji::parse("<a:dance:123>").unwrap()
+        );
+        assert_eq!(
+            &ParseMentionErrorType::PartMissing {
+                expected: 2,
+                found: 1,
+            },
+            CustomEmoji::parse("<a:123>").unwrap_err().kind(),
+        );
+    }
+
     #[test]
     fn test_parse_emoji_id() {
         assert_eq!(
             EmojiId::new(123).expect("non zero"),
             EmojiId::parse("<:name:123>").unwrap()
         );
+        assert_eq!(
+            EmojiId::new(123).expect("non zero"),
+            EmojiId::parse("<a:name:123>").unwrap()
+        );
         assert_eq!(
             &ParseMentionErrorType::Sigil {
-                expected: &[":"],
+                expected: &[":", "a:"],
                 found: Some('@'),
             },
             EmojiId::parse("<@123>").unwrap_err().kind(),
@@ -410,6 +512,15 @@ mod tests {
             MentionType::Emoji(EmojiId::new(123).expect("non zero")),
             MentionType::parse("<:name:123>").unwrap()
         );
+        assert_eq!(
+            MentionType::Emoji(EmojiId::new(123).expect("non zero")),
+            MentionType::parse("<a:name:123>").unwrap()
+        );
+        assert_eq!(
+            MentionType::Everyone,
+            MentionType::parse("@everyone").unwrap()
+        );
+        assert_eq!(MentionType::Here, MentionType::parse("@here").unwrap());
         assert_eq!(
             MentionType::Role(RoleId::new(123).expect("non zero")),
             MentionType::parse("<@&123>").unwrap()
@@ -420,7 +531,7 @@ mod tests {
         );
         assert_eq!(
             &ParseMentionErrorType::Sigil {
-                expected: &["#", ":", "@&", "@!", "@", "t:"],
+                expected: &["#", ":", "a:", "@&", "@!", "@", "t:"],
                 found: Some(';'),
             },
             MentionType::parse("<;123>").unwrap_err().kind(),
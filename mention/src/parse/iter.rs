@@ -1,4 +1,4 @@
-use super::ParseMention;
+use super::{r#impl::private::Sealed, ParseMention};
 use std::{iter::Iterator, marker::PhantomData, str::CharIndices};
 
 /// Iterator of mentions within a buffer.
@@ -51,6 +51,36 @@ impl<'a, T> MentionIter<'a, T> {
     }
 }
 
+impl<'a, T: ParseMention> MentionIter<'a, T> {
+    /// Parse a mention that isn't wrapped in arrows, such as `@everyone`,
+    /// starting at an index.
+    ///
+    /// Keywords must start at a word boundary, so `email@everyone` isn't a
+    /// mention.
+    fn keyword(&mut self, start: usize) -> Option<(T, usize, usize)> {
+        let preceding = self.buf.get(..start)?.chars().next_back();
+
+        if preceding.map_or(false, |c| c.is_alphanumeric() || c == '_') {
+            return None;
+        }
+
+        let remaining = self.buf.get(start..)?;
+        let keyword = <T as Sealed>::KEYWORDS
+            .iter()
+            .find(|keyword| remaining.starts_with(*keyword))?;
+        let end = start + keyword.len() - 1;
+
+        // The first character of the keyword has already been consumed.
+        for _ in 1..keyword.chars().count() {
+            self.chars.next();
+        }
+
+        T::parse(&self.buf[start..=end])
+            .ok()
+            .map(|mention| (mention, start, end))
+    }
+}
+
 impl<'a, T: ParseMention> Iterator for MentionIter<'a, T> {
     /// Found mention followed by the start and ending indexes in the source
     /// string returned by [`as_str`].
@@ -65,9 +95,18 @@ impl<'a, T: ParseMention> Iterator for MentionIter<'a, T> {
         loop {
             let start = match self.chars.next()? {
                 (idx, '<') => idx,
-                _ => continue,
+                (idx, _) => {
+                    if let Some(found) = self.keyword(idx) {
+                        return Some(found);
+                    }
+
+                    continue;
+                }
             };
 
+            // Restart the scan after the `<` if this isn't a mention, so that
+            // mentions within the brackets, such as `@everyone`, are found.
+            let resume = self.chars.clone();
             let mut found = false;
 
             for sigil in T::SIGILS {
@@ -84,22 +123,25 @@ impl<'a, T: ParseMention> Iterator for MentionIter<'a, T> {
                 continue;
             }
 
-            let end = match self.chars.find(|c| c.1 == '>') {
-                Some((idx, _)) => idx,
-                None => continue,
-            };
-            let buf = self.buf.get(start..=end)?;
+            if let Some((end, _)) = self.chars.find(|c| c.1 == '>') {
+                let buf = self.buf.get(start..=end)?;
 
-            if let Ok(id) = T::parse(buf) {
-                return Some((id, start, end));
+                if let Ok(id) = T::parse(buf) {
+                    return Some((id, start, end));
+                }
             }
+
+            self.chars = resume;
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::timestamp::{Timestamp, TimestampStyle};
+    use crate::{
+        emoji::CustomEmoji,
+        timestamp::{Timestamp, TimestampStyle},
+    };
 
     use super::{
         super::{MentionType, ParseMention},
@@ -110,6 +152,7 @@ mod tests {
     use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 
     assert_impl_all!(MentionIter<'_, ChannelId>: Clone, Debug, Iterator, Send, Sync);
+    assert_impl_all!(MentionIter<'_, CustomEmoji>: Clone, Debug, Iterator, Send, Sync);
     assert_impl_all!(MentionIter<'_, EmojiId>: Clone, Debug, Iterator, Send, Sync);
     assert_impl_all!(MentionIter<'_, MentionType>: Clone, Debug, Iterator, Send, Sync);
     assert_impl_all!(MentionIter<'_, RoleId>: Clone, Debug, Iterator, Send, Sync);
@@ -146,12 +189,57 @@ mod tests {
 
     #[test]
     fn test_iter_emoji_ids() {
-        let mut iter = EmojiId::iter("some <:name:123> emojis <:emoji:456>");
+        let mut iter = EmojiId::iter("some <:name:123> emojis <a:emoji:456>");
         assert_eq!(EmojiId::new(123).expect("non zero"), iter.next().unwrap().0);
         assert_eq!(EmojiId::new(456).expect("non zero"), iter.next().unwrap().0);
         assert!(iter.next().is_none());
     }
 
+    #[test]
+    fn test_iter_custom_emojis() {
+        let mut iter = CustomEmoji::iter("some <:name:123> emojis <a:emoji:456> @everyone");
+        assert_eq!(
+            CustomEmoji::new(EmojiId::new(123).expect("non zero"), "name", false),
+            iter.next().unwrap().0
+        );
+        assert_eq!(
+            CustomEmoji::new(EmojiId::new(456).expect("non zero"), "emoji", true),
+            iter.next().unwrap().0
+        );
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_iter_everyone_here() {
+        let buf = "@everyone and <@12>, @here @her";
+        let mut iter = MentionType::iter(buf);
+        assert_eq!(Some((MentionType::Everyone, 0, 8)), iter.next());
+        assert_eq!(
+            MentionType::User(UserId::new(12).expect("non zero")),
+            iter.next().unwrap().0
+        );
+        let (mention, start, end) = iter.next().unwrap();
+        assert_eq!(MentionType::Here, mention);
+        assert_eq!("@here", &buf[start..=end]);
+        assert!(iter.next().is_none());
+
+        assert!(UserId::iter("@everyone").next().is_none());
+    }
+
+    #[test]
+    fn test_iter_keyword_boundary() {
+        assert!(MentionType::iter("email@everyone").next().is_none());
+        assert!(MentionType::iter("snake_@here").next().is_none());
+        assert_eq!(
+            Some((MentionType::Here, 1, 5)),
+            MentionType::iter("(@here)").next()
+        );
+        assert_eq!(
+            Some((MentionType::Everyone, 1, 9)),
+            MentionType::iter("@@everyone").next()
+        );
+    }
+
     #[test]
     fn test_iter_mention_type() {
         let mut iter = MentionType::iter("<#12><:name:34><@&56><@!78><@90>");
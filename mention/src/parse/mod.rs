@@ -4,7 +4,8 @@
 //! to lazily parse mentions.
 //!
 //! There is also the [`MentionType`]: it's an enum wrapping all possible types
-//! of mentions and works just like the individual IDs and [`Timestamp`].
+//! of mentions and works just like the individual IDs, [`CustomEmoji`] and
+//! [`Timestamp`]. It's the only type that recognizes `@everyone` and `@here`.
 //!
 //! While the syntax of mentions will be validated and the IDs within them
 //! parsed, they won't be validated as being proper snowflakes or as real IDs in
@@ -54,6 +55,7 @@
 //! # Ok(()) }
 //! ```
 //!
+//! [`CustomEmoji`]: crate::emoji::CustomEmoji
 //! [`Timestamp`]: crate::timestamp::Timestamp
 
 mod error;
@@ -91,6 +93,7 @@ use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 /// assert_eq!(MentionType::Channel(ChannelId::new(123).expect("non zero")), MentionType::parse("<#123>")?);
 /// assert_eq!(MentionType::Role(RoleId::new(123).expect("non zero")), MentionType::parse("<@&123>")?);
 /// assert_eq!(MentionType::User(UserId::new(123).expect("non zero")), MentionType::parse("<@!123>")?);
+/// assert_eq!(MentionType::Everyone, MentionType::parse("@everyone")?);
 ///
 /// let timestamp = Timestamp::new(123, None);
 /// assert_eq!(MentionType::Timestamp(timestamp), MentionType::parse("<t:123>")?);
@@ -106,11 +109,12 @@ use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 ///  };
 /// use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 ///
-/// let buf = "channel <#12> emoji <:name:34> role <@&56> timestamp <t:1624047978> user <@78>";
+/// let buf = "channel <#12> emoji <a:name:34> @here role <@&56> timestamp <t:1624047978> user <@78>";
 ///
 /// let mut iter = MentionType::iter(buf);
 /// assert!(matches!(iter.next(), Some((MentionType::Channel(channel), _, _)) if channel.get() == 12));
 /// assert!(matches!(iter.next(), Some((MentionType::Emoji(emoji), _, _)) if emoji.get() == 34));
+/// assert!(matches!(iter.next(), Some((MentionType::Here, _, _))));
 /// assert!(matches!(iter.next(), Some((MentionType::Role(role), _, _)) if role.get() == 56));
 /// assert!(matches!(
 ///     iter.next(),
@@ -126,7 +130,16 @@ pub enum MentionType {
     /// Channel mention.
     Channel(ChannelId),
     /// Emoji mention.
+    ///
+    /// Parse a [`CustomEmoji`] to get the name of the emoji and whether it's
+    /// animated.
+    ///
+    /// [`CustomEmoji`]: crate::emoji::CustomEmoji
     Emoji(EmojiId),
+    /// Mention of everyone in a channel, `@everyone`.
+    Everyone,
+    /// Mention of everyone online in a channel, `@here`.
+    Here,
     /// Role mention.
     Role(RoleId),
     /// Timestamp mention.
@@ -140,6 +153,8 @@ impl Display for MentionType {
         match self {
             Self::Channel(id) => Display::fmt(id, f),
             Self::Emoji(id) => Display::fmt(id, f),
+            Self::Everyone => f.write_str("@everyone"),
+            Self::Here => f.write_str("@here"),
             Self::Role(id) => Display::fmt(id, f),
             Self::Timestamp(timestamp) => Display::fmt(&timestamp.mention(), f),
             Self::User(id) => Display::fmt(id, f),
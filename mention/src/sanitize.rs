@@ -0,0 +1,252 @@
+//! Neutralize mentions in content, such as user input echoed into a message,
+//! and infer the [`AllowedMentions`] of the mentions that remain.
+//!
+//! Mentions that would notify someone - user, role, `@everyone` and `@here`
+//! mentions - are either kept, broken by inserting a zero-width space after
+//! their `@`, or replaced with other text, such as the display name of the
+//! mentioned user. Other mentions, such as channel mentions, don't notify
+//! anyone and are kept as-is.
+//!
+//! # Examples
+//!
+//! Break all of the mentions in user input:
+//!
+//! ```
+//! use twilight_mention::sanitize;
+//!
+//! let sanitized = sanitize::sanitize("hi @everyone and <@123>");
+//!
+//! assert_eq!("hi @\u{200B}everyone and <@\u{200B}123>", sanitized.content());
+//! assert!(sanitized.allowed_mentions().users.is_empty());
+//! ```
+//!
+//! Resolve user mentions to display names, keeping role mentions:
+//!
+//! ```
+//! use twilight_mention::{
+//!     parse::MentionType,
+//!     sanitize::{self, SanitizeAction},
+//! };
+//!
+//! let sanitized = sanitize::sanitize_with("<@123> pinged <@&456>", |mention| match mention {
+//!     MentionType::User(id) if id.get() == 123 => SanitizeAction::Replace("@Ferris".to_owned()),
+//!     MentionType::Role(_) => SanitizeAction::Keep,
+//!     _ => SanitizeAction::Break,
+//! });
+//!
+//! assert_eq!("@Ferris pinged <@&456>", sanitized.content());
+//! assert_eq!(456, sanitized.allowed_mentions().roles[0].get());
+//! ```
+
+use crate::parse::{MentionType, ParseMention};
+use twilight_model::channel::message::{allowed_mentions::ParseTypes, AllowedMentions};
+
+/// Zero-width space inserted into mentions to break them.
+pub(crate) const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// Action to take on a mention that would notify someone.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum SanitizeAction {
+    /// Break the mention by inserting a zero-width space after its `@`.
+    ///
+    /// The mention is displayed as text and doesn't notify anyone.
+    Break,
+    /// Keep the mention, allowing it in the inferred [`AllowedMentions`].
+    Keep,
+    /// Replace the mention with text, such as the display name of a user.
+    ///
+    /// Any mentions in the text are broken.
+    Replace(String),
+}
+
+/// Content with neutralized mentions and the [`AllowedMentions`] of the
+/// mentions that remain.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sanitized {
+    allowed_mentions: AllowedMentions,
+    content: String,
+}
+
+impl Sanitized {
+    /// Minimal allowed mentions of the mentions that were kept.
+    ///
+    /// Kept user and role mentions are explicitly allowed by their IDs, and
+    /// `@everyone` is allowed if an `@everyone` or `@here` mention was kept.
+    #[must_use = "retrieving the allowed mentions has no effect if left unused"]
+    pub const fn allowed_mentions(&self) -> &AllowedMentions {
+        &self.allowed_mentions
+    }
+
+    /// Sanitized content.
+    #[must_use = "retrieving the content has no effect if left unused"]
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Consume the sanitized content, returning the content and its allowed
+    /// mentions.
+    #[must_use = "consuming into the parts has no effect if left unused"]
+    pub fn into_parts(self) -> (String, AllowedMentions) {
+        (self.content, self.allowed_mentions)
+    }
+}
+
+/// Break all mentions in content that would notify someone.
+///
+/// The allowed mentions of the returned content allow nothing.
+#[must_use = "sanitizing content has no effect if left unused"]
+pub fn sanitize(content: &str) -> Sanitized {
+    sanitize_with(content, |_| SanitizeAction::Break)
+}
+
+/// Sanitize the mentions in content that would notify someone, with a
+/// function deciding the action to take on each mention.
+///
+/// The function is only called with [`MentionType::Everyone`],
+/// [`MentionType::Here`], [`MentionType::Role`] and [`MentionType::User`]
+/// mentions.
+pub fn sanitize_with(
+    content: &str,
+    mut action: impl FnMut(&MentionType) -> SanitizeAction,
+) -> Sanitized {
+    let mut allowed_mentions = AllowedMentions::default();
+    let mut sanitized = String::with_capacity(content.len());
+    let mut position = 0;
+
+    for (mention, start, end) in MentionType::iter(content) {
+        if !matches!(
+            mention,
+            MentionType::Everyone | MentionType::Here | MentionType::Role(_) | MentionType::User(_)
+        ) {
+            continue;
+        }
+
+        sanitized.push_str(&content[position..start]);
+        position = end + 1;
+
+        let source = &content[start..=end];
+
+        match action(&mention) {
+            SanitizeAction::Break => push_broken(&mut sanitized, source),
+            SanitizeAction::Keep => {
+                sanitized.push_str(source);
+                allow(&mut allowed_mentions, &mention);
+            }
+            SanitizeAction::Replace(text) => sanitized.push_str(sanitize(&text).content()),
+        }
+    }
+
+    sanitized.push_str(&content[position..]);
+
+    Sanitized {
+        allowed_mentions,
+        content: sanitized,
+    }
+}
+
+/// Allow a kept mention, if it isn't allowed yet.
+fn allow(allowed_mentions: &mut AllowedMentions, mention: &MentionType) {
+    match mention {
+        MentionType::Everyone | MentionType::Here
+            if !allowed_mentions.parse.contains(&ParseTypes::Everyone) =>
+        {
+            allowed_mentions.parse.push(ParseTypes::Everyone);
+        }
+        MentionType::Role(id) if !allowed_mentions.roles.contains(id) => {
+            allowed_mentions.roles.push(*id);
+        }
+        MentionType::User(id) if !allowed_mentions.users.contains(id) => {
+            allowed_mentions.users.push(*id);
+        }
+        _ => {}
+    }
+}
+
+/// Push a mention with a zero-width space inserted after its `@`.
+fn push_broken(buf: &mut String, source: &str) {
+    if let Some(position) = source.find('@') {
+        buf.push_str(&source[..=position]);
+        buf.push(ZERO_WIDTH_SPACE);
+        buf.push_str(&source[position + 1..]);
+    } else {
+        buf.push_str(source);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sanitize, sanitize_with, SanitizeAction, Sanitized};
+    use crate::parse::MentionType;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        channel::message::{allowed_mentions::ParseTypes, AllowedMentions},
+        id::{RoleId, UserId},
+    };
+
+    assert_impl_all!(SanitizeAction: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(Sanitized: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_sanitize() {
+        let sanitized = sanitize("@everyone @here <@1> <@!2> <@&3> <#4> <:name:5> <t:6>");
+
+        assert_eq!(
+            "@\u{200B}everyone @\u{200B}here <@\u{200B}1> <@\u{200B}!2> <@\u{200B}&3> <#4> <:name:5> <t:6>",
+            sanitized.content()
+        );
+        assert_eq!(&AllowedMentions::default(), sanitized.allowed_mentions());
+    }
+
+    #[test]
+    fn test_sanitize_no_mentions() {
+        let content = "no <mentions> @ all";
+
+        assert_eq!(content, sanitize(content).content());
+    }
+
+    #[test]
+    fn test_sanitize_within_brackets() {
+        assert_eq!("<@@\u{200B}everyone>", sanitize("<@@everyone>").content());
+        assert_eq!("<@ hi @\u{200B}here>", sanitize("<@ hi @here>").content());
+        assert_eq!(
+            "x<@123 @\u{200B}everyone>",
+            sanitize("x<@123 @everyone>").content()
+        );
+    }
+
+    #[test]
+    fn test_keep() {
+        let sanitized = sanitize_with("<@1> <@&2> @here <@!1> @everyone <@3>", |mention| {
+            if matches!(mention, MentionType::User(id) if id.get() == 3) {
+                SanitizeAction::Break
+            } else {
+                SanitizeAction::Keep
+            }
+        });
+
+        let (content, allowed_mentions) = sanitized.into_parts();
+        assert_eq!("<@1> <@&2> @here <@!1> @everyone <@\u{200B}3>", content);
+        assert_eq!(
+            AllowedMentions {
+                parse: vec![ParseTypes::Everyone],
+                users: vec![UserId::new(1).expect("non zero")],
+                roles: vec![RoleId::new(2).expect("non zero")],
+                replied_user: false,
+            },
+            allowed_mentions
+        );
+    }
+
+    #[test]
+    fn test_replace() {
+        let sanitized = sanitize_with("hello <@1>!", |mention| match mention {
+            MentionType::User(_) => SanitizeAction::Replace("@everyone <@2>".to_owned()),
+            _ => SanitizeAction::Keep,
+        });
+
+        assert_eq!("hello @\u{200B}everyone <@\u{200B}2>!", sanitized.content());
+        assert_eq!(&AllowedMentions::default(), sanitized.allowed_mentions());
+    }
+}
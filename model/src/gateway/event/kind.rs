@@ -62,7 +62,9 @@ pub enum EventType {
     RoleUpdate,
     ShardConnected,
     ShardConnecting,
+    ShardDead,
     ShardDisconnected,
+    ShardGuildsReady,
     ShardIdentifying,
     ShardReconnecting,
     ShardPayload,
@@ -146,7 +148,9 @@ impl EventType {
             | Self::GatewayReconnect
             | Self::ShardConnected
             | Self::ShardConnecting
+            | Self::ShardDead
             | Self::ShardDisconnected
+            | Self::ShardGuildsReady
             | Self::ShardIdentifying
             | Self::ShardReconnecting
             | Self::ShardPayload
@@ -287,7 +291,9 @@ mod tests {
         assert_variant(EventType::RoleUpdate, "GUILD_ROLE_UPDATE");
         assert_variant(EventType::ShardConnected, "SHARD_CONNECTED");
         assert_variant(EventType::ShardConnecting, "SHARD_CONNECTING");
+        assert_variant(EventType::ShardDead, "SHARD_DEAD");
         assert_variant(EventType::ShardDisconnected, "SHARD_DISCONNECTED");
+        assert_variant(EventType::ShardGuildsReady, "SHARD_GUILDS_READY");
         assert_variant(EventType::ShardIdentifying, "SHARD_IDENTIFYING");
         assert_variant(EventType::ShardPayload, "SHARD_PAYLOAD");
         assert_variant(EventType::ShardReconnecting, "SHARD_RECONNECTING");
@@ -118,8 +118,12 @@ pub enum Event {
     /// A shard is now in a connecting stage after starting to connect to the
     /// gateway.
     ShardConnecting(Connecting),
+    /// A shard has stopped and won't reconnect.
+    ShardDead(Dead),
     /// A shard is now in a disconnected stage after the connection was closed.
     ShardDisconnected(Disconnected),
+    /// A shard has received all of the guilds from its session's ready event.
+    ShardGuildsReady(GuildsReady),
     /// A shard is now in a identifying stage after starting a new session.
     ShardIdentifying(Identifying),
     /// A shard is now in a reconnecting stage after a disconnect or session was
@@ -209,7 +213,9 @@ impl Event {
             Self::RoleUpdate(_) => EventType::RoleUpdate,
             Self::ShardConnected(_) => EventType::ShardConnected,
             Self::ShardConnecting(_) => EventType::ShardConnecting,
+            Self::ShardDead(_) => EventType::ShardDead,
             Self::ShardDisconnected(_) => EventType::ShardDisconnected,
+            Self::ShardGuildsReady(_) => EventType::ShardGuildsReady,
             Self::ShardIdentifying(_) => EventType::ShardIdentifying,
             Self::ShardReconnecting(_) => EventType::ShardReconnecting,
             Self::ShardPayload(_) => EventType::ShardPayload,
@@ -310,7 +316,9 @@ impl From<ShardEvent> for Event {
         match event {
             ShardEvent::Connected(v) => Self::ShardConnected(v),
             ShardEvent::Connecting(v) => Self::ShardConnecting(v),
+            ShardEvent::Dead(v) => Self::ShardDead(v),
             ShardEvent::Disconnected(v) => Self::ShardDisconnected(v),
+            ShardEvent::GuildsReady(v) => Self::ShardGuildsReady(v),
             ShardEvent::Identifying(v) => Self::ShardIdentifying(v),
             ShardEvent::Payload(v) => Self::ShardPayload(v),
             ShardEvent::Reconnecting(v) => Self::ShardReconnecting(v),
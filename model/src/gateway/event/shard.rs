@@ -1,4 +1,5 @@
 use super::{Event, EventConversionError};
+use crate::id::GuildId;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -20,6 +21,22 @@ pub struct Connecting {
     pub shard_id: u64,
 }
 
+/// Indicator that a shard has stopped and won't reconnect.
+///
+/// This happens when the gateway closes the connection with a fatal close
+/// code, or when the shard's reconnect policy gives up on reconnecting.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Dead {
+    /// Number of consecutive reconnect attempts made since the shard's last
+    /// successful session.
+    pub attempts: u32,
+    /// The code of the most recent close frame received from the gateway, if
+    /// any.
+    pub code: Option<u16>,
+    /// The ID of the shard that's now dead.
+    pub shard_id: u64,
+}
+
 /// Indicator that a shard is now disconnected and may soon be reconnecting if
 /// not explicitly shutdown.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -32,6 +49,22 @@ pub struct Disconnected {
     pub shard_id: u64,
 }
 
+/// Indicator that a shard has received all of the guilds that were unavailable
+/// in its most recent [`Ready`] event.
+///
+/// Guilds that were not received before the shard's guild ready timeout elapsed
+/// are included in [`unavailable_guilds`].
+///
+/// [`Ready`]: crate::gateway::payload::incoming::Ready
+/// [`unavailable_guilds`]: Self::unavailable_guilds
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct GuildsReady {
+    /// The ID of the shard that is now fully ready.
+    pub shard_id: u64,
+    /// Guilds that were still unavailable when the shard became ready.
+    pub unavailable_guilds: Vec<GuildId>,
+}
+
 /// Indicator that a shard is now identifying with the gateway to create a new
 /// session.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -75,8 +108,12 @@ pub enum ShardEvent {
     /// A shard is now in a Connecting stage after starting to connect to the
     /// gateway.
     Connecting(Connecting),
+    /// A shard has stopped and won't reconnect.
+    Dead(Dead),
     /// A shard is now in a Disconnected stage after the connection was closed.
     Disconnected(Disconnected),
+    /// A shard has received all of the guilds from its session's ready event.
+    GuildsReady(GuildsReady),
     /// A shard is now in a Identifying stage after starting a new session.
     Identifying(Identifying),
     /// A payload of bytes came in through the shard's connection.
@@ -95,7 +132,9 @@ impl TryFrom<Event> for ShardEvent {
         Ok(match event {
             Event::ShardConnected(v) => Self::Connected(v),
             Event::ShardConnecting(v) => Self::Connecting(v),
+            Event::ShardDead(v) => Self::Dead(v),
             Event::ShardDisconnected(v) => Self::Disconnected(v),
+            Event::ShardGuildsReady(v) => Self::GuildsReady(v),
             Event::ShardIdentifying(v) => Self::Identifying(v),
             Event::ShardPayload(v) => Self::Payload(v),
             Event::ShardReconnecting(v) => Self::Reconnecting(v),
@@ -109,9 +148,10 @@ impl TryFrom<Event> for ShardEvent {
 #[cfg(test)]
 mod tests {
     use super::{
-        Connected, Connecting, Disconnected, Event, Identifying, Payload, Reconnecting, Resuming,
-        ShardEvent,
+        Connected, Connecting, Dead, Disconnected, Event, GuildsReady, Identifying, Payload,
+        Reconnecting, Resuming, ShardEvent,
     };
+    use crate::id::GuildId;
     use serde_test::Token;
     use std::convert::TryInto;
 
@@ -161,6 +201,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dead() {
+        let value = Dead {
+            attempts: 5,
+            code: Some(4_000),
+            shard_id: 4,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Dead",
+                    len: 3,
+                },
+                Token::Str("attempts"),
+                Token::U32(5),
+                Token::Str("code"),
+                Token::Some,
+                Token::U16(4_000),
+                Token::Str("shard_id"),
+                Token::U64(4),
+                Token::StructEnd,
+            ],
+        );
+    }
+
     #[test]
     fn test_disconnected() {
         let value = Disconnected {
@@ -189,6 +256,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_guilds_ready() {
+        let value = GuildsReady {
+            shard_id: 4,
+            unavailable_guilds: vec![GuildId::new(1).expect("non zero")],
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildsReady",
+                    len: 2,
+                },
+                Token::Str("shard_id"),
+                Token::U64(4),
+                Token::Str("unavailable_guilds"),
+                Token::Seq { len: Some(1) },
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
     #[test]
     fn test_identifying() {
         let value = Identifying {
@@ -294,6 +387,13 @@ mod tests {
             ShardEvent::Connecting(_)
         ));
 
+        let dead = Event::ShardDead(Dead {
+            attempts: 5,
+            code: None,
+            shard_id: 4,
+        });
+        assert!(matches!(dead.try_into().unwrap(), ShardEvent::Dead(_)));
+
         let disconnected = Event::ShardDisconnected(Disconnected {
             code: Some(4_000),
             reason: None,
@@ -304,6 +404,15 @@ mod tests {
             ShardEvent::Disconnected(_)
         ));
 
+        let guilds_ready = Event::ShardGuildsReady(GuildsReady {
+            shard_id: 4,
+            unavailable_guilds: Vec::new(),
+        });
+        assert!(matches!(
+            guilds_ready.try_into().unwrap(),
+            ShardEvent::GuildsReady(_)
+        ));
+
         let identifying = Event::ShardIdentifying(Identifying {
             shard_id: 4,
             shard_total: 7,
@@ -8,6 +8,9 @@ pub struct VanityUrl {
     /// For example, in an invite of `discord.gg/applejack`, the code is
     /// `applejack`.
     pub code: String,
+    /// Number of times the vanity URL has been used.
+    #[serde(default)]
+    pub uses: u64,
 }
 
 #[cfg(test)]
@@ -19,16 +22,19 @@ mod tests {
     fn test_vanity_url() {
         let url = VanityUrl {
             code: "a".to_owned(),
+            uses: 2,
         };
         serde_test::assert_tokens(
             &url,
             &[
                 Token::Struct {
                     name: "VanityUrl",
-                    len: 1,
+                    len: 2,
                 },
                 Token::String("code"),
                 Token::String("a"),
+                Token::String("uses"),
+                Token::U64(2),
                 Token::StructEnd,
             ],
         );
@@ -0,0 +1,120 @@
+//! Errors building and running a paginator.
+
+use crate::paginator::Paginator;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Building or running a [`Paginator`] failed.
+#[derive(Debug)]
+pub struct PaginatorError {
+    kind: PaginatorErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl PaginatorError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &PaginatorErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (PaginatorErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    pub(crate) const fn new(kind: PaginatorErrorType) -> Self {
+        Self { kind, source: None }
+    }
+
+    pub(crate) fn deserializing(source: impl Error + Send + Sync + 'static) -> Self {
+        Self {
+            kind: PaginatorErrorType::Deserializing,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    pub(crate) fn requesting(source: impl Error + Send + Sync + 'static) -> Self {
+        Self {
+            kind: PaginatorErrorType::Requesting,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for PaginatorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            PaginatorErrorType::Deserializing => f.write_str("failed to deserialize the message"),
+            PaginatorErrorType::PagesEmpty => f.write_str("paginator has no pages"),
+            PaginatorErrorType::PagesTooMany { count } => {
+                f.write_str("paginator has ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" pages, but a select menu can have at most ")?;
+                Display::fmt(&Paginator::SELECT_MENU_PAGES_MAX, f)?;
+
+                f.write_str(" pages")
+            }
+            PaginatorErrorType::Requesting => {
+                f.write_str("failed to send or update the paginated message")
+            }
+        }
+    }
+}
+
+impl Error for PaginatorError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`PaginatorError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PaginatorErrorType {
+    /// Deserializing the sent message failed.
+    Deserializing,
+    /// No pages were provided.
+    PagesEmpty,
+    /// More pages were provided than fit in a select menu.
+    ///
+    /// Refer to [`Paginator::SELECT_MENU_PAGES_MAX`] for the limit.
+    PagesTooMany {
+        /// Number of provided pages.
+        count: usize,
+    },
+    /// Sending, responding with, or updating the message failed.
+    Requesting,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PaginatorError, PaginatorErrorType};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+
+    assert_fields!(PaginatorErrorType::PagesTooMany: count);
+    assert_impl_all!(PaginatorErrorType: Debug, Send, Sync);
+    assert_impl_all!(PaginatorError: Error, Send, Sync);
+
+    #[test]
+    fn test_display() {
+        let error = PaginatorError::new(PaginatorErrorType::PagesTooMany { count: 30 });
+
+        assert_eq!(
+            "paginator has 30 pages, but a select menu can have at most 25 pages",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! # twilight-paginator
+//!
+//! [![codecov badge][]][codecov link] [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-paginator` is a paginator of embeds navigated with message
+//! components for the [`twilight-rs`] ecosystem.
+//!
+//! A [`Paginator`] displays one embed at a time, with either buttons to go to
+//! the first, previous, next and last pages or a select menu to jump to any
+//! page. It's sent as a message to a channel or as the response to an
+//! interaction, after which it answers every click on its components with
+//! the new page until no one has interacted with it for a while, at which
+//! point its components are disabled. A paginator can be restricted to only
+//! be navigable by the user that it's for.
+//!
+//! Interactions are received through a [`Standby`], which must be processing
+//! the events received from the gateway.
+//!
+//! Pages can be created from long content with
+//! [`EmbedBuilder::build_pages`].
+//!
+//! ## Examples
+//!
+//! Page through a list of members when a command is used, in a spawned task
+//! since the paginator runs until it times out:
+//!
+//! ```no_run
+//! use std::{env, sync::Arc, time::Duration};
+//! use twilight_embed_builder::{EmbedBuilder, EmbedFieldBuilder};
+//! use twilight_http::Client;
+//! use twilight_model::application::interaction::ApplicationCommand;
+//! use twilight_paginator::{Controls, Paginator};
+//! use twilight_standby::Standby;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let http = Arc::new(Client::new(env::var("DISCORD_TOKEN")?));
+//! # let standby = Arc::new(Standby::new());
+//! # let command: ApplicationCommand = unimplemented!();
+//! let pages = (1..=100)
+//!     .fold(EmbedBuilder::new().title("Members"), |builder, index| {
+//!         builder.field(EmbedFieldBuilder::new(format!("Member {}", index), "Joined today"))
+//!     })
+//!     .build_pages()?;
+//!
+//! let mut paginator = Paginator::builder(pages)
+//!     .controls(Controls::Buttons)
+//!     .timeout(Duration::from_secs(60));
+//!
+//! let author = command
+//!     .member
+//!     .as_ref()
+//!     .and_then(|member| member.user.as_ref())
+//!     .or_else(|| command.user.as_ref());
+//!
+//! if let Some(user) = author {
+//!     paginator = paginator.owner(user.id);
+//! }
+//!
+//! let paginator = paginator.build()?;
+//!
+//! tokio::spawn(async move {
+//!     let result = paginator
+//!         .respond(&http, &standby, command.id, &command.token)
+//!         .await;
+//!
+//!     if let Err(source) = result {
+//!         eprintln!("paginator failed: {}", source);
+//!     }
+//! });
+//! # Ok(()) }
+//! ```
+//!
+//! [`EmbedBuilder::build_pages`]: https://docs.rs/twilight-embed-builder/*/twilight_embed_builder/struct.EmbedBuilder.html#method.build_pages
+//! [`Standby`]: twilight_standby::Standby
+//! [`twilight-rs`]: https://github.com/twilight-rs/twilight
+//! [codecov badge]: https://img.shields.io/codecov/c/gh/twilight-rs/twilight?logo=codecov&style=for-the-badge&token=E9ERLJL0L2
+//! [codecov link]: https://app.codecov.io/gh/twilight-rs/twilight/
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/main/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.53+-93450a.svg?style=for-the-badge&logo=rust
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(
+    clippy::all,
+    clippy::missing_const_for_fn,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    rustdoc::broken_intra_doc_links,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+pub mod error;
+
+mod paginator;
+
+pub use self::{
+    error::{PaginatorError, PaginatorErrorType},
+    paginator::{Controls, Paginator, PaginatorBuilder},
+};
@@ -0,0 +1,760 @@
+//! Paginator and its builder.
+
+use crate::error::{PaginatorError, PaginatorErrorType};
+use futures_util::StreamExt;
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::time::{self, Instant};
+use twilight_http::Client;
+use twilight_model::{
+    application::{
+        callback::{CallbackData, InteractionResponse},
+        component::{
+            button::ButtonStyle, select_menu::SelectMenuOption, ActionRow, Button, Component,
+            SelectMenu,
+        },
+        interaction::MessageComponentInteraction,
+    },
+    channel::{embed::Embed, Message},
+    id::{ChannelId, InteractionId, UserId},
+};
+use twilight_standby::Standby;
+
+/// Action of the button to go to the first page.
+const FIRST: &str = "first";
+
+/// Action of the button to go to the last page.
+const LAST: &str = "last";
+
+/// Action of the button to go to the next page.
+const NEXT: &str = "next";
+
+/// Action of the disabled button displaying the page number.
+const PAGE: &str = "page";
+
+/// Action of the button to go to the previous page.
+const PREVIOUS: &str = "previous";
+
+/// Action of the select menu to jump to a page.
+const SELECT: &str = "select";
+
+/// Maximum number of characters in the description of a select menu option.
+const OPTION_DESCRIPTION_LENGTH_MAX: usize = 100;
+
+/// Components to navigate the pages with.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Controls {
+    /// Buttons to go to the first, previous, next and last pages, with the
+    /// page number displayed between them.
+    Buttons,
+    /// Select menu to jump to any page, listing the pages by their number and
+    /// title.
+    ///
+    /// Only available if there are at most
+    /// [`Paginator::SELECT_MENU_PAGES_MAX`] pages.
+    SelectMenu,
+}
+
+/// Message the paginator is attached to.
+enum Target<'a> {
+    /// Response to an interaction, updated by an interaction token.
+    Interaction { token: &'a str },
+    /// Message sent to a channel.
+    Message { channel_id: ChannelId },
+}
+
+/// Paginator of embeds navigated with message components.
+///
+/// Each paginator generates its own custom IDs for its components, such that
+/// multiple paginators can run at once.
+///
+/// Refer to the [crate-level documentation] for examples.
+///
+/// [crate-level documentation]: crate
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Paginator {
+    controls: Controls,
+    id: String,
+    owner: Option<UserId>,
+    page: usize,
+    pages: Vec<Embed>,
+    timeout: Duration,
+}
+
+impl Paginator {
+    /// Maximum number of pages navigable with [`Controls::SelectMenu`].
+    pub const SELECT_MENU_PAGES_MAX: usize = 25;
+
+    /// Default duration without any interactions after which the paginator
+    /// stops.
+    pub const TIMEOUT_DEFAULT: Duration = Duration::from_secs(120);
+
+    /// Create a builder to configure a paginator of pages.
+    pub const fn builder(pages: Vec<Embed>) -> PaginatorBuilder {
+        PaginatorBuilder::new(pages)
+    }
+
+    /// Components of the current page.
+    #[must_use = "retrieving the components has no effect if left unused"]
+    pub fn components(&self) -> Vec<Component> {
+        self.render_components(false)
+    }
+
+    /// Embed of the current page.
+    #[must_use = "retrieving the page has no effect if left unused"]
+    pub fn page(&self) -> &Embed {
+        &self.pages[self.page]
+    }
+
+    /// Index of the current page, starting at 0.
+    #[must_use = "retrieving the page index has no effect if left unused"]
+    pub const fn page_index(&self) -> usize {
+        self.page
+    }
+
+    /// Embeds of all pages.
+    #[must_use = "retrieving the pages has no effect if left unused"]
+    pub fn pages(&self) -> &[Embed] {
+        &self.pages
+    }
+
+    /// Response to an interaction creating a message with the current page
+    /// and its components.
+    #[must_use = "creating a response has no effect if left unused"]
+    pub fn response(&self) -> InteractionResponse {
+        InteractionResponse::ChannelMessageWithSource(self.callback_data())
+    }
+
+    /// Handle an interaction with one of the paginator's components,
+    /// returning the response to the interaction.
+    ///
+    /// The page is updated and responded with as an
+    /// [`InteractionResponse::UpdateMessage`]. If the paginator has an owner
+    /// and the interaction is by another user, the interaction is
+    /// acknowledged with an [`InteractionResponse::DeferredUpdateMessage`]
+    /// without changing the page.
+    ///
+    /// This is called by [`send`] and [`respond`], and is only needed when
+    /// dispatching component interactions manually.
+    ///
+    /// Returns [`None`] if the interaction isn't with one of the paginator's
+    /// components.
+    ///
+    /// [`respond`]: Self::respond
+    /// [`send`]: Self::send
+    pub fn handle(
+        &mut self,
+        interaction: &MessageComponentInteraction,
+    ) -> Option<InteractionResponse> {
+        let action = interaction
+            .data
+            .custom_id
+            .strip_prefix(self.id.as_str())?
+            .strip_prefix(':')?;
+
+        if self.owner.is_some() && interaction.author_id() != self.owner {
+            return Some(InteractionResponse::DeferredUpdateMessage);
+        }
+
+        let last = self.pages.len() - 1;
+
+        self.page = match action {
+            FIRST => 0,
+            LAST => last,
+            NEXT => (self.page + 1).min(last),
+            PREVIOUS => self.page.saturating_sub(1),
+            SELECT => interaction
+                .data
+                .values
+                .first()
+                .and_then(|value| value.parse().ok())
+                .filter(|page| *page <= last)
+                .unwrap_or(self.page),
+            _ => self.page,
+        };
+
+        Some(InteractionResponse::UpdateMessage(self.callback_data()))
+    }
+
+    /// Send the paginator as a message to a channel and handle interactions
+    /// with it until it times out.
+    ///
+    /// The components are disabled once the paginator times out. The
+    /// returned future only resolves then, so it is usually spawned as a
+    /// task.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PaginatorErrorType::Deserializing`] error type if the sent
+    /// message couldn't be deserialized.
+    ///
+    /// Returns a [`PaginatorErrorType::Requesting`] error type if sending or
+    /// updating the message, or responding to an interaction failed.
+    pub async fn send(
+        self,
+        http: &Client,
+        standby: &Standby,
+        channel_id: ChannelId,
+    ) -> Result<(), PaginatorError> {
+        let embeds = [self.page().clone()];
+        let components = self.components();
+
+        let message = http
+            .create_message(channel_id)
+            .embeds(&embeds)
+            .map_err(PaginatorError::requesting)?
+            .components(&components)
+            .map_err(PaginatorError::requesting)?
+            .exec()
+            .await
+            .map_err(PaginatorError::requesting)?
+            .model()
+            .await
+            .map_err(PaginatorError::deserializing)?;
+
+        self.run(http, standby, &message, Target::Message { channel_id })
+            .await
+    }
+
+    /// Respond to an interaction with the paginator and handle interactions
+    /// with it until it times out.
+    ///
+    /// The interaction must not have been responded to yet. The components
+    /// are disabled once the paginator times out, which must be within 15
+    /// minutes of the last interaction while the interaction token is valid.
+    /// The returned future only resolves then, so it is usually spawned as a
+    /// task.
+    ///
+    /// The application ID of the client must be set.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PaginatorErrorType::Deserializing`] error type if the
+    /// response message couldn't be deserialized.
+    ///
+    /// Returns a [`PaginatorErrorType::Requesting`] error type if responding
+    /// to an interaction or updating the response failed.
+    pub async fn respond(
+        self,
+        http: &Client,
+        standby: &Standby,
+        interaction_id: InteractionId,
+        token: &str,
+    ) -> Result<(), PaginatorError> {
+        http.interaction_callback(interaction_id, token, &self.response())
+            .exec()
+            .await
+            .map_err(PaginatorError::requesting)?;
+
+        let message = http
+            .get_interaction_original(token)
+            .map_err(PaginatorError::requesting)?
+            .exec()
+            .await
+            .map_err(PaginatorError::requesting)?
+            .model()
+            .await
+            .map_err(PaginatorError::deserializing)?;
+
+        self.run(http, standby, &message, Target::Interaction { token })
+            .await
+    }
+
+    /// Handle interactions with the components of a message until the
+    /// paginator times out, then disable the components.
+    ///
+    /// The timeout is only reset by accepted interactions, so interactions of
+    /// users other than the owner don't keep the paginator alive. Failing to
+    /// respond to an interaction doesn't stop the paginator; the first such
+    /// error is returned once the components are disabled.
+    async fn run(
+        mut self,
+        http: &Client,
+        standby: &Standby,
+        message: &Message,
+        target: Target<'_>,
+    ) -> Result<(), PaginatorError> {
+        let prefix = format!("{}:", self.id);
+        let mut interactions = standby
+            .wait_for_component_stream(message.id, move |event: &MessageComponentInteraction| {
+                event.data.custom_id.starts_with(&prefix)
+            });
+
+        // Interaction tokens expire after 15 minutes, so the token of the
+        // latest interaction is used to disable the components.
+        let mut latest_token = None;
+        let mut deadline = Instant::now() + self.timeout;
+        let mut error = None;
+
+        while let Ok(Some(interaction)) = time::timeout_at(deadline, interactions.next()).await {
+            let response = match self.handle(&interaction) {
+                Some(response) => response,
+                None => continue,
+            };
+            let accepted = matches!(response, InteractionResponse::UpdateMessage(_));
+            let result = http
+                .interaction_callback(interaction.id, &interaction.token, &response)
+                .exec()
+                .await;
+
+            match result {
+                Ok(_) => {
+                    if accepted {
+                        deadline = Instant::now() + self.timeout;
+                    }
+
+                    latest_token = Some(interaction.token);
+                }
+                Err(source) => {
+                    error.get_or_insert_with(|| PaginatorError::requesting(source));
+                }
+            }
+        }
+
+        let disabled = self
+            .disable(http, message, target, latest_token.as_deref())
+            .await;
+
+        match error {
+            Some(error) => Err(error),
+            None => disabled,
+        }
+    }
+
+    /// Disable the components of a message.
+    async fn disable(
+        &self,
+        http: &Client,
+        message: &Message,
+        target: Target<'_>,
+        latest_token: Option<&str>,
+    ) -> Result<(), PaginatorError> {
+        let components = self.render_components(true);
+
+        match target {
+            Target::Interaction { token } => {
+                http.update_interaction_original(latest_token.unwrap_or(token))
+                    .map_err(PaginatorError::requesting)?
+                    .components(Some(&components))
+                    .map_err(PaginatorError::requesting)?
+                    .exec()
+                    .await
+                    .map_err(PaginatorError::requesting)?;
+            }
+            Target::Message { channel_id } => {
+                http.update_message(channel_id, message.id)
+                    .components(Some(&components))
+                    .map_err(PaginatorError::requesting)?
+                    .exec()
+                    .await
+                    .map_err(PaginatorError::requesting)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Message data of the current page and its components.
+    fn callback_data(&self) -> CallbackData {
+        CallbackData {
+            allowed_mentions: None,
+            components: Some(self.components()),
+            content: None,
+            embeds: vec![self.page().clone()],
+            flags: None,
+            tts: None,
+        }
+    }
+
+    /// Custom ID of a component performing an action.
+    fn custom_id(&self, action: &str) -> String {
+        format!("{}:{}", self.id, action)
+    }
+
+    /// Create a button performing an action.
+    fn button(&self, action: &str, label: String, disabled: bool) -> Component {
+        Component::Button(Button {
+            custom_id: Some(self.custom_id(action)),
+            disabled,
+            emoji: None,
+            label: Some(label),
+            style: ButtonStyle::Secondary,
+            url: None,
+        })
+    }
+
+    /// Render the components of the current page, optionally all disabled.
+    fn render_components(&self, disabled: bool) -> Vec<Component> {
+        let first = self.page == 0;
+        let last = self.page == self.pages.len() - 1;
+
+        let components = match self.controls {
+            Controls::Buttons => vec![
+                self.button(FIRST, "«".to_owned(), disabled || first),
+                self.button(PREVIOUS, "‹".to_owned(), disabled || first),
+                self.button(
+                    PAGE,
+                    format!("{}/{}", self.page + 1, self.pages.len()),
+                    true,
+                ),
+                self.button(NEXT, "›".to_owned(), disabled || last),
+                self.button(LAST, "»".to_owned(), disabled || last),
+            ],
+            Controls::SelectMenu => {
+                let options = self
+                    .pages
+                    .iter()
+                    .enumerate()
+                    .map(|(index, page)| SelectMenuOption {
+                        default: index == self.page,
+                        description: page.title.as_ref().map(|title| {
+                            title.chars().take(OPTION_DESCRIPTION_LENGTH_MAX).collect()
+                        }),
+                        emoji: None,
+                        label: format!("Page {}", index + 1),
+                        value: index.to_string(),
+                    })
+                    .collect();
+
+                vec![Component::SelectMenu(SelectMenu {
+                    custom_id: self.custom_id(SELECT),
+                    disabled,
+                    max_values: Some(1),
+                    min_values: Some(1),
+                    options,
+                    placeholder: None,
+                })]
+            }
+        };
+
+        vec![Component::ActionRow(ActionRow { components })]
+    }
+}
+
+/// Configure and build a [`Paginator`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a paginator"]
+pub struct PaginatorBuilder {
+    controls: Controls,
+    owner: Option<UserId>,
+    pages: Vec<Embed>,
+    timeout: Duration,
+}
+
+impl PaginatorBuilder {
+    /// Create a new builder to configure a paginator of pages.
+    pub const fn new(pages: Vec<Embed>) -> Self {
+        Self {
+            controls: Controls::Buttons,
+            owner: None,
+            pages,
+            timeout: Paginator::TIMEOUT_DEFAULT,
+        }
+    }
+
+    /// Build the paginator, generating the custom IDs of its components.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PaginatorErrorType::PagesEmpty`] error type if there are no
+    /// pages.
+    ///
+    /// Returns a [`PaginatorErrorType::PagesTooMany`] error type if the
+    /// controls are a [`Controls::SelectMenu`] and there are more than
+    /// [`Paginator::SELECT_MENU_PAGES_MAX`] pages.
+    pub fn build(self) -> Result<Paginator, PaginatorError> {
+        let count = self.pages.len();
+
+        if count == 0 {
+            return Err(PaginatorError::new(PaginatorErrorType::PagesEmpty));
+        }
+
+        if self.controls == Controls::SelectMenu && count > Paginator::SELECT_MENU_PAGES_MAX {
+            return Err(PaginatorError::new(PaginatorErrorType::PagesTooMany {
+                count,
+            }));
+        }
+
+        Ok(Paginator {
+            controls: self.controls,
+            id: generate_id(),
+            owner: self.owner,
+            page: 0,
+            pages: self.pages,
+            timeout: self.timeout,
+        })
+    }
+
+    /// Set the components to navigate the pages with.
+    ///
+    /// Defaults to [`Controls::Buttons`].
+    pub const fn controls(mut self, controls: Controls) -> Self {
+        self.controls = controls;
+
+        self
+    }
+
+    /// Set the only user allowed to navigate the pages.
+    ///
+    /// Defaults to allowing any user.
+    pub const fn owner(mut self, owner: UserId) -> Self {
+        self.owner = Some(owner);
+
+        self
+    }
+
+    /// Set the duration without any interactions after which the paginator
+    /// stops and disables its components.
+    ///
+    /// Interactions by users other than the [owner] don't count.
+    ///
+    /// Defaults to [`Paginator::TIMEOUT_DEFAULT`].
+    ///
+    /// [owner]: Self::owner
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+
+        self
+    }
+}
+
+/// Generate a custom ID prefix unique to a paginator.
+fn generate_id() -> String {
+    static COUNT: AtomicU64 = AtomicU64::new(0);
+
+    let count = COUNT.fetch_add(1, Ordering::Relaxed);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.subsec_nanos());
+
+    format!("paginator-{:x}-{:x}", nanos, count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Controls, Paginator, PaginatorBuilder};
+    use crate::error::PaginatorErrorType;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+    use twilight_embed_builder::EmbedBuilder;
+    use twilight_model::{
+        application::{
+            callback::InteractionResponse,
+            component::{Component, ComponentType},
+            interaction::{
+                message_component::MessageComponentInteractionData, InteractionType,
+                MessageComponentInteraction,
+            },
+        },
+        channel::{
+            embed::Embed,
+            message::{Message, MessageType},
+        },
+        datetime::Timestamp,
+        id::{ApplicationId, ChannelId, InteractionId, MessageId, UserId},
+        user::User,
+    };
+
+    assert_impl_all!(Controls: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(Paginator: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(PaginatorBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    fn pages(count: usize) -> Vec<Embed> {
+        (1..=count)
+            .map(|page| {
+                EmbedBuilder::new()
+                    .title(format!("Title {}", page))
+                    .build()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    fn user(id: u64) -> User {
+        User {
+            accent_color: None,
+            avatar: None,
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            id: UserId::new(id).expect("non zero"),
+            locale: None,
+            mfa_enabled: None,
+            name: "user".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        }
+    }
+
+    fn interaction(
+        user_id: u64,
+        custom_id: String,
+        values: Vec<String>,
+    ) -> MessageComponentInteraction {
+        MessageComponentInteraction {
+            application_id: ApplicationId::new(1).expect("non zero"),
+            channel_id: ChannelId::new(2).expect("non zero"),
+            data: MessageComponentInteractionData {
+                custom_id,
+                component_type: ComponentType::Button,
+                values,
+            },
+            guild_id: None,
+            id: InteractionId::new(3).expect("non zero"),
+            kind: InteractionType::MessageComponent,
+            member: None,
+            message: Message {
+                activity: None,
+                application: None,
+                application_id: None,
+                attachments: Vec::new(),
+                author: user(4),
+                channel_id: ChannelId::new(2).expect("non zero"),
+                components: Vec::new(),
+                content: String::new(),
+                edited_timestamp: None,
+                embeds: Vec::new(),
+                flags: None,
+                guild_id: None,
+                id: MessageId::new(5).expect("non zero"),
+                interaction: None,
+                kind: MessageType::Regular,
+                member: None,
+                mention_channels: Vec::new(),
+                mention_everyone: false,
+                mention_roles: Vec::new(),
+                mentions: Vec::new(),
+                pinned: false,
+                reactions: Vec::new(),
+                reference: None,
+                referenced_message: None,
+                sticker_items: Vec::new(),
+                thread: None,
+                timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+                tts: false,
+                webhook_id: None,
+            },
+            token: "token".to_owned(),
+            user: Some(user(user_id)),
+        }
+    }
+
+    fn custom_ids(components: &[Component]) -> Vec<(String, bool)> {
+        match &components[0] {
+            Component::ActionRow(row) => row
+                .components
+                .iter()
+                .map(|component| match component {
+                    Component::Button(button) => {
+                        (button.custom_id.clone().unwrap(), button.disabled)
+                    }
+                    Component::SelectMenu(menu) => (menu.custom_id.clone(), menu.disabled),
+                    Component::ActionRow(_) => unreachable!(),
+                })
+                .collect(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_build() {
+        assert!(matches!(
+            Paginator::builder(Vec::new()).build().unwrap_err().kind(),
+            PaginatorErrorType::PagesEmpty
+        ));
+        assert!(matches!(
+            Paginator::builder(pages(26))
+                .controls(Controls::SelectMenu)
+                .build()
+                .unwrap_err()
+                .kind(),
+            PaginatorErrorType::PagesTooMany { count: 26 }
+        ));
+        assert!(Paginator::builder(pages(26)).build().is_ok());
+
+        let first = Paginator::builder(pages(1)).build().unwrap();
+        let second = Paginator::builder(pages(1)).build().unwrap();
+        assert_ne!(
+            custom_ids(&first.components()),
+            custom_ids(&second.components())
+        );
+    }
+
+    #[test]
+    fn test_buttons() {
+        let mut paginator = Paginator::builder(pages(3)).build().unwrap();
+        let ids = custom_ids(&paginator.components());
+        assert_eq!(5, ids.len());
+        assert_eq!(
+            vec![true, true, true, false, false],
+            ids.iter()
+                .map(|(_, disabled)| *disabled)
+                .collect::<Vec<_>>()
+        );
+
+        let next = interaction(1, ids[3].0.clone(), Vec::new());
+        assert!(matches!(
+            paginator.handle(&next),
+            Some(InteractionResponse::UpdateMessage(data))
+            if data.embeds[0].title.as_deref() == Some("Title 2")
+        ));
+        assert_eq!(1, paginator.page_index());
+
+        let last = interaction(1, ids[4].0.clone(), Vec::new());
+        paginator.handle(&last);
+        paginator.handle(&next);
+        assert_eq!(2, paginator.page_index());
+        assert_eq!(
+            vec![false, false, true, true, true],
+            custom_ids(&paginator.components())
+                .iter()
+                .map(|(_, disabled)| *disabled)
+                .collect::<Vec<_>>()
+        );
+
+        let other = interaction(1, "other:next".to_owned(), Vec::new());
+        assert!(paginator.handle(&other).is_none());
+    }
+
+    #[test]
+    fn test_select_menu() {
+        let mut paginator = Paginator::builder(pages(5))
+            .controls(Controls::SelectMenu)
+            .build()
+            .unwrap();
+        let ids = custom_ids(&paginator.components());
+        assert_eq!(1, ids.len());
+
+        paginator.handle(&interaction(1, ids[0].0.clone(), vec!["3".to_owned()]));
+        assert_eq!(3, paginator.page_index());
+        assert_eq!(Some("Title 4"), paginator.page().title.as_deref());
+
+        paginator.handle(&interaction(1, ids[0].0.clone(), vec!["9".to_owned()]));
+        assert_eq!(3, paginator.page_index());
+    }
+
+    #[test]
+    fn test_owner() {
+        let mut paginator = Paginator::builder(pages(2))
+            .owner(UserId::new(1).expect("non zero"))
+            .build()
+            .unwrap();
+        let next = custom_ids(&paginator.components())[3].0.clone();
+
+        assert!(matches!(
+            paginator.handle(&interaction(2, next.clone(), Vec::new())),
+            Some(InteractionResponse::DeferredUpdateMessage)
+        ));
+        assert_eq!(0, paginator.page_index());
+
+        paginator.handle(&interaction(1, next, Vec::new()));
+        assert_eq!(1, paginator.page_index());
+    }
+}
@@ -0,0 +1,164 @@
+//! Parsing of the arguments of attributes.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Attribute, Ident, Lit, LitStr, Meta, Result, Token,
+};
+
+/// Value of an argument, such as the `5` of `min_value = 5`.
+pub struct Value {
+    /// Whether the value is negated.
+    negative: bool,
+    /// Literal of the value.
+    lit: Lit,
+}
+
+impl Value {
+    pub fn bool(&self) -> Result<bool> {
+        match &self.lit {
+            Lit::Bool(lit) if !self.negative => Ok(lit.value),
+            other => Err(syn::Error::new_spanned(other, "expected a boolean")),
+        }
+    }
+
+    pub fn string(&self) -> Result<String> {
+        match &self.lit {
+            Lit::Str(lit) if !self.negative => Ok(lit.value()),
+            other => Err(syn::Error::new_spanned(other, "expected a string")),
+        }
+    }
+
+    pub const fn lit(&self) -> &Lit {
+        &self.lit
+    }
+}
+
+impl ToTokens for Value {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if self.negative {
+            tokens.extend(quote!(-));
+        }
+
+        self.lit.to_tokens(tokens);
+    }
+}
+
+/// Argument of an attribute.
+pub enum Arg {
+    /// List of identifiers, such as `channel_types(GuildText, GuildNews)`.
+    List {
+        /// Name of the argument.
+        name: Ident,
+        /// Identifiers in the list.
+        items: Vec<Ident>,
+    },
+    /// Named value, such as `name = "ping"`.
+    Value {
+        /// Name of the argument.
+        name: Ident,
+        /// Value of the argument.
+        value: Value,
+    },
+}
+
+impl Arg {
+    pub const fn name(&self) -> &Ident {
+        match self {
+            Self::List { name, .. } | Self::Value { name, .. } => name,
+        }
+    }
+
+    pub fn into_list(self) -> Result<Vec<Ident>> {
+        match self {
+            Self::List { items, .. } => Ok(items),
+            Self::Value { name, .. } => Err(syn::Error::new_spanned(
+                name,
+                "expected a list of identifiers",
+            )),
+        }
+    }
+
+    pub fn into_value(self) -> Result<Value> {
+        match self {
+            Self::List { name, .. } => Err(syn::Error::new_spanned(name, "expected a value")),
+            Self::Value { value, .. } => Ok(value),
+        }
+    }
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let name = input.parse::<Ident>()?;
+
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            let negative = input.parse::<Option<Token![-]>>()?.is_some();
+            let lit = input.parse()?;
+
+            Ok(Self::Value {
+                name,
+                value: Value { negative, lit },
+            })
+        } else {
+            let content;
+            parenthesized!(content in input);
+            let items = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+
+            Ok(Self::List {
+                name,
+                items: items.into_iter().collect(),
+            })
+        }
+    }
+}
+
+/// Parse the arguments of all attributes with a name.
+pub fn parse(attrs: &[Attribute], name: &str) -> Result<Vec<Arg>> {
+    let mut args = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident(name)) {
+        let parsed = attr.parse_args_with(Punctuated::<Arg, Token![,]>::parse_terminated)?;
+        args.extend(parsed);
+    }
+
+    Ok(args)
+}
+
+/// Error for an unknown argument.
+pub fn unknown(arg: &Arg) -> syn::Error {
+    syn::Error::new_spanned(arg.name(), "unknown argument")
+}
+
+/// Description from the `desc` argument or the first paragraph of the doc
+/// comments.
+pub fn description(desc: Option<String>, attrs: &[Attribute], span: Span) -> Result<LitStr> {
+    let description = desc.or_else(|| {
+        let lines = attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("doc"))
+            .filter_map(|attr| match attr.parse_meta() {
+                Ok(Meta::NameValue(meta)) => match meta.lit {
+                    Lit::Str(lit) => Some(lit.value().trim().to_owned()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .skip_while(String::is_empty)
+            .take_while(|line| !line.is_empty())
+            .collect::<Vec<_>>();
+
+        if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join(" "))
+        }
+    });
+
+    description
+        .map(|description| LitStr::new(&description, span))
+        .ok_or_else(|| syn::Error::new(span, "a description or doc comment is required"))
+}
@@ -0,0 +1,164 @@
+//! Expansion of `#[derive(OptionChoices)]`.
+
+use crate::attr::{self, Value};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Data, DeriveInput, Fields, Lit, Result};
+
+/// Value of a choice.
+enum ChoiceValue {
+    /// Integer value.
+    Integer(Value),
+    /// String value, defaulting to the name of the variant.
+    String(String),
+}
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "only enums can have choices",
+            ))
+        }
+    };
+
+    let mut names = Vec::new();
+    let mut values = Vec::new();
+    let mut variants = Vec::new();
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new(
+                variant.span(),
+                "choices must be unit variants",
+            ));
+        }
+
+        let mut name = variant.ident.to_string();
+        let mut value = ChoiceValue::String(name.clone());
+
+        for arg in attr::parse(&variant.attrs, "choice")? {
+            match arg.name().to_string().as_str() {
+                "name" => name = arg.into_value()?.string()?,
+                "value" => {
+                    let arg = arg.into_value()?;
+
+                    value = match arg.lit() {
+                        Lit::Int(_) => ChoiceValue::Integer(arg),
+                        _ => ChoiceValue::String(arg.string()?),
+                    };
+                }
+                _ => return Err(attr::unknown(&arg)),
+            }
+        }
+
+        names.push(name);
+        values.push(value);
+        variants.push(&variant.ident);
+    }
+
+    let integers = values
+        .iter()
+        .filter(|value| matches!(value, ChoiceValue::Integer(_)))
+        .count();
+
+    let (create, parse) = if integers == 0 {
+        let values = values
+            .iter()
+            .map(|value| match value {
+                ChoiceValue::String(value) => value,
+                ChoiceValue::Integer(_) => unreachable!("all values are strings"),
+            })
+            .collect::<Vec<_>>();
+
+        expand_string(&names, &values, &variants)
+    } else if integers == values.len() {
+        let values = values
+            .iter()
+            .map(|value| match value {
+                ChoiceValue::Integer(value) => value,
+                ChoiceValue::String(_) => unreachable!("all values are integers"),
+            })
+            .collect::<Vec<_>>();
+
+        expand_integer(&names, &values, &variants)
+    } else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "choice values must all be strings or all be integers",
+        ));
+    };
+
+    let ident = &input.ident;
+
+    Ok(quote! {
+        impl ::twilight_slash_command::__private::OptionValue for #ident {
+            fn create_option(
+                data: ::twilight_slash_command::__private::OptionData,
+            ) -> ::twilight_slash_command::__private::CommandOption {
+                #create
+            }
+
+            fn from_option(
+                option: &::twilight_slash_command::__private::CommandDataOption,
+                _: ::std::option::Option<
+                    &::twilight_slash_command::__private::CommandInteractionDataResolved,
+                >,
+            ) -> ::std::result::Result<Self, ::twilight_slash_command::__private::ParseError> {
+                #parse
+            }
+        }
+    })
+}
+
+fn expand_integer(
+    names: &[String],
+    values: &[&Value],
+    variants: &[&syn::Ident],
+) -> (TokenStream, TokenStream) {
+    let create = quote! {
+        data.into_integer(::std::vec![
+            #(::twilight_slash_command::__private::CommandOptionChoice::Int {
+                name: ::std::string::String::from(#names),
+                value: #values,
+            }),*
+        ])
+    };
+    let parse = quote! {
+        match ::twilight_slash_command::__private::choice_integer(option)? {
+            #(#values => ::std::result::Result::Ok(Self::#variants),)*
+            other => ::std::result::Result::Err(
+                ::twilight_slash_command::__private::choice_unknown(option, &other),
+            ),
+        }
+    };
+
+    (create, parse)
+}
+
+fn expand_string(
+    names: &[String],
+    values: &[&String],
+    variants: &[&syn::Ident],
+) -> (TokenStream, TokenStream) {
+    let create = quote! {
+        data.into_string(::std::vec![
+            #(::twilight_slash_command::__private::CommandOptionChoice::String {
+                name: ::std::string::String::from(#names),
+                value: ::std::string::String::from(#values),
+            }),*
+        ])
+    };
+    let parse = quote! {
+        match ::twilight_slash_command::__private::choice_string(option)? {
+            #(#values => ::std::result::Result::Ok(Self::#variants),)*
+            other => ::std::result::Result::Err(
+                ::twilight_slash_command::__private::choice_unknown(option, &other),
+            ),
+        }
+    };
+
+    (create, parse)
+}
@@ -0,0 +1,277 @@
+//! Expansion of `#[derive(SlashCommand)]`.
+
+use crate::attr;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    spanned::Spanned, Data, DataEnum, DataStruct, DeriveInput, Field, Fields, GenericArgument, Lit,
+    LitStr, PathArguments, Result, Type,
+};
+
+/// Arguments of the `#[command]` attribute of the type.
+struct CommandArgs {
+    /// Whether the command is enabled by default.
+    default_permission: Option<bool>,
+    /// Description of the command.
+    description: LitStr,
+    /// Name of the command.
+    name: LitStr,
+}
+
+impl CommandArgs {
+    fn parse(input: &DeriveInput) -> Result<Self> {
+        let mut default_permission = None;
+        let mut desc = None;
+        let mut name = None;
+
+        for arg in attr::parse(&input.attrs, "command")? {
+            match arg.name().to_string().as_str() {
+                "default_permission" => default_permission = Some(arg.into_value()?.bool()?),
+                "desc" => desc = Some(arg.into_value()?.string()?),
+                "name" => {
+                    let span = arg.name().span();
+                    name = Some(LitStr::new(&arg.into_value()?.string()?, span));
+                }
+                _ => return Err(attr::unknown(&arg)),
+            }
+        }
+
+        Ok(Self {
+            default_permission,
+            description: attr::description(desc, &input.attrs, input.ident.span())?,
+            name: name.ok_or_else(|| {
+                syn::Error::new(
+                    input.ident.span(),
+                    "a name is required: #[command(name = \"..\")]",
+                )
+            })?,
+        })
+    }
+}
+
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let args = CommandArgs::parse(input)?;
+
+    let (create, parse) = match &input.data {
+        Data::Enum(data) => expand_enum(data)?,
+        Data::Struct(data) => expand_struct(data)?,
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "unions can't be commands",
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let name = &args.name;
+    let description = &args.description;
+    let default_permission = optional(args.default_permission.map(|value| quote!(#value)));
+
+    Ok(quote! {
+        impl #impl_generics ::twilight_slash_command::__private::SlashCommand
+            for #ident #ty_generics #where_clause
+        {
+            const NAME: &'static str = #name;
+
+            fn create_command() -> ::twilight_slash_command::__private::Command {
+                ::twilight_slash_command::__private::command(
+                    Self::NAME,
+                    #description,
+                    #default_permission,
+                    #create,
+                )
+            }
+
+            fn from_options(
+                options: &[::twilight_slash_command::__private::CommandDataOption],
+                resolved: ::std::option::Option<
+                    &::twilight_slash_command::__private::CommandInteractionDataResolved,
+                >,
+            ) -> ::std::result::Result<Self, ::twilight_slash_command::__private::ParseError> {
+                #parse
+            }
+        }
+    })
+}
+
+/// Expand an enum into a subcommand per variant.
+fn expand_enum(data: &DataEnum) -> Result<(TokenStream, TokenStream)> {
+    let mut types = Vec::new();
+    let mut variants = Vec::new();
+
+    for variant in &data.variants {
+        match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                types.push(&fields.unnamed[0].ty);
+                variants.push(&variant.ident);
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    "variants must hold exactly one command",
+                ))
+            }
+        }
+    }
+
+    let create = quote! {
+        ::std::vec![
+            #(::twilight_slash_command::__private::subcommand::<#types>()),*
+        ]
+    };
+    let parse = quote! {
+        let (name, options) = ::twilight_slash_command::__private::subcommand_options(options)?;
+
+        #(
+            if name == <#types as ::twilight_slash_command::__private::SlashCommand>::NAME {
+                return ::std::result::Result::Ok(Self::#variants(
+                    <#types as ::twilight_slash_command::__private::SlashCommand>::from_options(
+                        options,
+                        resolved,
+                    )?,
+                ));
+            }
+        )*
+
+        ::std::result::Result::Err(::twilight_slash_command::__private::subcommand_unknown(name))
+    };
+
+    Ok((create, parse))
+}
+
+/// Expand a struct into an option per field.
+fn expand_struct(data: &DataStruct) -> Result<(TokenStream, TokenStream)> {
+    let fields = match &data.fields {
+        Fields::Named(fields) => &fields.named,
+        Fields::Unit => {
+            return Ok((
+                quote!(::std::vec::Vec::new()),
+                quote!(::std::result::Result::Ok(Self)),
+            ))
+        }
+        Fields::Unnamed(fields) => {
+            return Err(syn::Error::new(
+                fields.span(),
+                "tuple structs can't be commands",
+            ))
+        }
+    };
+
+    let mut options = Vec::new();
+    let mut parsers = Vec::new();
+
+    for field in fields {
+        let (option, parser) = expand_field(field)?;
+        options.push(option);
+        parsers.push(parser);
+    }
+
+    let create = quote!(::std::vec![#(#options),*]);
+    let parse = quote!(::std::result::Result::Ok(Self { #(#parsers),* }));
+
+    Ok((create, parse))
+}
+
+/// Expand a field into the definition of its option and its parser.
+fn expand_field(field: &Field) -> Result<(TokenStream, TokenStream)> {
+    let ident = field.ident.as_ref().expect("named field");
+    let mut autocomplete = false;
+    let mut channel_types = Vec::new();
+    let mut desc = None;
+    let mut max_value = None;
+    let mut min_value = None;
+    let mut name = ident.to_string();
+
+    for arg in attr::parse(&field.attrs, "command")? {
+        match arg.name().to_string().as_str() {
+            "autocomplete" => autocomplete = arg.into_value()?.bool()?,
+            "channel_types" => channel_types = arg.into_list()?,
+            "desc" => desc = Some(arg.into_value()?.string()?),
+            "max_value" => max_value = Some(limit(&arg.into_value()?)?),
+            "min_value" => min_value = Some(limit(&arg.into_value()?)?),
+            "rename" => name = arg.into_value()?.string()?,
+            _ => return Err(attr::unknown(&arg)),
+        }
+    }
+
+    let description = attr::description(desc, &field.attrs, ident.span())?;
+    let (ty, required) = match option_inner(&field.ty) {
+        Some(inner) => (inner, false),
+        None => (&field.ty, true),
+    };
+    let max_value = optional(max_value);
+    let min_value = optional(min_value);
+
+    let option = quote! {
+        <#ty as ::twilight_slash_command::__private::OptionValue>::create_option(
+            ::twilight_slash_command::__private::OptionData {
+                autocomplete: #autocomplete,
+                channel_types: ::std::vec![
+                    #(::twilight_slash_command::__private::ChannelType::#channel_types),*
+                ],
+                description: ::std::string::String::from(#description),
+                max_value: #max_value,
+                min_value: #min_value,
+                name: ::std::string::String::from(#name),
+                required: #required,
+            },
+        )
+    };
+
+    let parse_fn = if required {
+        quote!(required)
+    } else {
+        quote!(optional)
+    };
+    let parser = quote! {
+        #ident: ::twilight_slash_command::__private::#parse_fn::<#ty>(options, #name, resolved)?
+    };
+
+    Ok((option, parser))
+}
+
+/// Expand the value of `min_value` or `max_value`.
+fn limit(value: &attr::Value) -> Result<TokenStream> {
+    match value.lit() {
+        Lit::Int(_) => Ok(quote! {
+            ::twilight_slash_command::__private::CommandOptionValue::Integer(#value)
+        }),
+        Lit::Float(_) => Ok(quote! {
+            ::twilight_slash_command::__private::CommandOptionValue::Number(
+                ::twilight_slash_command::__private::Number(#value),
+            )
+        }),
+        other => Err(syn::Error::new_spanned(other, "expected a number")),
+    }
+}
+
+fn optional(value: Option<TokenStream>) -> TokenStream {
+    if let Some(value) = value {
+        quote!(::std::option::Option::Some(#value))
+    } else {
+        quote!(::std::option::Option::None)
+    }
+}
+
+/// Inner type of an `Option`, if the type is one.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+    let segment = path.segments.last()?;
+
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,47 @@
+//! # twilight-slash-command-derive
+//!
+//! Derive macros for [`twilight-slash-command`]. Refer to its documentation
+//! for how to use them.
+//!
+//! [`twilight-slash-command`]: https://docs.rs/twilight-slash-command
+
+#![deny(
+    clippy::all,
+    clippy::missing_const_for_fn,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions)]
+
+mod attr;
+mod choices;
+mod command;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derive `SlashCommand` on a struct or enum.
+#[proc_macro_derive(SlashCommand, attributes(command))]
+pub fn slash_command(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    command::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `OptionValue` on an enum of choices.
+#[proc_macro_derive(OptionChoices, attributes(choice))]
+pub fn option_choices(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    choices::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,167 @@
+use crate::error::{ParseError, ParseErrorType};
+use twilight_model::application::{
+    command::{Command, CommandOption, OptionsCommandOptionData},
+    interaction::application_command::{
+        CommandData, CommandDataOption, CommandInteractionDataResolved, CommandOptionValue,
+    },
+};
+
+/// Slash command with a definition and typed options.
+///
+/// This is usually implemented by deriving [`SlashCommand`]. Structs define a
+/// command with an option per field, while enums define a command with a
+/// subcommand per variant. Refer to the [crate-level documentation] for the
+/// supported attributes.
+///
+/// [`SlashCommand`]: macro@crate::SlashCommand
+/// [crate-level documentation]: crate
+pub trait SlashCommand: Sized {
+    /// Name of the command.
+    const NAME: &'static str;
+
+    /// Create the definition of the command.
+    fn create_command() -> Command;
+
+    /// Parse the command out of the options of an interaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the options don't match the definition of
+    /// the command.
+    fn from_options(
+        options: &[CommandDataOption],
+        resolved: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseError>;
+
+    /// Parse the command out of the data of an interaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the options don't match the definition of
+    /// the command.
+    fn from_data(data: &CommandData) -> Result<Self, ParseError> {
+        Self::from_options(&data.options, data.resolved.as_ref())
+    }
+}
+
+/// Create the option definition of a command used as a subcommand.
+///
+/// Commands whose options are all subcommands become subcommand groups.
+pub fn subcommand<T: SlashCommand>() -> CommandOption {
+    let command = T::create_command();
+    let is_group = !command.options.is_empty()
+        && command
+            .options
+            .iter()
+            .all(|option| matches!(option, CommandOption::SubCommand(_)));
+
+    let data = OptionsCommandOptionData {
+        description: command.description,
+        name: command.name,
+        options: command.options,
+    };
+
+    if is_group {
+        CommandOption::SubCommandGroup(data)
+    } else {
+        CommandOption::SubCommand(data)
+    }
+}
+
+/// Name and options of the subcommand that was called.
+///
+/// # Errors
+///
+/// Returns a [`ParseErrorType::SubcommandMissing`] error type if none of the
+/// options are a subcommand.
+pub fn subcommand_options(
+    options: &[CommandDataOption],
+) -> Result<(&str, &[CommandDataOption]), ParseError> {
+    options
+        .iter()
+        .find_map(|option| match &option.value {
+            CommandOptionValue::SubCommand(options)
+            | CommandOptionValue::SubCommandGroup(options) => {
+                Some((option.name.as_str(), options.as_slice()))
+            }
+            _ => None,
+        })
+        .ok_or_else(|| ParseError::new(ParseErrorType::SubcommandMissing))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SlashCommand;
+    use crate::error::{ParseError, ParseErrorType};
+    use twilight_model::application::{
+        command::{Command, CommandOption, CommandType},
+        interaction::application_command::{
+            CommandDataOption, CommandInteractionDataResolved, CommandOptionValue,
+        },
+    };
+
+    struct Leaf;
+
+    impl SlashCommand for Leaf {
+        const NAME: &'static str = "leaf";
+
+        fn create_command() -> Command {
+            crate::__private::command(Self::NAME, "leaf", None, Vec::new())
+        }
+
+        fn from_options(
+            _: &[CommandDataOption],
+            _: Option<&CommandInteractionDataResolved>,
+        ) -> Result<Self, ParseError> {
+            Ok(Self)
+        }
+    }
+
+    struct Group;
+
+    impl SlashCommand for Group {
+        const NAME: &'static str = "group";
+
+        fn create_command() -> Command {
+            crate::__private::command(Self::NAME, "group", None, vec![super::subcommand::<Leaf>()])
+        }
+
+        fn from_options(
+            _: &[CommandDataOption],
+            _: Option<&CommandInteractionDataResolved>,
+        ) -> Result<Self, ParseError> {
+            Ok(Self)
+        }
+    }
+
+    #[test]
+    fn test_subcommand() {
+        assert!(matches!(
+            super::subcommand::<Leaf>(),
+            CommandOption::SubCommand(data) if data.name == "leaf"
+        ));
+        assert!(matches!(
+            super::subcommand::<Group>(),
+            CommandOption::SubCommandGroup(data) if data.options.len() == 1
+        ));
+        assert_eq!(CommandType::ChatInput, Group::create_command().kind);
+    }
+
+    #[test]
+    fn test_subcommand_options() {
+        let options = [CommandDataOption {
+            focused: false,
+            name: "leaf".to_owned(),
+            value: CommandOptionValue::SubCommand(Vec::new()),
+        }];
+
+        assert_eq!(
+            ("leaf", &[][..]),
+            super::subcommand_options(&options).unwrap()
+        );
+        assert_eq!(
+            &ParseErrorType::SubcommandMissing,
+            super::subcommand_options(&[]).unwrap_err().kind()
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! Errors returned when parsing commands out of interactions.
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::application::command::CommandOptionType;
+
+/// Parsing a command out of the data of an interaction failed.
+#[derive(Debug)]
+pub struct ParseError {
+    kind: ParseErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ParseError {
+    /// Create a new error from its type.
+    ///
+    /// This is useful when implementing [`OptionValue`] manually.
+    ///
+    /// [`OptionValue`]: crate::option::OptionValue
+    pub const fn new(kind: ParseErrorType) -> Self {
+        Self { kind, source: None }
+    }
+
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ParseErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ParseErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ParseErrorType::ChoiceUnknown { name, value } => {
+                f.write_str("option '")?;
+                f.write_str(name)?;
+                f.write_str("' has unknown choice '")?;
+                f.write_str(value)?;
+
+                f.write_str("'")
+            }
+            ParseErrorType::OptionMissing { name } => {
+                f.write_str("required option '")?;
+                f.write_str(name)?;
+
+                f.write_str("' is missing")
+            }
+            ParseErrorType::OptionType {
+                expected,
+                found,
+                name,
+            } => {
+                f.write_str("option '")?;
+                f.write_str(name)?;
+                f.write_str("' is of type ")?;
+                f.write_str(found.kind())?;
+                f.write_str(" but expected ")?;
+
+                f.write_str(expected.kind())
+            }
+            ParseErrorType::ResolvedMissing { name } => {
+                f.write_str("resolved data of option '")?;
+                f.write_str(name)?;
+
+                f.write_str("' is missing")
+            }
+            ParseErrorType::SubcommandMissing => f.write_str("no subcommand was provided"),
+            ParseErrorType::SubcommandUnknown { name } => {
+                f.write_str("subcommand '")?;
+                f.write_str(name)?;
+
+                f.write_str("' is unknown")
+            }
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ParseError`] that occurred.
+#[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseErrorType {
+    /// Value of an option isn't one of its choices.
+    ChoiceUnknown {
+        /// Name of the option.
+        name: String,
+        /// Value that was provided.
+        value: String,
+    },
+    /// Required option wasn't provided.
+    OptionMissing {
+        /// Name of the option.
+        name: String,
+    },
+    /// Option has a different type than expected.
+    OptionType {
+        /// Type of the option in the command definition.
+        expected: CommandOptionType,
+        /// Type of the option that was provided.
+        found: CommandOptionType,
+        /// Name of the option.
+        name: String,
+    },
+    /// Option references a user, role, or channel that Discord didn't
+    /// resolve.
+    ResolvedMissing {
+        /// Name of the option.
+        name: String,
+    },
+    /// Command has subcommands but none was provided.
+    SubcommandMissing,
+    /// Subcommand isn't part of the command definition.
+    SubcommandUnknown {
+        /// Name of the subcommand.
+        name: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseError, ParseErrorType};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::application::command::CommandOptionType;
+
+    assert_fields!(ParseErrorType::ChoiceUnknown: name, value);
+    assert_fields!(ParseErrorType::OptionMissing: name);
+    assert_fields!(ParseErrorType::OptionType: expected, found, name);
+    assert_fields!(ParseErrorType::ResolvedMissing: name);
+    assert_fields!(ParseErrorType::SubcommandUnknown: name);
+    assert_impl_all!(ParseErrorType: Debug, Send, Sync);
+    assert_impl_all!(ParseError: Error, Send, Sync);
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::new(ParseErrorType::OptionType {
+            expected: CommandOptionType::Integer,
+            found: CommandOptionType::String,
+            name: "count".to_owned(),
+        });
+
+        assert_eq!(
+            "option 'count' is of type String but expected Integer",
+            error.to_string()
+        );
+    }
+}
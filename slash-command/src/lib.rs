@@ -0,0 +1,257 @@
+//! # twilight-slash-command
+//!
+//! [![codecov badge][]][codecov link] [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-slash-command` is a slash command framework for the
+//! [`twilight-rs`] ecosystem.
+//!
+//! Commands are defined as types deriving [`SlashCommand`], which generates
+//! the [`Command`] definition to register with Discord and parses the data of
+//! command interactions back into the type. A [`Router`] routes command
+//! interactions to handlers, and the [`sync`] module registers the command
+//! definitions with Discord only if they changed.
+//!
+//! ## Defining commands
+//!
+//! Structs define a command with an option per field. Fields can be of any
+//! type implementing [`OptionValue`], and are optional if they're an
+//! [`Option`]. Enums define a command with a subcommand per variant, each
+//! variant holding a type deriving [`SlashCommand`]. Variants holding an enum
+//! become subcommand groups.
+//!
+//! Enums of unit variants deriving [`OptionChoices`] can be used as options
+//! with a fixed set of choices.
+//!
+//! Descriptions default to the doc comments of types and fields. The
+//! following attributes are supported:
+//!
+//! | Attribute | On | Description |
+//! |-----------|----|-------------|
+//! | `#[command(name = "..")]` | struct, enum | Name of the command, required |
+//! | `#[command(desc = "..")]` | struct, enum, field | Description |
+//! | `#[command(default_permission = false)]` | struct, enum | Whether the command is enabled by default |
+//! | `#[command(rename = "..")]` | field | Name of the option, defaults to the field name |
+//! | `#[command(autocomplete = true)]` | field | Whether the option supports autocomplete |
+//! | `#[command(min_value = 0, max_value = 10)]` | field | Range of integer and number options |
+//! | `#[command(channel_types(GuildText, GuildNews))]` | field | [`ChannelType`]s of channel options |
+//! | `#[choice(name = "..", value = ..)]` | variant | Name and string or integer value of a choice |
+//!
+//! ## Examples
+//!
+//! Define a `/role` command with `add` and `remove` subcommands, route it to
+//! a handler, and synchronize it with Discord:
+//!
+//! ```no_run
+//! use std::env;
+//! use twilight_http::Client;
+//! use twilight_model::id::{ApplicationId, RoleId, UserId};
+//! use twilight_slash_command::{sync, Router, SlashCommand};
+//!
+//! /// Give a role to a member.
+//! #[derive(SlashCommand)]
+//! #[command(name = "add")]
+//! struct AddRole {
+//!     /// Member to give the role to.
+//!     user: UserId,
+//!     /// Role to give.
+//!     role: RoleId,
+//!     /// Reason for the audit log.
+//!     reason: Option<String>,
+//! }
+//!
+//! /// Take a role from a member.
+//! #[derive(SlashCommand)]
+//! #[command(name = "remove")]
+//! struct RemoveRole {
+//!     /// Member to take the role from.
+//!     user: UserId,
+//!     /// Role to take.
+//!     role: RoleId,
+//! }
+//!
+//! /// Manage the roles of members.
+//! #[derive(SlashCommand)]
+//! #[command(name = "role", default_permission = false)]
+//! enum Role {
+//!     Add(AddRole),
+//!     Remove(RemoveRole),
+//! }
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let http = Client::new(env::var("DISCORD_TOKEN")?);
+//! http.set_application_id(ApplicationId::new(123).expect("non zero"));
+//!
+//! let mut router = Router::new();
+//! router.command(|_interaction, command: Role| async move {
+//!     match command {
+//!         Role::Add(add) => println!("adding role {} to {}", add.role, add.user),
+//!         Role::Remove(remove) => println!("removing role {}", remove.role),
+//!     }
+//! });
+//!
+//! let diff = sync::sync_global_commands(&http, &router.commands()).await?;
+//! println!("added commands: {:?}", diff.added());
+//!
+//! // Then, for every received `Interaction::ApplicationCommand`:
+//! // router.dispatch(*command).await?;
+//! # Ok(()) }
+//! ```
+//!
+//! [`ChannelType`]: twilight_model::channel::ChannelType
+//! [`Command`]: twilight_model::application::command::Command
+//! [`OptionValue`]: option::OptionValue
+//! [`twilight-rs`]: https://github.com/twilight-rs/twilight
+//! [codecov badge]: https://img.shields.io/codecov/c/gh/twilight-rs/twilight?logo=codecov&style=for-the-badge&token=E9ERLJL0L2
+//! [codecov link]: https://app.codecov.io/gh/twilight-rs/twilight/
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/main/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-1.53+-93450a.svg?style=for-the-badge&logo=rust
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(
+    clippy::all,
+    clippy::missing_const_for_fn,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    rustdoc::broken_intra_doc_links,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+pub mod error;
+pub mod option;
+pub mod router;
+pub mod sync;
+
+mod command;
+
+pub use self::{
+    command::SlashCommand,
+    error::{ParseError, ParseErrorType},
+    router::Router,
+};
+
+/// Derive [`SlashCommand`](trait@SlashCommand) on a struct or enum.
+///
+/// Refer to the [crate-level documentation](crate) for the supported
+/// attributes.
+pub use twilight_slash_command_derive::SlashCommand;
+
+/// Derive [`OptionValue`](option::OptionValue) on an enum of choices.
+///
+/// Refer to the [crate-level documentation](crate) for the supported
+/// attributes.
+pub use twilight_slash_command_derive::OptionChoices;
+
+/// Items used by the derive macros.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::{
+        command::{subcommand, subcommand_options},
+        option::{OptionData, OptionValue},
+        ParseError, SlashCommand,
+    };
+    pub use twilight_model::{
+        application::{
+            command::{Command, CommandOption, CommandOptionChoice, CommandOptionValue, Number},
+            interaction::application_command::{CommandDataOption, CommandInteractionDataResolved},
+        },
+        channel::ChannelType,
+    };
+
+    use crate::{error::ParseErrorType, option};
+    use std::fmt::Display;
+    use twilight_model::{
+        application::{
+            command::{CommandOptionType, CommandType},
+            interaction::application_command::CommandOptionValue as DataValue,
+        },
+        id::CommandVersionId,
+    };
+
+    /// Create the definition of a chat input command.
+    pub fn command(
+        name: &str,
+        description: &str,
+        default_permission: Option<bool>,
+        options: Vec<CommandOption>,
+    ) -> Command {
+        Command {
+            application_id: None,
+            default_permission,
+            description: description.to_owned(),
+            guild_id: None,
+            id: None,
+            kind: CommandType::ChatInput,
+            name: name.to_owned(),
+            options,
+            version: CommandVersionId::new(1).expect("non zero"),
+        }
+    }
+
+    /// Parse the value of a required option.
+    pub fn required<T: OptionValue>(
+        options: &[CommandDataOption],
+        name: &str,
+        resolved: Option<&CommandInteractionDataResolved>,
+    ) -> Result<T, ParseError> {
+        optional(options, name, resolved)?.ok_or_else(|| {
+            ParseError::new(ParseErrorType::OptionMissing {
+                name: name.to_owned(),
+            })
+        })
+    }
+
+    /// Parse the value of an optional option.
+    pub fn optional<T: OptionValue>(
+        options: &[CommandDataOption],
+        name: &str,
+        resolved: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Option<T>, ParseError> {
+        options
+            .iter()
+            .find(|option| option.name == name)
+            .map(|option| T::from_option(option, resolved))
+            .transpose()
+    }
+
+    /// Value of an integer choice option.
+    pub fn choice_integer(option: &CommandDataOption) -> Result<i64, ParseError> {
+        match option.value {
+            DataValue::Integer(value) => Ok(value),
+            _ => Err(option::option_type(option, CommandOptionType::Integer)),
+        }
+    }
+
+    /// Value of a string choice option.
+    pub fn choice_string(option: &CommandDataOption) -> Result<&str, ParseError> {
+        match &option.value {
+            DataValue::String(value) => Ok(value),
+            _ => Err(option::option_type(option, CommandOptionType::String)),
+        }
+    }
+
+    /// Error for a choice option with an unknown value.
+    pub fn choice_unknown(option: &CommandDataOption, value: &dyn Display) -> ParseError {
+        ParseError::new(ParseErrorType::ChoiceUnknown {
+            name: option.name.clone(),
+            value: value.to_string(),
+        })
+    }
+
+    /// Error for a subcommand that isn't part of the command.
+    pub fn subcommand_unknown(name: &str) -> ParseError {
+        ParseError::new(ParseErrorType::SubcommandUnknown {
+            name: name.to_owned(),
+        })
+    }
+}
@@ -0,0 +1,325 @@
+//! Values of command options.
+//!
+//! Types implementing [`OptionValue`] can be used as fields of commands
+//! deriving [`SlashCommand`]. Implementations are provided for strings,
+//! integers, numbers, booleans, IDs, and users, roles, and channels resolved
+//! by Discord. Enums with a fixed set of choices can derive [`OptionChoices`]
+//! to implement it.
+//!
+//! [`OptionChoices`]: crate::OptionChoices
+//! [`SlashCommand`]: crate::SlashCommand
+
+use crate::error::{ParseError, ParseErrorType};
+use twilight_model::{
+    application::{
+        command::{
+            BaseCommandOptionData, ChannelCommandOptionData, ChoiceCommandOptionData,
+            CommandOption, CommandOptionChoice, CommandOptionType,
+            CommandOptionValue as CommandOptionValueLimit, NumberCommandOptionData,
+        },
+        interaction::application_command::{
+            CommandDataOption, CommandInteractionDataResolved, CommandOptionValue,
+            InteractionChannel,
+        },
+    },
+    channel::ChannelType,
+    guild::Role,
+    id::{ChannelId, GenericId, RoleId, UserId},
+    user::User,
+};
+
+/// Definition of an option, independent of its type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptionData {
+    /// Whether the option supports autocomplete.
+    ///
+    /// Only applies to string, integer, and number options.
+    pub autocomplete: bool,
+    /// Types of channels that can be selected.
+    ///
+    /// Only applies to channel options. All types can be selected if empty.
+    pub channel_types: Vec<ChannelType>,
+    /// Description of the option.
+    pub description: String,
+    /// Maximum value of the option.
+    ///
+    /// Only applies to integer and number options.
+    pub max_value: Option<CommandOptionValueLimit>,
+    /// Minimum value of the option.
+    ///
+    /// Only applies to integer and number options.
+    pub min_value: Option<CommandOptionValueLimit>,
+    /// Name of the option.
+    pub name: String,
+    /// Whether the option is required.
+    pub required: bool,
+}
+
+impl OptionData {
+    /// Create a [`CommandOption::String`] with choices.
+    pub fn into_string(self, choices: Vec<CommandOptionChoice>) -> CommandOption {
+        CommandOption::String(ChoiceCommandOptionData {
+            autocomplete: self.autocomplete,
+            choices,
+            description: self.description,
+            name: self.name,
+            required: self.required,
+        })
+    }
+
+    /// Create a [`CommandOption::Integer`] with choices.
+    pub fn into_integer(self, choices: Vec<CommandOptionChoice>) -> CommandOption {
+        CommandOption::Integer(self.into_number_data(choices))
+    }
+
+    /// Create a [`CommandOption::Number`] with choices.
+    pub fn into_number(self, choices: Vec<CommandOptionChoice>) -> CommandOption {
+        CommandOption::Number(self.into_number_data(choices))
+    }
+
+    fn into_base_data(self) -> BaseCommandOptionData {
+        BaseCommandOptionData {
+            description: self.description,
+            name: self.name,
+            required: self.required,
+        }
+    }
+
+    fn into_number_data(self, choices: Vec<CommandOptionChoice>) -> NumberCommandOptionData {
+        NumberCommandOptionData {
+            autocomplete: self.autocomplete,
+            choices,
+            description: self.description,
+            max_value: self.max_value,
+            min_value: self.min_value,
+            name: self.name,
+            required: self.required,
+        }
+    }
+}
+
+/// Value of a command option.
+pub trait OptionValue: Sized {
+    /// Create the definition of the option.
+    fn create_option(data: OptionData) -> CommandOption;
+
+    /// Parse the value of an option received in an interaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`ParseErrorType::OptionType`] if the option
+    /// has a different type.
+    ///
+    /// Returns an error of type [`ParseErrorType::ResolvedMissing`] if the
+    /// value references data that Discord didn't resolve.
+    fn from_option(
+        option: &CommandDataOption,
+        resolved: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseError>;
+}
+
+/// Error for an option having a different type than expected.
+pub(crate) fn option_type(option: &CommandDataOption, expected: CommandOptionType) -> ParseError {
+    ParseError::new(ParseErrorType::OptionType {
+        expected,
+        found: option.value.kind(),
+        name: option.name.clone(),
+    })
+}
+
+/// Error for an option referencing data that wasn't resolved.
+fn resolved_missing(option: &CommandDataOption) -> ParseError {
+    ParseError::new(ParseErrorType::ResolvedMissing {
+        name: option.name.clone(),
+    })
+}
+
+/// Implement [`OptionValue`] for a type mapping directly to an option value.
+macro_rules! impl_value {
+    ($ty: ty, $kind: ident, |$data: ident| $create: expr, |$value: ident| $convert: expr) => {
+        impl OptionValue for $ty {
+            fn create_option($data: OptionData) -> CommandOption {
+                $create
+            }
+
+            fn from_option(
+                option: &CommandDataOption,
+                _: Option<&CommandInteractionDataResolved>,
+            ) -> Result<Self, ParseError> {
+                match &option.value {
+                    CommandOptionValue::$kind($value) => Ok($convert),
+                    _ => Err(option_type(option, CommandOptionType::$kind)),
+                }
+            }
+        }
+    };
+}
+
+impl_value!(
+    bool,
+    Boolean,
+    |data| CommandOption::Boolean(data.into_base_data()),
+    |value| *value
+);
+impl_value!(ChannelId, Channel, |data| channel_option(data), |id| *id);
+impl_value!(
+    GenericId,
+    Mentionable,
+    |data| CommandOption::Mentionable(data.into_base_data()),
+    |id| *id
+);
+impl_value!(
+    i64,
+    Integer,
+    |data| data.into_integer(Vec::new()),
+    |value| *value
+);
+impl_value!(f64, Number, |data| data.into_number(Vec::new()), |value| {
+    value.0
+});
+impl_value!(
+    RoleId,
+    Role,
+    |data| CommandOption::Role(data.into_base_data()),
+    |id| *id
+);
+impl_value!(
+    String,
+    String,
+    |data| data.into_string(Vec::new()),
+    |value| value.clone()
+);
+impl_value!(
+    UserId,
+    User,
+    |data| CommandOption::User(data.into_base_data()),
+    |id| *id
+);
+
+impl OptionValue for InteractionChannel {
+    fn create_option(data: OptionData) -> CommandOption {
+        channel_option(data)
+    }
+
+    fn from_option(
+        option: &CommandDataOption,
+        resolved: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseError> {
+        let id = ChannelId::from_option(option, resolved)?;
+
+        resolved
+            .and_then(|resolved| resolved.channels.get(&id))
+            .cloned()
+            .ok_or_else(|| resolved_missing(option))
+    }
+}
+
+impl OptionValue for Role {
+    fn create_option(data: OptionData) -> CommandOption {
+        RoleId::create_option(data)
+    }
+
+    fn from_option(
+        option: &CommandDataOption,
+        resolved: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseError> {
+        let id = RoleId::from_option(option, resolved)?;
+
+        resolved
+            .and_then(|resolved| resolved.roles.get(&id))
+            .cloned()
+            .ok_or_else(|| resolved_missing(option))
+    }
+}
+
+impl OptionValue for User {
+    fn create_option(data: OptionData) -> CommandOption {
+        UserId::create_option(data)
+    }
+
+    fn from_option(
+        option: &CommandDataOption,
+        resolved: Option<&CommandInteractionDataResolved>,
+    ) -> Result<Self, ParseError> {
+        let id = UserId::from_option(option, resolved)?;
+
+        resolved
+            .and_then(|resolved| resolved.users.get(&id))
+            .cloned()
+            .ok_or_else(|| resolved_missing(option))
+    }
+}
+
+fn channel_option(data: OptionData) -> CommandOption {
+    CommandOption::Channel(ChannelCommandOptionData {
+        channel_types: data.channel_types,
+        description: data.description,
+        name: data.name,
+        required: data.required,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OptionData, OptionValue};
+    use static_assertions::assert_fields;
+    use twilight_model::{
+        application::{
+            command::{CommandOption, CommandOptionType},
+            interaction::application_command::{CommandDataOption, CommandOptionValue},
+        },
+        channel::ChannelType,
+        id::{ChannelId, UserId},
+    };
+
+    assert_fields!(
+        OptionData: autocomplete,
+        channel_types,
+        description,
+        max_value,
+        min_value,
+        name,
+        required
+    );
+
+    fn data() -> OptionData {
+        OptionData {
+            autocomplete: false,
+            channel_types: vec![ChannelType::GuildText],
+            description: "description".to_owned(),
+            max_value: None,
+            min_value: None,
+            name: "name".to_owned(),
+            required: true,
+        }
+    }
+
+    #[test]
+    fn test_create_option() {
+        assert!(matches!(
+            ChannelId::create_option(data()),
+            CommandOption::Channel(data) if data.channel_types == [ChannelType::GuildText]
+        ));
+        assert!(matches!(
+            i64::create_option(data()),
+            CommandOption::Integer(data) if data.required
+        ));
+        assert_eq!(
+            CommandOptionType::User,
+            UserId::create_option(data()).kind()
+        );
+    }
+
+    #[test]
+    fn test_from_option() {
+        let option = CommandDataOption {
+            focused: false,
+            name: "count".to_owned(),
+            value: CommandOptionValue::Integer(5),
+        };
+
+        assert_eq!(5, i64::from_option(&option, None).unwrap());
+        assert!(String::from_option(&option, None).is_err());
+        assert!(twilight_model::user::User::from_option(&option, None).is_err());
+    }
+}
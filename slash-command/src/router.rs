@@ -0,0 +1,220 @@
+//! Route command interactions to handlers.
+
+use crate::{command::SlashCommand, error::ParseError};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+};
+use twilight_model::application::{command::Command, interaction::ApplicationCommand};
+
+/// Handler of a command, parsing the command before returning the future
+/// handling it.
+type Handler = Box<
+    dyn Fn(ApplicationCommand) -> Result<Pin<Box<dyn Future<Output = ()> + Send>>, ParseError>
+        + Send
+        + Sync,
+>;
+
+/// Dispatching an interaction to a handler failed.
+#[derive(Debug)]
+pub struct DispatchError {
+    kind: DispatchErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl DispatchError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &DispatchErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (DispatchErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for DispatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            DispatchErrorType::CommandUnknown { name } => {
+                f.write_str("no handler is registered for command '")?;
+                f.write_str(name)?;
+
+                f.write_str("'")
+            }
+            DispatchErrorType::Parsing => f.write_str("failed to parse the command"),
+        }
+    }
+}
+
+impl Error for DispatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`DispatchError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DispatchErrorType {
+    /// No handler is registered for the command.
+    CommandUnknown {
+        /// Name of the command.
+        name: String,
+    },
+    /// Parsing the command failed.
+    ///
+    /// The source is a [`ParseError`].
+    Parsing,
+}
+
+/// Command and its handler.
+struct Route {
+    /// Definition of the command.
+    command: Command,
+    /// Handler of the command.
+    handler: Handler,
+}
+
+/// Router of command interactions to handlers by command name.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_slash_command::{Router, SlashCommand};
+///
+/// /// Check if the bot is alive.
+/// #[derive(SlashCommand)]
+/// #[command(name = "ping")]
+/// struct Ping;
+///
+/// let mut router = Router::new();
+/// router.command(|interaction, _: Ping| async move {
+///     println!("ping in channel {}", interaction.channel_id);
+/// });
+///
+/// assert_eq!(1, router.commands().len());
+/// ```
+#[derive(Default)]
+pub struct Router {
+    /// Routes by command name.
+    routes: HashMap<&'static str, Route>,
+}
+
+impl Router {
+    /// Create a new router without any commands.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a handler for a command.
+    ///
+    /// A previously registered handler for a command with the same name is
+    /// replaced.
+    pub fn command<C, F, Fut>(&mut self, handler: F) -> &mut Self
+    where
+        C: SlashCommand + Send + 'static,
+        F: Fn(ApplicationCommand, C) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handler: Handler = Box::new(move |interaction: ApplicationCommand| {
+            let command = C::from_data(&interaction.data)?;
+
+            Ok(Box::pin(handler(interaction, command)))
+        });
+
+        self.routes.insert(
+            C::NAME,
+            Route {
+                command: C::create_command(),
+                handler,
+            },
+        );
+
+        self
+    }
+
+    /// Definitions of the registered commands, sorted by name.
+    ///
+    /// These can be synchronized with Discord via [`sync_global_commands`] or
+    /// [`sync_guild_commands`].
+    ///
+    /// [`sync_global_commands`]: crate::sync::sync_global_commands
+    /// [`sync_guild_commands`]: crate::sync::sync_guild_commands
+    pub fn commands(&self) -> Vec<Command> {
+        let mut commands = self
+            .routes
+            .values()
+            .map(|route| route.command.clone())
+            .collect::<Vec<_>>();
+        commands.sort_by(|a, b| a.name.cmp(&b.name));
+
+        commands
+    }
+
+    /// Parse a command interaction and call the handler of the command.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`DispatchErrorType::CommandUnknown`] if no
+    /// handler is registered for the command.
+    ///
+    /// Returns an error of type [`DispatchErrorType::Parsing`] if the command
+    /// couldn't be parsed out of the interaction.
+    pub async fn dispatch(&self, interaction: ApplicationCommand) -> Result<(), DispatchError> {
+        let route = match self.routes.get(interaction.data.name.as_str()) {
+            Some(route) => route,
+            None => {
+                return Err(DispatchError {
+                    kind: DispatchErrorType::CommandUnknown {
+                        name: interaction.data.name,
+                    },
+                    source: None,
+                })
+            }
+        };
+
+        let future = (route.handler)(interaction).map_err(|source| DispatchError {
+            kind: DispatchErrorType::Parsing,
+            source: Some(Box::new(source)),
+        })?;
+
+        future.await;
+
+        Ok(())
+    }
+}
+
+impl Debug for Router {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Router")
+            .field("commands", &self.routes.keys())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DispatchError, DispatchErrorType, Router};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+
+    assert_fields!(DispatchErrorType::CommandUnknown: name);
+    assert_impl_all!(DispatchErrorType: Debug, Send, Sync);
+    assert_impl_all!(DispatchError: Error, Send, Sync);
+    assert_impl_all!(Router: Debug, Default, Send, Sync);
+}
@@ -0,0 +1,295 @@
+//! Synchronize command definitions with Discord.
+//!
+//! [`diff`] compares command definitions with the commands registered with
+//! Discord. [`sync_global_commands`] and [`sync_guild_commands`] retrieve the
+//! registered commands and only overwrite them if they differ, avoiding
+//! needless requests and rate limits on every startup.
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::application::command::Command;
+
+#[cfg(feature = "sync")]
+use twilight_http::Client;
+#[cfg(feature = "sync")]
+use twilight_model::id::GuildId;
+
+/// Synchronizing commands with Discord failed.
+#[derive(Debug)]
+pub struct SyncError {
+    kind: SyncErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl SyncError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &SyncErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (SyncErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    #[cfg(feature = "sync")]
+    fn deserializing(source: impl Error + Send + Sync + 'static) -> Self {
+        Self {
+            kind: SyncErrorType::Deserializing,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    #[cfg(feature = "sync")]
+    fn requesting(source: impl Error + Send + Sync + 'static) -> Self {
+        Self {
+            kind: SyncErrorType::Requesting,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for SyncError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            SyncErrorType::Deserializing => {
+                f.write_str("failed to deserialize the registered commands")
+            }
+            SyncErrorType::Requesting => f.write_str("failed to request the commands"),
+        }
+    }
+}
+
+impl Error for SyncError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`SyncError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SyncErrorType {
+    /// Deserializing the registered commands failed.
+    Deserializing,
+    /// Retrieving or setting the commands failed.
+    Requesting,
+}
+
+/// Differences between command definitions and the registered commands.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CommandDiff {
+    added: Vec<String>,
+    removed: Vec<String>,
+    updated: Vec<String>,
+}
+
+impl CommandDiff {
+    /// Names of the commands that aren't registered.
+    pub fn added(&self) -> &[String] {
+        &self.added
+    }
+
+    /// Whether the registered commands match the definitions.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.updated.is_empty()
+    }
+
+    /// Names of the registered commands that aren't defined.
+    pub fn removed(&self) -> &[String] {
+        &self.removed
+    }
+
+    /// Names of the registered commands that differ from their definition.
+    pub fn updated(&self) -> &[String] {
+        &self.updated
+    }
+}
+
+/// Compare the registered commands with command definitions.
+///
+/// Commands are matched by name and type. IDs, versions, and other fields set
+/// by Discord are ignored.
+pub fn diff(registered: &[Command], commands: &[Command]) -> CommandDiff {
+    let mut registered = registered
+        .iter()
+        .map(|command| ((command.kind, command.name.as_str()), command))
+        .collect::<HashMap<_, _>>();
+    let mut diff = CommandDiff::default();
+
+    for command in commands {
+        match registered.remove(&(command.kind, command.name.as_str())) {
+            Some(existing) if !same_definition(existing, command) => {
+                diff.updated.push(command.name.clone());
+            }
+            Some(_) => {}
+            None => diff.added.push(command.name.clone()),
+        }
+    }
+
+    diff.removed = registered
+        .into_iter()
+        .map(|((_, name), _)| name.to_owned())
+        .collect();
+    diff.added.sort();
+    diff.removed.sort();
+    diff.updated.sort();
+
+    diff
+}
+
+/// Whether two commands have the same user-facing definition.
+fn same_definition(registered: &Command, command: &Command) -> bool {
+    registered.default_permission.unwrap_or(true) == command.default_permission.unwrap_or(true)
+        && registered.description == command.description
+        && registered.options == command.options
+}
+
+/// Synchronize global commands, only setting them if they differ from the
+/// registered commands.
+///
+/// Returns the differences that were found, which are empty if the commands
+/// weren't set.
+///
+/// # Errors
+///
+/// Returns an error of type [`SyncErrorType::Requesting`] if the application
+/// ID isn't set on the client or a request failed.
+///
+/// Returns an error of type [`SyncErrorType::Deserializing`] if the
+/// registered commands couldn't be deserialized.
+#[cfg(feature = "sync")]
+pub async fn sync_global_commands(
+    http: &Client,
+    commands: &[Command],
+) -> Result<CommandDiff, SyncError> {
+    let registered = http
+        .get_global_commands()
+        .map_err(SyncError::requesting)?
+        .exec()
+        .await
+        .map_err(SyncError::requesting)?
+        .models()
+        .await
+        .map_err(SyncError::deserializing)?;
+    let diff = diff(&registered, commands);
+
+    if !diff.is_empty() {
+        http.set_global_commands(commands)
+            .map_err(SyncError::requesting)?
+            .exec()
+            .await
+            .map_err(SyncError::requesting)?;
+    }
+
+    Ok(diff)
+}
+
+/// Synchronize the commands of a guild, only setting them if they differ from
+/// the registered commands.
+///
+/// Returns the differences that were found, which are empty if the commands
+/// weren't set.
+///
+/// # Errors
+///
+/// Returns an error of type [`SyncErrorType::Requesting`] if the application
+/// ID isn't set on the client or a request failed.
+///
+/// Returns an error of type [`SyncErrorType::Deserializing`] if the
+/// registered commands couldn't be deserialized.
+#[cfg(feature = "sync")]
+pub async fn sync_guild_commands(
+    http: &Client,
+    guild_id: GuildId,
+    commands: &[Command],
+) -> Result<CommandDiff, SyncError> {
+    let registered = http
+        .get_guild_commands(guild_id)
+        .map_err(SyncError::requesting)?
+        .exec()
+        .await
+        .map_err(SyncError::requesting)?
+        .models()
+        .await
+        .map_err(SyncError::deserializing)?;
+    let diff = diff(&registered, commands);
+
+    if !diff.is_empty() {
+        http.set_guild_commands(guild_id, commands)
+            .map_err(SyncError::requesting)?
+            .exec()
+            .await
+            .map_err(SyncError::requesting)?;
+    }
+
+    Ok(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandDiff, SyncError, SyncErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        application::command::{Command, CommandType},
+        id::{ApplicationId, CommandId},
+    };
+
+    assert_impl_all!(CommandDiff: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(SyncErrorType: Debug, Send, Sync);
+    assert_impl_all!(SyncError: Error, Send, Sync);
+
+    fn command(name: &str, description: &str) -> Command {
+        crate::__private::command(name, description, None, Vec::new())
+    }
+
+    #[test]
+    fn test_diff() {
+        let mut registered = vec![
+            command("ban", "Ban a user"),
+            command("kick", "Kick a user"),
+            command("old", "Removed command"),
+        ];
+
+        for (idx, command) in registered.iter_mut().enumerate() {
+            command.application_id = ApplicationId::new(1);
+            command.default_permission = Some(true);
+            command.id = CommandId::new(idx as u64 + 1);
+        }
+
+        let commands = [
+            command("ban", "Ban a user"),
+            command("kick", "Kick a member"),
+            command("new", "Added command"),
+        ];
+        let diff = super::diff(&registered, &commands);
+
+        assert_eq!(["new"], diff.added());
+        assert_eq!(["old"], diff.removed());
+        assert_eq!(["kick"], diff.updated());
+        assert!(!diff.is_empty());
+
+        assert!(super::diff(&commands, &commands).is_empty());
+
+        let mut user_command = command("ban", "");
+        user_command.kind = CommandType::User;
+        assert_eq!(
+            ["ban"],
+            super::diff(&[user_command], &commands[..1]).added()
+        );
+    }
+}
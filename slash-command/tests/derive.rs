@@ -0,0 +1,309 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+use twilight_model::{
+    application::{
+        command::{CommandOption, CommandOptionChoice, CommandOptionValue as Limit},
+        interaction::{
+            application_command::{CommandData, CommandDataOption, CommandOptionValue},
+            ApplicationCommand, InteractionType,
+        },
+    },
+    channel::ChannelType,
+    id::{ApplicationId, ChannelId, CommandId, GuildId, InteractionId, UserId},
+};
+use twilight_slash_command::{
+    router::DispatchErrorType, OptionChoices, ParseErrorType, Router, SlashCommand,
+};
+
+#[derive(Clone, Copy, Debug, Eq, OptionChoices, PartialEq)]
+enum Unit {
+    #[choice(name = "Seconds", value = "s")]
+    Seconds,
+    Minutes,
+}
+
+#[derive(Clone, Copy, Debug, Eq, OptionChoices, PartialEq)]
+enum Days {
+    #[choice(name = "None", value = 0)]
+    Zero,
+    #[choice(name = "Week", value = 7)]
+    Week,
+}
+
+/// Ban a user.
+///
+/// This paragraph isn't part of the description.
+#[derive(Debug, PartialEq, SlashCommand)]
+#[command(name = "ban", default_permission = false)]
+struct Ban {
+    /// User to ban.
+    user: UserId,
+    #[command(rename = "days", desc = "Days of messages to delete")]
+    delete_days: Option<Days>,
+    /// Length of the ban.
+    #[command(min_value = 1, max_value = 60)]
+    length: Option<i64>,
+    /// Unit of the length.
+    unit: Option<Unit>,
+}
+
+/// Set the log channel.
+#[derive(Debug, PartialEq, SlashCommand)]
+#[command(name = "channel")]
+struct LogChannel {
+    /// Channel to log to.
+    #[command(channel_types(GuildText, GuildNews))]
+    channel: ChannelId,
+}
+
+/// Disable logging.
+#[derive(Debug, PartialEq, SlashCommand)]
+#[command(name = "disable")]
+struct LogDisable;
+
+/// Configure logging.
+#[derive(Debug, PartialEq, SlashCommand)]
+#[command(name = "log")]
+enum Log {
+    Channel(LogChannel),
+    Disable(LogDisable),
+}
+
+/// Configure the bot.
+#[derive(Debug, PartialEq, SlashCommand)]
+#[command(name = "config")]
+enum Config {
+    Log(Log),
+}
+
+fn option(name: &str, value: CommandOptionValue) -> CommandDataOption {
+    CommandDataOption {
+        focused: false,
+        name: name.to_owned(),
+        value,
+    }
+}
+
+fn data(name: &str, options: Vec<CommandDataOption>) -> CommandData {
+    CommandData {
+        id: CommandId::new(1).expect("non zero"),
+        name: name.to_owned(),
+        options,
+        resolved: None,
+    }
+}
+
+#[test]
+fn test_create_struct() {
+    let command = Ban::create_command();
+
+    assert_eq!("ban", command.name);
+    assert_eq!("Ban a user.", command.description);
+    assert_eq!(Some(false), command.default_permission);
+    assert_eq!(4, command.options.len());
+
+    assert!(matches!(
+        &command.options[0],
+        CommandOption::User(data) if data.required && data.description == "User to ban."
+    ));
+    assert!(matches!(
+        &command.options[1],
+        CommandOption::Integer(data) if data.name == "days"
+            && !data.required
+            && data.choices == [
+                CommandOptionChoice::Int { name: "None".to_owned(), value: 0 },
+                CommandOptionChoice::Int { name: "Week".to_owned(), value: 7 },
+            ]
+    ));
+    assert!(matches!(
+        &command.options[2],
+        CommandOption::Integer(data) if data.min_value == Some(Limit::Integer(1))
+            && data.max_value == Some(Limit::Integer(60))
+    ));
+    assert!(matches!(
+        &command.options[3],
+        CommandOption::String(data) if data.choices == [
+            CommandOptionChoice::String { name: "Seconds".to_owned(), value: "s".to_owned() },
+            CommandOptionChoice::String { name: "Minutes".to_owned(), value: "Minutes".to_owned() },
+        ]
+    ));
+}
+
+#[test]
+fn test_create_enum() {
+    let command = Config::create_command();
+    assert_eq!(1, command.options.len());
+
+    let log = match &command.options[0] {
+        CommandOption::SubCommandGroup(data) => data,
+        other => panic!("expected a subcommand group: {:?}", other),
+    };
+    assert_eq!("log", log.name);
+
+    assert!(matches!(
+        &log.options[0],
+        CommandOption::SubCommand(data) if data.name == "channel" && matches!(
+            &data.options[0],
+            CommandOption::Channel(channel)
+                if channel.channel_types == [ChannelType::GuildText, ChannelType::GuildNews]
+        )
+    ));
+    assert!(matches!(
+        &log.options[1],
+        CommandOption::SubCommand(data) if data.name == "disable" && data.options.is_empty()
+    ));
+}
+
+#[test]
+fn test_parse_struct() {
+    let user_id = UserId::new(2).expect("non zero");
+    let ban = Ban::from_data(&data(
+        "ban",
+        vec![
+            option("user", CommandOptionValue::User(user_id)),
+            option("days", CommandOptionValue::Integer(7)),
+            option("unit", CommandOptionValue::String("s".to_owned())),
+        ],
+    ))
+    .unwrap();
+
+    assert_eq!(
+        Ban {
+            user: user_id,
+            delete_days: Some(Days::Week),
+            length: None,
+            unit: Some(Unit::Seconds),
+        },
+        ban
+    );
+
+    let error = Ban::from_data(&data("ban", Vec::new())).unwrap_err();
+    assert_eq!(
+        &ParseErrorType::OptionMissing {
+            name: "user".to_owned()
+        },
+        error.kind()
+    );
+
+    let error = Ban::from_data(&data(
+        "ban",
+        vec![
+            option("user", CommandOptionValue::User(user_id)),
+            option("unit", CommandOptionValue::String("h".to_owned())),
+        ],
+    ))
+    .unwrap_err();
+    assert_eq!(
+        &ParseErrorType::ChoiceUnknown {
+            name: "unit".to_owned(),
+            value: "h".to_owned()
+        },
+        error.kind()
+    );
+}
+
+#[test]
+fn test_parse_enum() {
+    let channel_id = ChannelId::new(3).expect("non zero");
+    let config = Config::from_data(&data(
+        "config",
+        vec![option(
+            "log",
+            CommandOptionValue::SubCommandGroup(vec![option(
+                "channel",
+                CommandOptionValue::SubCommand(vec![option(
+                    "channel",
+                    CommandOptionValue::Channel(channel_id),
+                )]),
+            )]),
+        )],
+    ))
+    .unwrap();
+
+    assert_eq!(
+        Config::Log(Log::Channel(LogChannel {
+            channel: channel_id
+        })),
+        config
+    );
+
+    let error = Log::from_data(&data(
+        "log",
+        vec![option("enable", CommandOptionValue::SubCommand(Vec::new()))],
+    ))
+    .unwrap_err();
+    assert_eq!(
+        &ParseErrorType::SubcommandUnknown {
+            name: "enable".to_owned()
+        },
+        error.kind()
+    );
+}
+
+#[tokio::test]
+async fn test_router() {
+    let calls = Arc::new(AtomicU64::new(0));
+    let mut router = Router::new();
+
+    let handler_calls = Arc::clone(&calls);
+    router
+        .command(move |_, log: Log| {
+            let calls = Arc::clone(&handler_calls);
+
+            async move {
+                assert_eq!(Log::Disable(LogDisable), log);
+                calls.fetch_add(1, Ordering::Relaxed);
+            }
+        })
+        .command(|_, _: Ban| async {});
+
+    assert_eq!(
+        vec!["ban", "log"],
+        router
+            .commands()
+            .iter()
+            .map(|command| command.name.as_str())
+            .collect::<Vec<_>>()
+    );
+
+    let interaction = |data| ApplicationCommand {
+        application_id: ApplicationId::new(1).expect("non zero"),
+        channel_id: ChannelId::new(2).expect("non zero"),
+        data,
+        guild_id: GuildId::new(3),
+        id: InteractionId::new(4).expect("non zero"),
+        kind: InteractionType::ApplicationCommand,
+        member: None,
+        token: "token".to_owned(),
+        user: None,
+    };
+
+    router
+        .dispatch(interaction(data(
+            "log",
+            vec![option(
+                "disable",
+                CommandOptionValue::SubCommand(Vec::new()),
+            )],
+        )))
+        .await
+        .unwrap();
+    assert_eq!(1, calls.load(Ordering::Relaxed));
+
+    let error = router
+        .dispatch(interaction(data("log", Vec::new())))
+        .await
+        .unwrap_err();
+    assert!(matches!(error.kind(), DispatchErrorType::Parsing));
+
+    let error = router
+        .dispatch(interaction(data("kick", Vec::new())))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        DispatchErrorType::CommandUnknown { name } if name == "kick"
+    ));
+}
@@ -2,7 +2,12 @@
 //!
 //! This is in its own file for better maintainability when a new event is added.
 
-use twilight_model::{channel::Channel, gateway::event::Event, id::GuildId};
+use twilight_model::{
+    application::interaction::ApplicationCommand,
+    channel::Channel,
+    gateway::event::Event,
+    id::{GuildId, UserId},
+};
 
 /// Retrieve the guild ID of an event if it took place in a guild.
 ///
@@ -75,7 +80,9 @@ pub const fn guild_id(event: &Event) -> Option<GuildId> {
         | Event::Resumed
         | Event::ShardConnected(_)
         | Event::ShardConnecting(_)
+        | Event::ShardDead(_)
         | Event::ShardDisconnected(_)
+        | Event::ShardGuildsReady(_)
         | Event::ShardIdentifying(_)
         | Event::ShardPayload(_)
         | Event::ShardReconnecting(_)
@@ -85,6 +92,24 @@ pub const fn guild_id(event: &Event) -> Option<GuildId> {
     }
 }
 
+/// Retrieve the ID of the user who invoked an application command.
+///
+/// This will first check for the member's user's ID and, if not present, then
+/// check the user's ID.
+pub const fn command_author_id(command: &ApplicationCommand) -> Option<UserId> {
+    if let Some(member) = &command.member {
+        if let Some(user) = &member.user {
+            return Some(user.id);
+        }
+    }
+
+    if let Some(user) = &command.user {
+        return Some(user.id);
+    }
+
+    None
+}
+
 /// Retrieve the guild ID of a channel if it's a [`GuildChannel`].
 ///
 /// [`GuildChannel`]: twilight_model::channel::GuildChannel
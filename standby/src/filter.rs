@@ -0,0 +1,311 @@
+//! Composable predicates for waiting on events.
+//!
+//! Filters are predicates over events that implement [`Filterable`], such as
+//! [`Event`], [`Interaction`], [`MessageCreate`], [`ReactionAdd`], and
+//! [`MessageComponentInteraction`]. They can be passed to any method of
+//! [`Standby`] taking a predicate and combined via [`and`], [`or`], and
+//! [`not`].
+//!
+//! # Examples
+//!
+//! Wait for a message in channel 123 by user 456 starting with "!":
+//!
+//! ```no_run
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use twilight_model::id::{ChannelId, UserId};
+//! use twilight_standby::{filter, Standby};
+//!
+//! let standby = Standby::new();
+//!
+//! let channel_id = ChannelId::new(123).expect("non zero");
+//! let user_id = UserId::new(456).expect("non zero");
+//!
+//! let message = standby
+//!     .wait_for_message(
+//!         channel_id,
+//!         filter::and(filter::author(user_id), filter::content_prefix("!")),
+//!     )
+//!     .await?;
+//! # Ok(()) }
+//! ```
+//!
+//! [`Standby`]: crate::Standby
+
+use twilight_model::{
+    application::interaction::{ApplicationCommand, Interaction, MessageComponentInteraction},
+    gateway::{
+        event::Event,
+        payload::incoming::{MessageCreate, ReactionAdd},
+    },
+    id::{ChannelId, GuildId, UserId},
+};
+
+/// Event with fields that filters can match on.
+pub trait Filterable {
+    /// ID of the user who caused the event, if any.
+    fn author_id(&self) -> Option<UserId>;
+
+    /// ID of the channel the event took place in, if any.
+    fn channel_id(&self) -> Option<ChannelId>;
+
+    /// Content of the message of the event, if any.
+    fn content(&self) -> Option<&str>;
+
+    /// ID of the guild the event took place in, if any.
+    fn guild_id(&self) -> Option<GuildId>;
+}
+
+impl Filterable for Event {
+    fn author_id(&self) -> Option<UserId> {
+        match self {
+            Self::InteractionCreate(e) => e.0.author_id(),
+            Self::MessageCreate(e) => Some(e.author.id),
+            Self::MessageUpdate(e) => e.author.as_ref().map(|author| author.id),
+            Self::ReactionAdd(e) => Some(e.user_id),
+            Self::ReactionRemove(e) => Some(e.user_id),
+            Self::TypingStart(e) => Some(e.user_id),
+            _ => None,
+        }
+    }
+
+    fn channel_id(&self) -> Option<ChannelId> {
+        match self {
+            Self::ChannelPinsUpdate(e) => Some(e.channel_id),
+            Self::InteractionCreate(e) => e.0.channel_id(),
+            Self::MessageCreate(e) => Some(e.channel_id),
+            Self::MessageDelete(e) => Some(e.channel_id),
+            Self::MessageDeleteBulk(e) => Some(e.channel_id),
+            Self::MessageUpdate(e) => Some(e.channel_id),
+            Self::ReactionAdd(e) => Some(e.channel_id),
+            Self::ReactionRemove(e) => Some(e.channel_id),
+            Self::ReactionRemoveAll(e) => Some(e.channel_id),
+            Self::ReactionRemoveEmoji(e) => Some(e.channel_id),
+            Self::TypingStart(e) => Some(e.channel_id),
+            _ => None,
+        }
+    }
+
+    fn content(&self) -> Option<&str> {
+        match self {
+            Self::InteractionCreate(e) => e.0.content(),
+            Self::MessageCreate(e) => Some(&e.content),
+            Self::MessageUpdate(e) => e.content.as_deref(),
+            _ => None,
+        }
+    }
+
+    fn guild_id(&self) -> Option<GuildId> {
+        crate::event::guild_id(self)
+    }
+}
+
+impl Filterable for ApplicationCommand {
+    fn author_id(&self) -> Option<UserId> {
+        crate::event::command_author_id(self)
+    }
+
+    fn channel_id(&self) -> Option<ChannelId> {
+        Some(self.channel_id)
+    }
+
+    fn content(&self) -> Option<&str> {
+        None
+    }
+
+    fn guild_id(&self) -> Option<GuildId> {
+        self.guild_id
+    }
+}
+
+impl Filterable for Interaction {
+    fn author_id(&self) -> Option<UserId> {
+        match self {
+            Self::ApplicationCommand(command) | Self::ApplicationCommandAutocomplete(command) => {
+                command.author_id()
+            }
+            Self::MessageComponent(component) => Filterable::author_id(&**component),
+            _ => None,
+        }
+    }
+
+    fn channel_id(&self) -> Option<ChannelId> {
+        match self {
+            Self::ApplicationCommand(command) | Self::ApplicationCommandAutocomplete(command) => {
+                Some(command.channel_id)
+            }
+            Self::MessageComponent(component) => Some(component.channel_id),
+            _ => None,
+        }
+    }
+
+    fn content(&self) -> Option<&str> {
+        match self {
+            Self::MessageComponent(component) => component.content(),
+            _ => None,
+        }
+    }
+
+    fn guild_id(&self) -> Option<GuildId> {
+        Interaction::guild_id(self)
+    }
+}
+
+impl Filterable for MessageCreate {
+    fn author_id(&self) -> Option<UserId> {
+        Some(self.author.id)
+    }
+
+    fn channel_id(&self) -> Option<ChannelId> {
+        Some(self.0.channel_id)
+    }
+
+    fn content(&self) -> Option<&str> {
+        Some(&self.0.content)
+    }
+
+    fn guild_id(&self) -> Option<GuildId> {
+        self.0.guild_id
+    }
+}
+
+impl Filterable for MessageComponentInteraction {
+    fn author_id(&self) -> Option<UserId> {
+        MessageComponentInteraction::author_id(self)
+    }
+
+    fn channel_id(&self) -> Option<ChannelId> {
+        Some(self.channel_id)
+    }
+
+    fn content(&self) -> Option<&str> {
+        Some(&self.message.content)
+    }
+
+    fn guild_id(&self) -> Option<GuildId> {
+        self.guild_id
+    }
+}
+
+impl Filterable for ReactionAdd {
+    fn author_id(&self) -> Option<UserId> {
+        Some(self.0.user_id)
+    }
+
+    fn channel_id(&self) -> Option<ChannelId> {
+        Some(self.0.channel_id)
+    }
+
+    fn content(&self) -> Option<&str> {
+        None
+    }
+
+    fn guild_id(&self) -> Option<GuildId> {
+        self.0.guild_id
+    }
+}
+
+/// Match events caused by a user.
+pub fn author<T: Filterable>(user_id: UserId) -> impl Fn(&T) -> bool + Send + Sync + 'static {
+    move |event| event.author_id() == Some(user_id)
+}
+
+/// Match events in a channel.
+pub fn channel<T: Filterable>(
+    channel_id: ChannelId,
+) -> impl Fn(&T) -> bool + Send + Sync + 'static {
+    move |event| event.channel_id() == Some(channel_id)
+}
+
+/// Match events with content starting with a prefix.
+pub fn content_prefix<T: Filterable>(
+    prefix: impl Into<String>,
+) -> impl Fn(&T) -> bool + Send + Sync + 'static {
+    let prefix = prefix.into();
+
+    move |event| {
+        event
+            .content()
+            .map_or(false, |content| content.starts_with(&prefix))
+    }
+}
+
+/// Match events in a guild.
+pub fn guild<T: Filterable>(guild_id: GuildId) -> impl Fn(&T) -> bool + Send + Sync + 'static {
+    move |event| event.guild_id() == Some(guild_id)
+}
+
+/// Match events matching both of two predicates.
+pub fn and<T>(
+    first: impl Fn(&T) -> bool + Send + Sync + 'static,
+    second: impl Fn(&T) -> bool + Send + Sync + 'static,
+) -> impl Fn(&T) -> bool + Send + Sync + 'static {
+    move |event| first(event) && second(event)
+}
+
+/// Match events matching either of two predicates.
+pub fn or<T>(
+    first: impl Fn(&T) -> bool + Send + Sync + 'static,
+    second: impl Fn(&T) -> bool + Send + Sync + 'static,
+) -> impl Fn(&T) -> bool + Send + Sync + 'static {
+    move |event| first(event) || second(event)
+}
+
+/// Match events not matching a predicate.
+pub fn not<T>(
+    predicate: impl Fn(&T) -> bool + Send + Sync + 'static,
+) -> impl Fn(&T) -> bool + Send + Sync + 'static {
+    move |event| !predicate(event)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Filterable;
+    use twilight_model::{
+        gateway::{event::Event, payload::incoming::MessageCreate},
+        id::{ChannelId, GuildId, UserId},
+    };
+
+    fn message() -> MessageCreate {
+        MessageCreate(crate::tests::message())
+    }
+
+    #[test]
+    fn test_filters() {
+        let message = message();
+        let author_id = UserId::new(2).expect("non zero");
+        let channel_id = ChannelId::new(1).expect("non zero");
+
+        assert!(super::author(author_id)(&message));
+        assert!(super::channel(channel_id)(&message));
+        assert!(super::guild(GuildId::new(4).expect("non zero"))(&message));
+        assert!(super::content_prefix("te")(&message));
+        assert!(!super::content_prefix("!")(&message));
+
+        assert!(super::and(
+            super::author(author_id),
+            super::content_prefix("t")
+        )(&message));
+        assert!(!super::and(
+            super::author(author_id),
+            super::content_prefix("!")
+        )(&message));
+        assert!(super::or(
+            super::content_prefix("!"),
+            super::channel(channel_id)
+        )(&message));
+        assert!(super::not(super::content_prefix("!"))(&message));
+    }
+
+    #[test]
+    fn test_event_fields() {
+        let event = Event::MessageCreate(Box::new(message()));
+
+        assert_eq!(Some(UserId::new(2).expect("non zero")), event.author_id());
+        assert_eq!(
+            Some(ChannelId::new(1).expect("non zero")),
+            event.channel_id()
+        );
+        assert_eq!(Some("test"), event.content());
+        assert_eq!(Some(GuildId::new(4).expect("non zero")), event.guild_id());
+    }
+}
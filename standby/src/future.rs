@@ -2,20 +2,29 @@
 //!
 //! [`Standby`]: super::Standby
 
-use futures_util::{future::FutureExt, stream::Stream};
+use crate::Remover;
+use futures_util::{
+    future::FutureExt,
+    ready,
+    stream::{Stream, StreamExt},
+};
 use std::{
     error::Error,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
     future::Future,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
-use tokio::sync::{
-    mpsc::UnboundedReceiver as MpscReceiver,
-    oneshot::{error::RecvError, Receiver},
+use tokio::{
+    sync::{
+        mpsc::UnboundedReceiver as MpscReceiver,
+        oneshot::{error::RecvError, Receiver},
+    },
+    time::{self, Sleep},
 };
 use twilight_model::{
-    application::interaction::MessageComponentInteraction,
+    application::interaction::{ApplicationCommand, Interaction, MessageComponentInteraction},
     gateway::{
         event::Event,
         payload::incoming::{MessageCreate, ReactionAdd},
@@ -24,25 +33,358 @@ use twilight_model::{
 
 /// Future canceled due to Standby being dropped.
 #[derive(Debug)]
-pub struct Canceled(RecvError);
+pub struct Canceled(Option<RecvError>);
 
 impl Canceled {
     /// Consume the error, returning the source error if there is any.
     #[allow(clippy::must_use_candidate)]
     pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
-        Some(Box::new(self.0))
+        self.0
+            .map(|source| Box::new(source) as Box<dyn Error + Send + Sync>)
     }
 }
 
 impl Display for Canceled {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        Display::fmt(&self.0, f)
+        match &self.0 {
+            Some(source) => Display::fmt(source, f),
+            None => f.write_str("channel closed"),
+        }
     }
 }
 
 impl Error for Canceled {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        Some(&self.0)
+        self.0
+            .as_ref()
+            .map(|source| source as &(dyn Error + 'static))
+    }
+}
+
+/// Waiting for an event with a timeout failed.
+#[derive(Debug)]
+pub struct WaitError {
+    /// Type of error.
+    kind: WaitErrorType,
+    /// Source error if there is any.
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl WaitError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &WaitErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (WaitErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for WaitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            WaitErrorType::Canceled => f.write_str("standby was dropped"),
+            WaitErrorType::TimedOut => f.write_str("no matching event was received in time"),
+        }
+    }
+}
+
+impl Error for WaitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+impl From<Canceled> for WaitError {
+    fn from(source: Canceled) -> Self {
+        Self {
+            kind: WaitErrorType::Canceled,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+/// Type of [`WaitError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum WaitErrorType {
+    /// The associated [`Standby`] instance was dropped.
+    ///
+    /// [`Standby`]: crate::Standby
+    Canceled,
+    /// No matching event was received before the timeout elapsed.
+    TimedOut,
+}
+
+/// Future or stream that stops waiting once a duration has elapsed.
+///
+/// When the duration elapses the bystander is removed from the [`Standby`]
+/// instance. Futures resolve to a [`WaitErrorType::TimedOut`] error and
+/// streams end.
+///
+/// Created via the `timeout` method of the futures and streams returned by
+/// [`Standby`].
+///
+/// [`Standby`]: crate::Standby
+#[derive(Debug)]
+#[must_use = "futures and streams do nothing unless you poll them"]
+pub struct Timeout<T> {
+    /// Future or stream being waited on.
+    inner: T,
+    /// Remover of the bystander, called once the duration elapses.
+    remover: Remover,
+    /// Sleep until the duration elapses.
+    sleep: Pin<Box<Sleep>>,
+}
+
+impl<T> Timeout<T> {
+    /// Create a new timeout around a future or stream.
+    fn new(inner: T, remover: Remover, duration: Duration) -> Self {
+        Self {
+            inner,
+            remover,
+            sleep: Box::pin(time::sleep(duration)),
+        }
+    }
+
+    /// Whether the duration has elapsed, removing the bystander if it has.
+    fn poll_elapsed(&mut self, cx: &mut Context<'_>) -> bool {
+        if self.sleep.poll_unpin(cx).is_pending() {
+            return false;
+        }
+
+        self.remover.remove();
+
+        true
+    }
+}
+
+impl<T, F: Future<Output = Result<T, Canceled>> + Unpin> Future for Timeout<F> {
+    type Output = Result<T, WaitError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Poll::Ready(result) = self.inner.poll_unpin(cx) {
+            return Poll::Ready(result.map_err(WaitError::from));
+        }
+
+        if self.poll_elapsed(cx) {
+            return Poll::Ready(Err(WaitError {
+                kind: WaitErrorType::TimedOut,
+                source: None,
+            }));
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<S: Stream + Unpin> Stream for Timeout<S> {
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.poll_elapsed(cx) {
+            return Poll::Ready(None);
+        }
+
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+/// Implement a `timeout` method for futures and streams holding a bystander's
+/// [`Remover`].
+macro_rules! impl_timeout {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Stop waiting once a duration has elapsed, removing the
+                /// bystander from the [`Standby`] instance.
+                ///
+                /// Refer to [`Timeout`] for more information.
+                ///
+                /// [`Standby`]: crate::Standby
+                pub fn timeout(mut self, duration: Duration) -> Timeout<Self> {
+                    let remover = self.remover.take();
+
+                    Timeout::new(self, remover, duration)
+                }
+            }
+        )*
+    };
+}
+
+impl_timeout!(
+    WaitForAutocompleteFuture,
+    WaitForAutocompleteStream,
+    WaitForCommandFuture,
+    WaitForCommandStream,
+    WaitForComponentFuture,
+    WaitForComponentStream,
+    WaitForEventFuture,
+    WaitForEventStream,
+    WaitForGuildEventFuture,
+    WaitForGuildEventStream,
+    WaitForInteractionFuture,
+    WaitForInteractionStream,
+    WaitForMessageFuture,
+    WaitForMessageStream,
+    WaitForReactionFuture,
+    WaitForReactionStream,
+);
+
+/// Pending check of an event by a [`WaitForAsyncFuture`], resolving to whether
+/// the event matches.
+type PendingCheck = Pin<Box<dyn Future<Output = bool> + Send>>;
+
+/// Predicate of a [`WaitForAsyncFuture`].
+type AsyncCheck<T> = Box<dyn Fn(&T) -> PendingCheck + Send + Sync>;
+
+/// The future returned from methods such as [`Standby::wait_for_async`],
+/// waiting for an event matching an asynchronous predicate.
+///
+/// Events are checked one at a time in the order they were received.
+///
+/// [`Standby::wait_for_async`]: crate::Standby::wait_for_async
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForAsyncFuture<T> {
+    /// Asynchronous predicate to check events with.
+    check: AsyncCheck<T>,
+    /// Event currently being checked and its pending check.
+    pending: Option<(T, PendingCheck)>,
+    /// Remover of the bystander, called once an event matches.
+    remover: Remover,
+    /// Receiver half of the MPSC channel.
+    rx: MpscReceiver<T>,
+}
+
+impl<T> WaitForAsyncFuture<T> {
+    /// Create a new future checking the events received over a channel.
+    pub(crate) fn new<F: Fn(&T) -> Fut + Send + Sync + 'static, Fut>(
+        rx: MpscReceiver<T>,
+        remover: Remover,
+        check: F,
+    ) -> Self
+    where
+        Fut: Future<Output = bool> + Send + 'static,
+    {
+        Self {
+            check: Box::new(move |event| Box::pin(check(event))),
+            pending: None,
+            remover,
+            rx,
+        }
+    }
+
+    /// Stop waiting once a duration has elapsed, removing the bystander from
+    /// the [`Standby`] instance.
+    ///
+    /// Refer to [`Timeout`] for more information.
+    ///
+    /// [`Standby`]: crate::Standby
+    pub fn timeout(mut self, duration: Duration) -> Timeout<Self> {
+        let remover = self.remover.take();
+
+        Timeout::new(self, remover, duration)
+    }
+}
+
+// The pending event is never pinned, so the future can be moved regardless of
+// whether the event can be.
+impl<T> Unpin for WaitForAsyncFuture<T> {}
+
+impl<T: Debug> Debug for WaitForAsyncFuture<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("WaitForAsyncFuture")
+            .field("pending", &self.pending.as_ref().map(|(event, _)| event))
+            .field("remover", &self.remover)
+            .field("rx", &self.rx)
+            .finish()
+    }
+}
+
+impl<T> Future for WaitForAsyncFuture<T> {
+    type Output = Result<T, Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if let Some((event, mut check)) = self.pending.take() {
+                match check.as_mut().poll(cx) {
+                    Poll::Ready(true) => {
+                        self.remover.remove();
+
+                        return Poll::Ready(Ok(event));
+                    }
+                    Poll::Ready(false) => {}
+                    Poll::Pending => {
+                        self.pending = Some((event, check));
+
+                        return Poll::Pending;
+                    }
+                }
+            }
+
+            match ready!(self.rx.poll_recv(cx)) {
+                Some(event) => {
+                    let check = (self.check)(&event);
+                    self.pending = Some((event, check));
+                }
+                None => return Poll::Ready(Err(Canceled(None))),
+            }
+        }
+    }
+}
+
+/// The future returned from methods such as [`Standby::wait_for_any`],
+/// waiting for an event under any of several keys.
+///
+/// [`Standby::wait_for_any`]: crate::Standby::wait_for_any
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForAnyFuture<T> {
+    /// Remover of the bystanders, called once an event matches.
+    pub(crate) remover: Remover,
+    /// Receiver half of the MPSC channel shared by the bystanders.
+    pub(crate) rx: MpscReceiver<T>,
+}
+
+impl<T> WaitForAnyFuture<T> {
+    /// Stop waiting once a duration has elapsed, removing the bystanders from
+    /// the [`Standby`] instance.
+    ///
+    /// Refer to [`Timeout`] for more information.
+    ///
+    /// [`Standby`]: crate::Standby
+    pub fn timeout(mut self, duration: Duration) -> Timeout<Self> {
+        let remover = self.remover.take();
+
+        Timeout::new(self, remover, duration)
+    }
+}
+
+impl<T> Future for WaitForAnyFuture<T> {
+    type Output = Result<T, Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match ready!(self.rx.poll_recv(cx)) {
+            Some(event) => {
+                // The bystanders under the other keys are no longer needed.
+                self.remover.remove();
+
+                Poll::Ready(Ok(event))
+            }
+            None => Poll::Ready(Err(Canceled(None))),
+        }
     }
 }
 
@@ -53,6 +395,8 @@ impl Error for Canceled {
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForEventFuture {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
     /// Receiver half of the oneshot channel.
     pub(crate) rx: Receiver<Event>,
 }
@@ -61,7 +405,9 @@ impl Future for WaitForEventFuture {
     type Output = Result<Event, Canceled>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        self.rx.poll_unpin(cx).map_err(Canceled)
+        self.rx
+            .poll_unpin(cx)
+            .map_err(|source| Canceled(Some(source)))
     }
 }
 
@@ -71,6 +417,8 @@ impl Future for WaitForEventFuture {
 #[derive(Debug)]
 #[must_use = "streams do nothing unless you poll them"]
 pub struct WaitForEventStream {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
     /// Receiver half of the MPSC channel.
     pub(crate) rx: MpscReceiver<Event>,
 }
@@ -90,6 +438,8 @@ impl Stream for WaitForEventStream {
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForGuildEventFuture {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
     /// Receiver half of the oneshot channel.
     pub(crate) rx: Receiver<Event>,
 }
@@ -98,7 +448,9 @@ impl Future for WaitForGuildEventFuture {
     type Output = Result<Event, Canceled>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        self.rx.poll_unpin(cx).map_err(Canceled)
+        self.rx
+            .poll_unpin(cx)
+            .map_err(|source| Canceled(Some(source)))
     }
 }
 
@@ -108,6 +460,8 @@ impl Future for WaitForGuildEventFuture {
 #[derive(Debug)]
 #[must_use = "streams do nothing unless you poll them"]
 pub struct WaitForGuildEventStream {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
     /// Receiver half of the MPSC channel.
     pub(crate) rx: MpscReceiver<Event>,
 }
@@ -127,6 +481,8 @@ impl Stream for WaitForGuildEventStream {
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForMessageFuture {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
     /// Receiver half of the oneshot channel.
     pub(crate) rx: Receiver<MessageCreate>,
 }
@@ -135,7 +491,9 @@ impl Future for WaitForMessageFuture {
     type Output = Result<MessageCreate, Canceled>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        self.rx.poll_unpin(cx).map_err(Canceled)
+        self.rx
+            .poll_unpin(cx)
+            .map_err(|source| Canceled(Some(source)))
     }
 }
 
@@ -145,6 +503,8 @@ impl Future for WaitForMessageFuture {
 #[derive(Debug)]
 #[must_use = "streams do nothing unless you poll them"]
 pub struct WaitForMessageStream {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
     /// Receiver half of the MPSC channel.
     pub(crate) rx: MpscReceiver<MessageCreate>,
 }
@@ -164,6 +524,8 @@ impl Stream for WaitForMessageStream {
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForReactionFuture {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
     /// Receiver half of the oneshot channel.
     pub(crate) rx: Receiver<ReactionAdd>,
 }
@@ -172,7 +534,9 @@ impl Future for WaitForReactionFuture {
     type Output = Result<ReactionAdd, Canceled>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        self.rx.poll_unpin(cx).map_err(Canceled)
+        self.rx
+            .poll_unpin(cx)
+            .map_err(|source| Canceled(Some(source)))
     }
 }
 
@@ -182,6 +546,8 @@ impl Future for WaitForReactionFuture {
 #[derive(Debug)]
 #[must_use = "streams do nothing unless you poll them"]
 pub struct WaitForReactionStream {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
     /// Receiver half of the MPSC channel.
     pub(crate) rx: MpscReceiver<ReactionAdd>,
 }
@@ -194,12 +560,140 @@ impl Stream for WaitForReactionStream {
     }
 }
 
+/// The future returned from [`Standby::wait_for_autocomplete`].
+///
+/// [`Standby::wait_for_autocomplete`]: crate::Standby::wait_for_autocomplete
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForAutocompleteFuture {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
+    /// Receiver half of the oneshot channel.
+    pub(crate) rx: Receiver<ApplicationCommand>,
+}
+
+impl Future for WaitForAutocompleteFuture {
+    type Output = Result<ApplicationCommand, Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.rx
+            .poll_unpin(cx)
+            .map_err(|source| Canceled(Some(source)))
+    }
+}
+
+/// The stream returned from [`Standby::wait_for_autocomplete_stream`].
+///
+/// [`Standby::wait_for_autocomplete_stream`]: crate::Standby::wait_for_autocomplete_stream
+#[derive(Debug)]
+#[must_use = "streams do nothing unless you poll them"]
+pub struct WaitForAutocompleteStream {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
+    /// Receiver half of the MPSC channel.
+    pub(crate) rx: MpscReceiver<ApplicationCommand>,
+}
+
+impl Stream for WaitForAutocompleteStream {
+    type Item = ApplicationCommand;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+/// The future returned from [`Standby::wait_for_command`].
+///
+/// [`Standby::wait_for_command`]: crate::Standby::wait_for_command
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForCommandFuture {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
+    /// Receiver half of the oneshot channel.
+    pub(crate) rx: Receiver<ApplicationCommand>,
+}
+
+impl Future for WaitForCommandFuture {
+    type Output = Result<ApplicationCommand, Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.rx
+            .poll_unpin(cx)
+            .map_err(|source| Canceled(Some(source)))
+    }
+}
+
+/// The stream returned from [`Standby::wait_for_command_stream`].
+///
+/// [`Standby::wait_for_command_stream`]: crate::Standby::wait_for_command_stream
+#[derive(Debug)]
+#[must_use = "streams do nothing unless you poll them"]
+pub struct WaitForCommandStream {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
+    /// Receiver half of the MPSC channel.
+    pub(crate) rx: MpscReceiver<ApplicationCommand>,
+}
+
+impl Stream for WaitForCommandStream {
+    type Item = ApplicationCommand;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+/// The future returned from [`Standby::wait_for_interaction`].
+///
+/// [`Standby::wait_for_interaction`]: crate::Standby::wait_for_interaction
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForInteractionFuture {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
+    /// Receiver half of the oneshot channel.
+    pub(crate) rx: Receiver<Interaction>,
+}
+
+impl Future for WaitForInteractionFuture {
+    type Output = Result<Interaction, Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.rx
+            .poll_unpin(cx)
+            .map_err(|source| Canceled(Some(source)))
+    }
+}
+
+/// The stream returned from [`Standby::wait_for_interaction_stream`].
+///
+/// [`Standby::wait_for_interaction_stream`]: crate::Standby::wait_for_interaction_stream
+#[derive(Debug)]
+#[must_use = "streams do nothing unless you poll them"]
+pub struct WaitForInteractionStream {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
+    /// Receiver half of the MPSC channel.
+    pub(crate) rx: MpscReceiver<Interaction>,
+}
+
+impl Stream for WaitForInteractionStream {
+    type Item = Interaction;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
 /// The future returned from [`Standby::wait_for_component`].
 ///
 /// [`Standby::wait_for_component`]: crate::Standby::wait_for_component
 #[derive(Debug)]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct WaitForComponentFuture {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
     /// Receiver half of the oneshot channel.
     pub(crate) rx: Receiver<MessageComponentInteraction>,
 }
@@ -208,7 +702,9 @@ impl Future for WaitForComponentFuture {
     type Output = Result<MessageComponentInteraction, Canceled>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        self.rx.poll_unpin(cx).map_err(Canceled)
+        self.rx
+            .poll_unpin(cx)
+            .map_err(|source| Canceled(Some(source)))
     }
 }
 
@@ -218,6 +714,8 @@ impl Future for WaitForComponentFuture {
 #[derive(Debug)]
 #[must_use]
 pub struct WaitForComponentStream {
+    /// Remover of the bystander, used by [`Timeout`].
+    pub(crate) remover: Remover,
     /// Receiver half of the MPSC channel.
     pub(crate) rx: MpscReceiver<MessageComponentInteraction>,
 }
@@ -233,12 +731,16 @@ impl Stream for WaitForComponentStream {
 #[cfg(test)]
 mod tests {
     use super::{
-        WaitForEventFuture, WaitForEventStream, WaitForGuildEventFuture, WaitForGuildEventStream,
+        Timeout, WaitError, WaitErrorType, WaitForAnyFuture, WaitForAsyncFuture,
+        WaitForAutocompleteFuture, WaitForAutocompleteStream, WaitForCommandFuture,
+        WaitForCommandStream, WaitForEventFuture, WaitForEventStream, WaitForGuildEventFuture,
+        WaitForGuildEventStream, WaitForInteractionFuture, WaitForInteractionStream,
         WaitForMessageFuture, WaitForMessageStream, WaitForReactionFuture, WaitForReactionStream,
     };
     use futures_util::stream::Stream;
     use static_assertions::assert_impl_all;
-    use std::{fmt::Debug, future::Future};
+    use std::{error::Error, fmt::Debug, future::Future};
+    use twilight_model::gateway::event::Event;
 
     assert_impl_all!(WaitForEventFuture: Debug, Future, Send, Sync);
     assert_impl_all!(WaitForGuildEventFuture: Debug, Future, Send, Sync);
@@ -248,4 +750,16 @@ mod tests {
     assert_impl_all!(WaitForGuildEventStream: Debug, Stream, Send, Sync);
     assert_impl_all!(WaitForMessageStream: Debug, Stream, Send, Sync);
     assert_impl_all!(WaitForReactionStream: Debug, Stream, Send, Sync);
+    assert_impl_all!(WaitForAutocompleteFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForAutocompleteStream: Debug, Stream, Send, Sync);
+    assert_impl_all!(WaitForCommandFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForCommandStream: Debug, Stream, Send, Sync);
+    assert_impl_all!(WaitForInteractionFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForInteractionStream: Debug, Stream, Send, Sync);
+    assert_impl_all!(Timeout<WaitForMessageFuture>: Debug, Future, Send, Sync);
+    assert_impl_all!(Timeout<WaitForMessageStream>: Debug, Stream, Send, Sync);
+    assert_impl_all!(WaitError: Error, Send, Sync);
+    assert_impl_all!(WaitErrorType: Debug, Send, Sync);
+    assert_impl_all!(WaitForAnyFuture<Event>: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForAsyncFuture<Event>: Debug, Future, Send);
 }
@@ -16,6 +16,11 @@
 //! a guild, such as a new `Ready` event ([`Standby::wait_for_event`]). Each
 //! method also has a stream variant.
 //!
+//! Interactions can be waited for as well, such as autocomplete interactions
+//! of a command by a user ([`Standby::wait_for_autocomplete`]), a follow-up
+//! command by a user ([`Standby::wait_for_command`]), and interactions with a
+//! certain custom ID or token prefix ([`Standby::wait_for_interaction`]).
+//!
 //! To use Standby it must process events, such as in an event loop of events
 //! received by the gateway. Check out the [`Standby::process`] method.
 //!
@@ -34,6 +39,24 @@
 //! miss some events while processing a received event. By using a stream, you
 //! won't miss any events.
 //!
+//! ## Timeouts, filters, and asynchronous predicates
+//!
+//! Futures and streams have a `timeout` method which stops waiting once a
+//! duration has elapsed, removing the bystander from [`Standby`] instead of
+//! leaving it around until the next event under its key is processed.
+//!
+//! Common predicates, such as matching the author or channel of an event, are
+//! available in the [`filter`] module and can be combined with each other.
+//! Predicates that need to await something, such as a database query, can be
+//! used via the asynchronous variants of methods, such as
+//! [`Standby::wait_for_message_async`]. These also take a synchronous filter,
+//! so that only events passing it are queued for the asynchronous predicate.
+//!
+//! Dropping a future or stream removes its bystander.
+//!
+//! To wait for an event under any of several keys, such as a message in any of
+//! several channels, use methods such as [`Standby::wait_for_any`].
+//!
 //! ## Features
 //!
 //! ### Tracing
@@ -154,38 +177,93 @@
     warnings
 )]
 
+pub mod filter;
 pub mod future;
 
 mod event;
 
 use self::future::{
-    WaitForComponentFuture, WaitForComponentStream, WaitForEventFuture, WaitForEventStream,
-    WaitForGuildEventFuture, WaitForGuildEventStream, WaitForMessageFuture, WaitForMessageStream,
+    WaitForAnyFuture, WaitForAsyncFuture, WaitForAutocompleteFuture, WaitForAutocompleteStream,
+    WaitForCommandFuture, WaitForCommandStream, WaitForComponentFuture, WaitForComponentStream,
+    WaitForEventFuture, WaitForEventStream, WaitForGuildEventFuture, WaitForGuildEventStream,
+    WaitForInteractionFuture, WaitForInteractionStream, WaitForMessageFuture, WaitForMessageStream,
     WaitForReactionFuture, WaitForReactionStream,
 };
 use dashmap::DashMap;
 use std::{
     fmt::{Debug, Display, Formatter, Result as FmtResult},
+    future::Future,
     hash::Hash,
-    sync::atomic::{AtomicU64, Ordering},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
 };
 use tokio::sync::{
     mpsc::{self, UnboundedReceiver, UnboundedSender as MpscSender},
     oneshot::{self, Receiver, Sender as OneshotSender},
 };
 use twilight_model::{
-    application::interaction::{Interaction, MessageComponentInteraction},
+    application::interaction::{ApplicationCommand, Interaction, MessageComponentInteraction},
     gateway::{
         event::Event,
         payload::incoming::{MessageCreate, ReactionAdd},
     },
-    id::{ChannelId, GuildId, MessageId},
+    id::{ChannelId, CommandId, GuildId, MessageId, UserId},
 };
 
 /// Map keyed by an ID - such as a channel ID or message ID - storing a list of
 /// bystanders.
 type BystanderMap<K, V> = DashMap<K, Vec<Bystander<V>>>;
 
+/// Key of an interaction bystander.
+///
+/// Used by [`Standby::wait_for_interaction`] to wait for interactions that
+/// aren't tied to a message, such as the steps of a multi-step application
+/// command flow.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum InteractionKey {
+    /// Match component interactions with a custom ID.
+    CustomId(String),
+    /// Match interactions with a token starting with a prefix.
+    TokenPrefix(String),
+}
+
+impl Display for InteractionKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::CustomId(custom_id) => {
+                f.write_str("custom ID ")?;
+
+                f.write_str(custom_id)
+            }
+            // Tokens are secrets, so don't display the prefix.
+            Self::TokenPrefix(_) => f.write_str("token prefix"),
+        }
+    }
+}
+
+/// Key of an autocomplete bystander: the command being autocompleted and the
+/// user typing it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct AutocompleteKey {
+    /// ID of the command.
+    command_id: CommandId,
+    /// ID of the user.
+    user_id: UserId,
+}
+
+impl Display for AutocompleteKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("command ")?;
+        Display::fmt(&self.command_id, f)?;
+        f.write_str(" by user ")?;
+
+        Display::fmt(&self.user_id, f)
+    }
+}
+
 /// Sender to a caller that may be for a future bystander or a stream bystander.
 #[derive(Debug)]
 enum Sender<E> {
@@ -210,6 +288,10 @@ impl<E> Sender<E> {
 struct Bystander<T> {
     /// Predicate check to perform on an event.
     func: Box<dyn Fn(&T) -> bool + Send + Sync>,
+    /// ID of the bystander, used to remove it from its map.
+    ///
+    /// The ID is generated via [`Standby::bystander_counter`].
+    id: u64,
     /// [`Sender::Future`]s consume themselves once upon sending so the sender
     /// needs to be able to be taken out separately.
     sender: Option<Sender<T>>,
@@ -218,11 +300,87 @@ struct Bystander<T> {
 impl<T: Debug> Debug for Bystander<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("Bystander")
+            .field("id", &self.id)
             .field("sender", &self.sender)
             .finish()
     }
 }
 
+/// Removes a bystander from its map, such as once it has timed out or the
+/// future or stream waiting on it is dropped.
+///
+/// Holds a weak reference to the map so that dropping the [`Standby`] instance
+/// still cancels the bystander's receiver.
+pub(crate) struct Remover(Option<Box<dyn FnOnce() + Send + Sync>>);
+
+impl Remover {
+    /// Create a remover for a bystander registered under one or more keys of a
+    /// map.
+    fn keyed<K: Eq + Hash + Send + Sync + 'static, V: Send + 'static>(
+        map: &Arc<BystanderMap<K, V>>,
+        keys: Vec<K>,
+        id: u64,
+    ) -> Self {
+        let map = Arc::downgrade(map);
+
+        Self(Some(Box::new(move || {
+            let map = match map.upgrade() {
+                Some(map) => map,
+                None => return,
+            };
+
+            for key in keys {
+                let now_empty = map.get_mut(&key).map_or(false, |mut bystanders| {
+                    bystanders.retain(|bystander| bystander.id != id);
+
+                    bystanders.is_empty()
+                });
+
+                if now_empty {
+                    map.remove_if(&key, |_, bystanders| bystanders.is_empty());
+                }
+            }
+        })))
+    }
+
+    /// Create a remover for a bystander of any event.
+    fn event(map: &Arc<DashMap<u64, Bystander<Event>>>, id: u64) -> Self {
+        let map = Arc::downgrade(map);
+
+        Self(Some(Box::new(move || {
+            if let Some(map) = map.upgrade() {
+                map.remove(&id);
+            }
+        })))
+    }
+
+    /// Remove the bystander if it hasn't already been removed.
+    pub(crate) fn remove(&mut self) {
+        if let Some(remove) = self.0.take() {
+            remove();
+        }
+    }
+
+    /// Take the remover, leaving one that does nothing in its place.
+    pub(crate) fn take(&mut self) -> Self {
+        Self(self.0.take())
+    }
+}
+
+impl Drop for Remover {
+    fn drop(&mut self) {
+        self.remove();
+    }
+}
+
+impl Debug for Remover {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Remover")
+            .field("removed", &self.0.is_none())
+            .finish()
+    }
+}
+
 /// The `Standby` struct, used by the main event loop to process events and by
 /// tasks to wait for an event.
 ///
@@ -234,27 +392,42 @@ impl<T: Debug> Debug for Bystander<T> {
 /// [`std::sync::Arc`] or [`std::rc::Rc`].
 #[derive(Debug, Default)]
 pub struct Standby {
+    /// List of autocomplete bystanders where the ID of the command and the
+    /// user are known beforehand.
+    autocompletes: Arc<BystanderMap<AutocompleteKey, ApplicationCommand>>,
+    /// Counter used to generate the IDs of bystanders.
+    ///
+    /// IDs of bystanders in [`events`] are also used as their key.
+    ///
+    /// [`events`]: Self::events
+    bystander_counter: AtomicU64,
+    /// List of application command bystanders where the ID of the invoking
+    /// user is known beforehand.
+    commands: Arc<BystanderMap<UserId, ApplicationCommand>>,
     /// List of component bystanders where the ID of the message is known
     /// beforehand.
-    components: DashMap<MessageId, Vec<Bystander<MessageComponentInteraction>>>,
+    components: Arc<BystanderMap<MessageId, MessageComponentInteraction>>,
     /// Bystanders for any event that may not be in any particular guild.
     ///
-    /// The key is generated via [`event_counter`].
-    ///
-    /// [`event_counter`]: Self::event_counter
-    events: DashMap<u64, Bystander<Event>>,
-    /// Event counter to be used as the key of [`events`].
-    ///
-    /// [`events`]: Self::events
-    event_counter: AtomicU64,
+    /// The key is the ID of the bystander.
+    events: Arc<DashMap<u64, Bystander<Event>>>,
     /// List of bystanders where the ID of the guild is known beforehand.
-    guilds: DashMap<GuildId, Vec<Bystander<Event>>>,
+    guilds: Arc<BystanderMap<GuildId, Event>>,
+    /// List of interaction bystanders where the custom ID of the component
+    /// is known beforehand.
+    interaction_custom_ids: Arc<BystanderMap<String, Interaction>>,
+    /// List of interaction bystanders where a prefix of the token is known
+    /// beforehand.
+    ///
+    /// Every interaction is compared with all prefixes, so these are kept
+    /// apart from the bystanders that can be looked up by key.
+    interaction_token_prefixes: Arc<BystanderMap<String, Interaction>>,
     /// List of message bystanders where the ID of the channel is known
     /// beforehand.
-    messages: DashMap<ChannelId, Vec<Bystander<MessageCreate>>>,
+    messages: Arc<BystanderMap<ChannelId, MessageCreate>>,
     /// List of reaction bystanders where the ID of the message is known
     /// beforehand.
-    reactions: DashMap<MessageId, Vec<Bystander<ReactionAdd>>>,
+    reactions: Arc<BystanderMap<MessageId, ReactionAdd>>,
 }
 
 impl Standby {
@@ -291,13 +464,41 @@ impl Standby {
 
         match event {
             Event::InteractionCreate(e) => {
-                if let Interaction::MessageComponent(comp) = &e.0 {
-                    completions.add_with(&Self::process_specific_event(
-                        &self.components,
-                        comp.message.id,
-                        &**comp,
-                    ));
+                match &e.0 {
+                    Interaction::ApplicationCommand(command) => {
+                        if let Some(user_id) = event::command_author_id(command) {
+                            completions.add_with(&Self::process_specific_event(
+                                &self.commands,
+                                user_id,
+                                &**command,
+                            ));
+                        }
+                    }
+                    Interaction::ApplicationCommandAutocomplete(command) => {
+                        if let Some(user_id) = event::command_author_id(command) {
+                            let key = AutocompleteKey {
+                                command_id: command.data.id,
+                                user_id,
+                            };
+
+                            completions.add_with(&Self::process_specific_event(
+                                &self.autocompletes,
+                                key,
+                                &**command,
+                            ));
+                        }
+                    }
+                    Interaction::MessageComponent(comp) => {
+                        completions.add_with(&Self::process_specific_event(
+                            &self.components,
+                            comp.message.id,
+                            &**comp,
+                        ));
+                    }
+                    _ => {}
                 }
+
+                completions.add_with(&self.process_interaction(&e.0));
             }
             Event::MessageCreate(e) => {
                 completions.add_with(&Self::process_specific_event(
@@ -370,9 +571,9 @@ impl Standby {
         #[cfg(feature = "tracing")]
         tracing::trace!(%guild_id, "waiting for event in guild");
 
-        WaitForGuildEventFuture {
-            rx: Self::insert_future(&self.guilds, guild_id, check),
-        }
+        let (rx, remover) = self.insert_future(&self.guilds, guild_id, check);
+
+        WaitForGuildEventFuture { remover, rx }
     }
 
     /// Wait for a stream of events in a certain guild.
@@ -429,9 +630,9 @@ impl Standby {
         #[cfg(feature = "tracing")]
         tracing::trace!(%guild_id, "waiting for event in guild");
 
-        WaitForGuildEventStream {
-            rx: Self::insert_stream(&self.guilds, guild_id, check),
-        }
+        let (rx, remover) = self.insert_stream(&self.guilds, guild_id, check);
+
+        WaitForGuildEventStream { remover, rx }
     }
 
     /// Wait for an event not in a certain guild. This must be filtered by an
@@ -479,16 +680,9 @@ impl Standby {
         tracing::trace!("waiting for event");
 
         let (tx, rx) = oneshot::channel();
+        let remover = self.insert_event(check, Sender::Future(tx));
 
-        self.events.insert(
-            self.next_event_id(),
-            Bystander {
-                func: check.into(),
-                sender: Some(Sender::Future(tx)),
-            },
-        );
-
-        WaitForEventFuture { rx }
+        WaitForEventFuture { remover, rx }
     }
 
     /// Wait for a stream of events not in a certain guild. This must be
@@ -539,16 +733,9 @@ impl Standby {
         tracing::trace!("waiting for event");
 
         let (tx, rx) = mpsc::unbounded_channel();
+        let remover = self.insert_event(check, Sender::Stream(tx));
 
-        self.events.insert(
-            self.next_event_id(),
-            Bystander {
-                func: check.into(),
-                sender: Some(Sender::Stream(tx)),
-            },
-        );
-
-        WaitForEventStream { rx }
+        WaitForEventStream { remover, rx }
     }
 
     /// Wait for a message in a certain channel.
@@ -596,9 +783,9 @@ impl Standby {
         #[cfg(feature = "tracing")]
         tracing::trace!(%channel_id, "waiting for message in channel");
 
-        WaitForMessageFuture {
-            rx: Self::insert_future(&self.messages, channel_id, check),
-        }
+        let (rx, remover) = self.insert_future(&self.messages, channel_id, check);
+
+        WaitForMessageFuture { remover, rx }
     }
 
     /// Wait for a stream of message in a certain channel.
@@ -650,9 +837,9 @@ impl Standby {
         #[cfg(feature = "tracing")]
         tracing::trace!(%channel_id, "waiting for message in channel");
 
-        WaitForMessageStream {
-            rx: Self::insert_stream(&self.messages, channel_id, check),
-        }
+        let (rx, remover) = self.insert_stream(&self.messages, channel_id, check);
+
+        WaitForMessageStream { remover, rx }
     }
 
     /// Wait for a reaction on a certain message.
@@ -700,9 +887,9 @@ impl Standby {
         #[cfg(feature = "tracing")]
         tracing::trace!(%message_id, "waiting for reaction on message");
 
-        WaitForReactionFuture {
-            rx: Self::insert_future(&self.reactions, message_id, check),
-        }
+        let (rx, remover) = self.insert_future(&self.reactions, message_id, check);
+
+        WaitForReactionFuture { remover, rx }
     }
 
     /// Wait for a stream of reactions on a certain message.
@@ -744,149 +931,834 @@ impl Standby {
     /// The returned stream ends when the associated [`Standby`] instance is
     /// dropped.
     ///
-    /// [`wait_for_reaction`]: Self::wait_for_reaction
-    pub fn wait_for_reaction_stream<F: Fn(&ReactionAdd) -> bool + Send + Sync + 'static>(
+    /// [`wait_for_reaction`]: Self::wait_for_reaction
+    pub fn wait_for_reaction_stream<F: Fn(&ReactionAdd) -> bool + Send + Sync + 'static>(
+        &self,
+        message_id: MessageId,
+        check: impl Into<Box<F>>,
+    ) -> WaitForReactionStream {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(%message_id, "waiting for reaction on message");
+
+        let (rx, remover) = self.insert_stream(&self.reactions, message_id, check);
+
+        WaitForReactionStream { remover, rx }
+    }
+
+    /// Wait for a component on a certain message.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// If you need to wait for multiple components matching the given predicate,
+    /// use [`wait_for_component_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for a component on message 123 by user 456:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures_util::future;
+    /// use twilight_model::{
+    ///     application::interaction::message_component::MessageComponentInteraction,
+    ///     id::{MessageId, UserId},
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let component = standby.wait_for_component(MessageId::new(123).expect("non zero"), |event: &MessageComponentInteraction| {
+    ///     event.author_id() == Some(UserId::new(456).expect("non zero"))
+    /// }).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`wait_for_component_stream`]: Self::wait_for_component_stream
+    pub fn wait_for_component<
+        F: Fn(&MessageComponentInteraction) -> bool + Send + Sync + 'static,
+    >(
+        &self,
+        message_id: MessageId,
+        check: impl Into<Box<F>>,
+    ) -> WaitForComponentFuture {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(%message_id, "waiting for component on message");
+
+        let (rx, remover) = self.insert_future(&self.components, message_id, check);
+
+        WaitForComponentFuture { remover, rx }
+    }
+
+    /// Wait for a stream of components on a certain message.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// If you need to wait for only one component matching the given predicate,
+    /// use [`wait_for_component`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for multiple button components on message 123 with a custom_id of "Click":
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures_util::stream::StreamExt;
+    /// use twilight_model::{
+    ///     application::interaction::message_component::MessageComponentInteraction,
+    ///     id::{MessageId, UserId},
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let mut components = standby.wait_for_component_stream(MessageId::new(123).expect("non zero"), |event: &MessageComponentInteraction| {
+    ///     event.data.custom_id == "Click".to_string()
+    /// });
+    ///
+    /// while let Some(component) = components.next().await {
+    ///     println!("got a component by {}", component.author_id().unwrap());
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`wait_for_component`]: Self::wait_for_component
+    pub fn wait_for_component_stream<
+        F: Fn(&MessageComponentInteraction) -> bool + Send + Sync + 'static,
+    >(
+        &self,
+        message_id: MessageId,
+        check: impl Into<Box<F>>,
+    ) -> WaitForComponentStream {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(%message_id, "waiting for component on message");
+
+        let (rx, remover) = self.insert_stream(&self.components, message_id, check);
+
+        WaitForComponentStream { remover, rx }
+    }
+
+    /// Wait for an autocomplete interaction of a certain command by a certain
+    /// user.
+    ///
+    /// To wait for multiple autocomplete interactions matching the given
+    /// predicate use [`wait_for_autocomplete_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for user 456 to autocomplete command 123:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::{
+    ///     application::interaction::ApplicationCommand,
+    ///     id::{CommandId, UserId},
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let command_id = CommandId::new(123).expect("non zero");
+    /// let user_id = UserId::new(456).expect("non zero");
+    ///
+    /// let autocomplete = standby
+    ///     .wait_for_autocomplete(command_id, user_id, |_: &ApplicationCommand| true)
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`wait_for_autocomplete_stream`]: Self::wait_for_autocomplete_stream
+    pub fn wait_for_autocomplete<F: Fn(&ApplicationCommand) -> bool + Send + Sync + 'static>(
+        &self,
+        command_id: CommandId,
+        user_id: UserId,
+        check: impl Into<Box<F>>,
+    ) -> WaitForAutocompleteFuture {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(%command_id, %user_id, "waiting for autocomplete of command");
+
+        let key = AutocompleteKey {
+            command_id,
+            user_id,
+        };
+        let (rx, remover) = self.insert_future(&self.autocompletes, key, check);
+
+        WaitForAutocompleteFuture { remover, rx }
+    }
+
+    /// Wait for a stream of autocomplete interactions of a certain command by
+    /// a certain user.
+    ///
+    /// To wait for only one autocomplete interaction matching the given
+    /// predicate use [`wait_for_autocomplete`].
+    ///
+    /// # Errors
+    ///
+    /// The returned stream ends when the associated [`Standby`] instance is
+    /// dropped.
+    ///
+    /// [`wait_for_autocomplete`]: Self::wait_for_autocomplete
+    pub fn wait_for_autocomplete_stream<
+        F: Fn(&ApplicationCommand) -> bool + Send + Sync + 'static,
+    >(
+        &self,
+        command_id: CommandId,
+        user_id: UserId,
+        check: impl Into<Box<F>>,
+    ) -> WaitForAutocompleteStream {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(%command_id, %user_id, "waiting for autocomplete of command");
+
+        let key = AutocompleteKey {
+            command_id,
+            user_id,
+        };
+        let (rx, remover) = self.insert_stream(&self.autocompletes, key, check);
+
+        WaitForAutocompleteStream { remover, rx }
+    }
+
+    /// Wait for an application command invoked by a certain user.
+    ///
+    /// This is useful for waiting on a follow-up command from a user.
+    ///
+    /// To wait for multiple application commands matching the given predicate
+    /// use [`wait_for_command_stream`].
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`wait_for_command_stream`]: Self::wait_for_command_stream
+    pub fn wait_for_command<F: Fn(&ApplicationCommand) -> bool + Send + Sync + 'static>(
+        &self,
+        user_id: UserId,
+        check: impl Into<Box<F>>,
+    ) -> WaitForCommandFuture {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(%user_id, "waiting for command by user");
+
+        let (rx, remover) = self.insert_future(&self.commands, user_id, check);
+
+        WaitForCommandFuture { remover, rx }
+    }
+
+    /// Wait for a stream of application commands invoked by a certain user.
+    ///
+    /// To wait for only one application command matching the given predicate
+    /// use [`wait_for_command`].
+    ///
+    /// # Errors
+    ///
+    /// The returned stream ends when the associated [`Standby`] instance is
+    /// dropped.
+    ///
+    /// [`wait_for_command`]: Self::wait_for_command
+    pub fn wait_for_command_stream<F: Fn(&ApplicationCommand) -> bool + Send + Sync + 'static>(
+        &self,
+        user_id: UserId,
+        check: impl Into<Box<F>>,
+    ) -> WaitForCommandStream {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(%user_id, "waiting for command by user");
+
+        let (rx, remover) = self.insert_stream(&self.commands, user_id, check);
+
+        WaitForCommandStream { remover, rx }
+    }
+
+    /// Wait for an interaction with a certain custom ID or token prefix.
+    ///
+    /// Components are matched by custom ID on any message, which is useful for
+    /// components of ephemeral or deferred messages, the ID of which may not
+    /// be known beforehand.
+    ///
+    /// To wait for multiple interactions matching the given predicate use
+    /// [`wait_for_interaction_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for a component with the custom ID "confirm":
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::application::interaction::Interaction;
+    /// use twilight_standby::{InteractionKey, Standby};
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let key = InteractionKey::CustomId("confirm".to_owned());
+    /// let interaction = standby.wait_for_interaction(key, |_: &Interaction| true).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`wait_for_interaction_stream`]: Self::wait_for_interaction_stream
+    pub fn wait_for_interaction<F: Fn(&Interaction) -> bool + Send + Sync + 'static>(
+        &self,
+        key: InteractionKey,
+        check: impl Into<Box<F>>,
+    ) -> WaitForInteractionFuture {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(%key, "waiting for interaction");
+
+        let (rx, remover) = match key {
+            InteractionKey::CustomId(custom_id) => {
+                self.insert_future(&self.interaction_custom_ids, custom_id, check)
+            }
+            InteractionKey::TokenPrefix(prefix) => {
+                self.insert_future(&self.interaction_token_prefixes, prefix, check)
+            }
+        };
+
+        WaitForInteractionFuture { remover, rx }
+    }
+
+    /// Wait for a stream of interactions with a certain custom ID or token
+    /// prefix.
+    ///
+    /// To wait for only one interaction matching the given predicate use
+    /// [`wait_for_interaction`].
+    ///
+    /// # Errors
+    ///
+    /// The returned stream ends when the associated [`Standby`] instance is
+    /// dropped.
+    ///
+    /// [`wait_for_interaction`]: Self::wait_for_interaction
+    pub fn wait_for_interaction_stream<F: Fn(&Interaction) -> bool + Send + Sync + 'static>(
+        &self,
+        key: InteractionKey,
+        check: impl Into<Box<F>>,
+    ) -> WaitForInteractionStream {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(%key, "waiting for interaction");
+
+        let (rx, remover) = match key {
+            InteractionKey::CustomId(custom_id) => {
+                self.insert_stream(&self.interaction_custom_ids, custom_id, check)
+            }
+            InteractionKey::TokenPrefix(prefix) => {
+                self.insert_stream(&self.interaction_token_prefixes, prefix, check)
+            }
+        };
+
+        WaitForInteractionStream { remover, rx }
+    }
+
+    /// Wait for an event in any of several guilds.
+    ///
+    /// Once an event in one of the guilds matches, the bystanders of the
+    /// other guilds are removed.
+    ///
+    /// # Examples
+    ///
+    /// Wait for a [`BanAdd`] event in guild 123 or 456:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::{
+    ///     gateway::event::{Event, EventType},
+    ///     id::GuildId,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let guild_ids = [
+    ///     GuildId::new(123).expect("non zero"),
+    ///     GuildId::new(456).expect("non zero"),
+    /// ];
+    ///
+    /// let event = standby.wait_for_any(guild_ids, |event: &Event| {
+    ///     event.kind() == EventType::BanAdd
+    /// }).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`BanAdd`]: twilight_model::gateway::payload::incoming::BanAdd
+    /// [`Canceled`]: future::Canceled
+    pub fn wait_for_any<F: Fn(&Event) -> bool + Send + Sync + 'static>(
+        &self,
+        guild_ids: impl IntoIterator<Item = GuildId>,
+        check: F,
+    ) -> WaitForAnyFuture<Event> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!("waiting for event in any of several guilds");
+
+        self.insert_any(&self.guilds, guild_ids, check)
+    }
+
+    /// Wait for a message in any of several channels.
+    ///
+    /// Once a message in one of the channels matches, the bystanders of the
+    /// other channels are removed.
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    pub fn wait_for_message_any<F: Fn(&MessageCreate) -> bool + Send + Sync + 'static>(
+        &self,
+        channel_ids: impl IntoIterator<Item = ChannelId>,
+        check: F,
+    ) -> WaitForAnyFuture<MessageCreate> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!("waiting for message in any of several channels");
+
+        self.insert_any(&self.messages, channel_ids, check)
+    }
+
+    /// Wait for a reaction on any of several messages.
+    ///
+    /// Once a reaction on one of the messages matches, the bystanders of the
+    /// other messages are removed.
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    pub fn wait_for_reaction_any<F: Fn(&ReactionAdd) -> bool + Send + Sync + 'static>(
+        &self,
+        message_ids: impl IntoIterator<Item = MessageId>,
+        check: F,
+    ) -> WaitForAnyFuture<ReactionAdd> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!("waiting for reaction on any of several messages");
+
+        self.insert_any(&self.reactions, message_ids, check)
+    }
+
+    /// Wait for a component on any of several messages.
+    ///
+    /// Once a component on one of the messages matches, the bystanders of the
+    /// other messages are removed.
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    pub fn wait_for_component_any<
+        F: Fn(&MessageComponentInteraction) -> bool + Send + Sync + 'static,
+    >(
+        &self,
+        message_ids: impl IntoIterator<Item = MessageId>,
+        check: F,
+    ) -> WaitForAnyFuture<MessageComponentInteraction> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!("waiting for component on any of several messages");
+
+        self.insert_any(&self.components, message_ids, check)
+    }
+
+    /// Wait for an event in a certain guild matching an asynchronous
+    /// predicate.
+    ///
+    /// Events in the guild are checked one at a time in the order they were
+    /// received. This is the asynchronous variant of [`wait_for`].
+    ///
+    /// Events are first checked by the synchronous `filter`, such as one from
+    /// the [`filter`] module, and only events passing it are queued to be
+    /// checked by the asynchronous `check`.
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`wait_for`]: Self::wait_for
+    pub fn wait_for_async<
+        P: Fn(&Event) -> bool + Send + Sync + 'static,
+        F: Fn(&Event) -> Fut + Send + Sync + 'static,
+        Fut,
+    >(
+        &self,
+        guild_id: GuildId,
+        filter: P,
+        check: F,
+    ) -> WaitForAsyncFuture<Event>
+    where
+        Fut: Future<Output = bool> + Send + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(%guild_id, "waiting for event in guild");
+
+        let (rx, remover) = self.insert_stream(&self.guilds, guild_id, filter);
+
+        WaitForAsyncFuture::new(rx, remover, check)
+    }
+
+    /// Wait for an event not in a certain guild matching an asynchronous
+    /// predicate.
+    ///
+    /// Events are checked one at a time in the order they were received. This
+    /// is the asynchronous variant of [`wait_for_event`].
+    ///
+    /// Events are first checked by the synchronous `filter`, such as one from
+    /// the [`filter`] module, and only events passing it are queued to be
+    /// checked by the asynchronous `check`.
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`wait_for_event`]: Self::wait_for_event
+    pub fn wait_for_event_async<
+        P: Fn(&Event) -> bool + Send + Sync + 'static,
+        F: Fn(&Event) -> Fut + Send + Sync + 'static,
+        Fut,
+    >(
         &self,
-        message_id: MessageId,
-        check: impl Into<Box<F>>,
-    ) -> WaitForReactionStream {
+        filter: P,
+        check: F,
+    ) -> WaitForAsyncFuture<Event>
+    where
+        Fut: Future<Output = bool> + Send + 'static,
+    {
         #[cfg(feature = "tracing")]
-        tracing::trace!(%message_id, "waiting for reaction on message");
+        tracing::trace!("waiting for event");
 
-        WaitForReactionStream {
-            rx: Self::insert_stream(&self.reactions, message_id, check),
-        }
+        let (tx, rx) = mpsc::unbounded_channel();
+        let remover = self.insert_event(filter, Sender::Stream(tx));
+
+        WaitForAsyncFuture::new(rx, remover, check)
     }
 
-    /// Wait for a component on a certain message.
+    /// Wait for a message in a certain channel matching an asynchronous
+    /// predicate.
     ///
-    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    /// Messages in the channel are checked one at a time in the order they
+    /// were received. This is the asynchronous variant of
+    /// [`wait_for_message`].
     ///
-    /// If you need to wait for multiple components matching the given predicate,
-    /// use [`wait_for_component_stream`].
+    /// Events are first checked by the synchronous `filter`, such as one from
+    /// the [`filter`] module, and only events passing it are queued to be
+    /// checked by the asynchronous `check`.
     ///
     /// # Examples
     ///
-    /// Wait for a component on message 123 by user 456:
+    /// Wait for a message in channel 123 starting with "!register" by a user
+    /// who is in a database:
     ///
     /// ```no_run
-    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use futures_util::future;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::time::Duration;
     /// use twilight_model::{
-    ///     application::interaction::message_component::MessageComponentInteraction,
-    ///     id::{MessageId, UserId},
+    ///     gateway::payload::incoming::MessageCreate,
+    ///     id::{ChannelId, UserId},
     /// };
-    /// use twilight_standby::Standby;
+    /// use twilight_standby::{filter, Standby};
+    ///
+    /// async fn is_registered(user_id: UserId) -> bool {
+    ///     // Query a database.
+    ///     # true
+    /// }
     ///
     /// let standby = Standby::new();
     ///
-    /// let component = standby.wait_for_component(MessageId::new(123).expect("non zero"), |event: &MessageComponentInteraction| {
-    ///     event.author_id() == Some(UserId::new(456).expect("non zero"))
-    /// }).await?;
+    /// let channel_id = ChannelId::new(123).expect("non zero");
+    ///
+    /// let message = standby
+    ///     .wait_for_message_async(
+    ///         channel_id,
+    ///         filter::content_prefix("!register"),
+    ///         |event: &MessageCreate| is_registered(event.author.id),
+    ///     )
+    ///     .timeout(Duration::from_secs(60))
+    ///     .await?;
     /// # Ok(()) }
     /// ```
     ///
-    /// [`wait_for_component_stream`]: Self::wait_for_component_stream
-    pub fn wait_for_component<
-        F: Fn(&MessageComponentInteraction) -> bool + Send + Sync + 'static,
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
+    ///
+    /// [`Canceled`]: future::Canceled
+    /// [`wait_for_message`]: Self::wait_for_message
+    pub fn wait_for_message_async<
+        P: Fn(&MessageCreate) -> bool + Send + Sync + 'static,
+        F: Fn(&MessageCreate) -> Fut + Send + Sync + 'static,
+        Fut,
     >(
         &self,
-        message_id: MessageId,
-        check: impl Into<Box<F>>,
-    ) -> WaitForComponentFuture {
+        channel_id: ChannelId,
+        filter: P,
+        check: F,
+    ) -> WaitForAsyncFuture<MessageCreate>
+    where
+        Fut: Future<Output = bool> + Send + 'static,
+    {
         #[cfg(feature = "tracing")]
-        tracing::trace!(%message_id, "waiting for component on message");
+        tracing::trace!(%channel_id, "waiting for message in channel");
 
-        WaitForComponentFuture {
-            rx: Self::insert_future(&self.components, message_id, check),
-        }
+        let (rx, remover) = self.insert_stream(&self.messages, channel_id, filter);
+
+        WaitForAsyncFuture::new(rx, remover, check)
     }
 
-    /// Wait for a stream of components on a certain message.
+    /// Wait for a reaction on a certain message matching an asynchronous
+    /// predicate.
     ///
-    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    /// Reactions on the message are checked one at a time in the order they
+    /// were received. This is the asynchronous variant of
+    /// [`wait_for_reaction`].
     ///
-    /// If you need to wait for only one component matching the given predicate,
-    /// use [`wait_for_component`].
+    /// Events are first checked by the synchronous `filter`, such as one from
+    /// the [`filter`] module, and only events passing it are queued to be
+    /// checked by the asynchronous `check`.
     ///
-    /// # Examples
+    /// # Errors
     ///
-    /// Wait for multiple button components on message 123 with a custom_id of "Click":
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
     ///
-    /// ```no_run
-    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use futures_util::stream::StreamExt;
-    /// use twilight_model::{
-    ///     application::interaction::message_component::MessageComponentInteraction,
-    ///     id::{MessageId, UserId},
-    /// };
-    /// use twilight_standby::Standby;
+    /// [`Canceled`]: future::Canceled
+    /// [`wait_for_reaction`]: Self::wait_for_reaction
+    pub fn wait_for_reaction_async<
+        P: Fn(&ReactionAdd) -> bool + Send + Sync + 'static,
+        F: Fn(&ReactionAdd) -> Fut + Send + Sync + 'static,
+        Fut,
+    >(
+        &self,
+        message_id: MessageId,
+        filter: P,
+        check: F,
+    ) -> WaitForAsyncFuture<ReactionAdd>
+    where
+        Fut: Future<Output = bool> + Send + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(%message_id, "waiting for reaction on message");
+
+        let (rx, remover) = self.insert_stream(&self.reactions, message_id, filter);
+
+        WaitForAsyncFuture::new(rx, remover, check)
+    }
+
+    /// Wait for a component on a certain message matching an asynchronous
+    /// predicate.
     ///
-    /// let standby = Standby::new();
+    /// Components on the message are checked one at a time in the order they
+    /// were received. This is the asynchronous variant of
+    /// [`wait_for_component`].
     ///
-    /// let mut components = standby.wait_for_component_stream(MessageId::new(123).expect("non zero"), |event: &MessageComponentInteraction| {
-    ///     event.data.custom_id == "Click".to_string()
-    /// });
+    /// Events are first checked by the synchronous `filter`, such as one from
+    /// the [`filter`] module, and only events passing it are queued to be
+    /// checked by the asynchronous `check`.
     ///
-    /// while let Some(component) = components.next().await {
-    ///     println!("got a component by {}", component.author_id().unwrap());
-    /// }
-    /// # Ok(()) }
-    /// ```
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`Canceled`] error if the associated
+    /// [`Standby`] instance is dropped.
     ///
+    /// [`Canceled`]: future::Canceled
     /// [`wait_for_component`]: Self::wait_for_component
-    pub fn wait_for_component_stream<
-        F: Fn(&MessageComponentInteraction) -> bool + Send + Sync + 'static,
+    pub fn wait_for_component_async<
+        P: Fn(&MessageComponentInteraction) -> bool + Send + Sync + 'static,
+        F: Fn(&MessageComponentInteraction) -> Fut + Send + Sync + 'static,
+        Fut,
     >(
         &self,
         message_id: MessageId,
-        check: impl Into<Box<F>>,
-    ) -> WaitForComponentStream {
+        filter: P,
+        check: F,
+    ) -> WaitForAsyncFuture<MessageComponentInteraction>
+    where
+        Fut: Future<Output = bool> + Send + 'static,
+    {
         #[cfg(feature = "tracing")]
         tracing::trace!(%message_id, "waiting for component on message");
 
-        WaitForComponentStream {
-            rx: Self::insert_stream(&self.components, message_id, check),
-        }
+        let (rx, remover) = self.insert_stream(&self.components, message_id, filter);
+
+        WaitForAsyncFuture::new(rx, remover, check)
     }
 
-    /// Next event ID in [`Standby::event_counter`].
-    fn next_event_id(&self) -> u64 {
-        self.event_counter.fetch_add(1, Ordering::SeqCst)
+    /// Next bystander ID in [`Standby::bystander_counter`].
+    fn next_bystander_id(&self) -> u64 {
+        self.bystander_counter.fetch_add(1, Ordering::SeqCst)
     }
 
-    /// Append a new future bystander into a map according to the ID.
-    fn insert_future<F: Fn(&V) -> bool + Send + Sync + 'static, K: Eq + Hash, V>(
-        map: &BystanderMap<K, V>,
-        id: K,
+    /// Insert a new bystander of any event.
+    fn insert_event<F: Fn(&Event) -> bool + Send + Sync + 'static>(
+        &self,
         check: impl Into<Box<F>>,
-    ) -> Receiver<V> {
-        let (tx, rx) = oneshot::channel();
+        sender: Sender<Event>,
+    ) -> Remover {
+        let id = self.next_bystander_id();
+
+        self.events.insert(
+            id,
+            Bystander {
+                func: check.into(),
+                id,
+                sender: Some(sender),
+            },
+        );
+
+        Remover::event(&self.events, id)
+    }
+
+    /// Append a new bystander into a map according to the key.
+    fn insert_keyed<
+        F: Fn(&V) -> bool + Send + Sync + 'static,
+        K: Clone + Eq + Hash + Send + Sync + 'static,
+        V: Send + 'static,
+    >(
+        &self,
+        map: &Arc<BystanderMap<K, V>>,
+        key: K,
+        check: impl Into<Box<F>>,
+        sender: Sender<V>,
+    ) -> Remover {
+        let id = self.next_bystander_id();
 
-        let mut entry = map.entry(id).or_default();
-        entry.push(Bystander {
+        map.entry(key.clone()).or_default().push(Bystander {
             func: check.into(),
-            sender: Some(Sender::Future(tx)),
+            id,
+            sender: Some(sender),
         });
 
-        rx
+        Remover::keyed(map, vec![key], id)
+    }
+
+    /// Append a new future bystander into a map according to the key.
+    fn insert_future<
+        F: Fn(&V) -> bool + Send + Sync + 'static,
+        K: Clone + Eq + Hash + Send + Sync + 'static,
+        V: Send + 'static,
+    >(
+        &self,
+        map: &Arc<BystanderMap<K, V>>,
+        key: K,
+        check: impl Into<Box<F>>,
+    ) -> (Receiver<V>, Remover) {
+        let (tx, rx) = oneshot::channel();
+        let remover = self.insert_keyed(map, key, check, Sender::Future(tx));
+
+        (rx, remover)
     }
 
-    /// Append a new stream bystander into a map according to the ID.
-    fn insert_stream<F: Fn(&V) -> bool + Send + Sync + 'static, K: Eq + Hash, V>(
-        map: &BystanderMap<K, V>,
-        id: K,
+    /// Append a new stream bystander into a map according to the key.
+    fn insert_stream<
+        F: Fn(&V) -> bool + Send + Sync + 'static,
+        K: Clone + Eq + Hash + Send + Sync + 'static,
+        V: Send + 'static,
+    >(
+        &self,
+        map: &Arc<BystanderMap<K, V>>,
+        key: K,
         check: impl Into<Box<F>>,
-    ) -> UnboundedReceiver<V> {
+    ) -> (UnboundedReceiver<V>, Remover) {
         let (tx, rx) = mpsc::unbounded_channel();
+        let remover = self.insert_keyed(map, key, check, Sender::Stream(tx));
 
-        let mut entry = map.entry(id).or_default();
-        entry.push(Bystander {
-            func: check.into(),
-            sender: Some(Sender::Stream(tx)),
-        });
+        (rx, remover)
+    }
+
+    /// Append a bystander sharing one predicate and channel under each of
+    /// several keys of a map.
+    fn insert_any<
+        F: Fn(&V) -> bool + Send + Sync + 'static,
+        K: Copy + Eq + Hash + Send + Sync + 'static,
+        V: Send + 'static,
+    >(
+        &self,
+        map: &Arc<BystanderMap<K, V>>,
+        keys: impl IntoIterator<Item = K>,
+        check: F,
+    ) -> WaitForAnyFuture<V> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let check = Arc::new(check);
+        let id = self.next_bystander_id();
+        let keys = keys.into_iter().collect::<Vec<_>>();
+
+        for key in &keys {
+            let check = Arc::clone(&check);
+
+            map.entry(*key).or_default().push(Bystander {
+                func: Box::new(move |event| check(event)),
+                id,
+                sender: Some(Sender::Stream(tx.clone())),
+            });
+        }
+
+        WaitForAnyFuture {
+            remover: Remover::keyed(map, keys, id),
+            rx,
+        }
+    }
+
+    /// Process an interaction for the bystanders keyed by its custom ID or a
+    /// prefix of its token.
+    fn process_interaction(&self, interaction: &Interaction) -> ProcessResults {
+        let mut results = ProcessResults::new();
+
+        let token = match interaction {
+            Interaction::ApplicationCommand(command)
+            | Interaction::ApplicationCommandAutocomplete(command) => &command.token,
+            Interaction::MessageComponent(component) => {
+                results.add_with(&Self::process_specific_event(
+                    &self.interaction_custom_ids,
+                    component.data.custom_id.clone(),
+                    interaction,
+                ));
 
-        rx
+                &component.token
+            }
+            Interaction::Ping(ping) => &ping.token,
+            _ => return results,
+        };
+
+        // Collect the matching prefixes first, since processing a prefix locks
+        // its entry in the map.
+        let prefixes = self
+            .interaction_token_prefixes
+            .iter()
+            .filter(|entry| token.starts_with(entry.key().as_str()))
+            .map(|entry| entry.key().clone())
+            .collect::<Vec<_>>();
+
+        for prefix in prefixes {
+            results.add_with(&Self::process_specific_event(
+                &self.interaction_token_prefixes,
+                prefix,
+                interaction,
+            ));
+        }
+
+        results
     }
 
     /// Process a general event that is not of any particular type or in any
@@ -1209,16 +2081,17 @@ impl ProcessStatus {
 mod tests {
     #![allow(clippy::non_ascii_literal)]
 
-    use super::Standby;
+    use super::{filter, future::WaitErrorType, InteractionKey, Standby};
     use futures_util::StreamExt;
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{fmt::Debug, time::Duration};
     use twilight_model::{
         application::{
             component::ComponentType,
             interaction::{
-                message_component::MessageComponentInteractionData, Interaction, InteractionType,
-                MessageComponentInteraction,
+                application_command::CommandData,
+                message_component::MessageComponentInteractionData, ApplicationCommand,
+                Interaction, InteractionType, MessageComponentInteraction,
             },
         },
         channel::{
@@ -1230,14 +2103,16 @@ mod tests {
             event::{Event, EventType},
             payload::incoming::{InteractionCreate, MessageCreate, ReactionAdd, Ready, RoleDelete},
         },
-        id::{ApplicationId, ChannelId, GuildId, InteractionId, MessageId, RoleId, UserId},
+        id::{
+            ApplicationId, ChannelId, CommandId, GuildId, InteractionId, MessageId, RoleId, UserId,
+        },
         oauth::{current_application_info::ApplicationFlags, PartialApplication},
         user::{CurrentUser, User},
     };
 
     assert_impl_all!(Standby: Debug, Default, Send, Sync);
 
-    fn message() -> Message {
+    pub(crate) fn message() -> Message {
         Message {
             id: MessageId::new(3).expect("non zero"),
             activity: None,
@@ -1300,6 +2175,25 @@ mod tests {
         }
     }
 
+    fn command() -> ApplicationCommand {
+        ApplicationCommand {
+            application_id: ApplicationId::new(1).expect("non zero"),
+            channel_id: ChannelId::new(2).expect("non zero"),
+            data: CommandData {
+                id: CommandId::new(5).expect("non zero"),
+                name: "ping".to_owned(),
+                options: Vec::new(),
+                resolved: None,
+            },
+            guild_id: Some(GuildId::new(3).expect("non zero")),
+            id: InteractionId::new(4).expect("non zero"),
+            kind: InteractionType::ApplicationCommand,
+            member: None,
+            token: String::from("command token"),
+            user: button().user,
+        }
+    }
+
     fn button() -> MessageComponentInteraction {
         MessageComponentInteraction {
             application_id: ApplicationId::new(1).expect("non zero"),
@@ -1342,9 +2236,8 @@ mod tests {
         let standby = Standby::new();
         let guild_id = GuildId::new(1).expect("non zero");
 
-        {
-            let _rx = standby.wait_for(guild_id, move |_: &Event| false);
-        }
+        // Dropping a future removes its bystander, so only drop the receiver.
+        let (_, _remover) = standby.insert_future(&standby.guilds, guild_id, |_: &Event| false);
 
         let results = standby.process(&Event::RoleDelete(RoleDelete {
             guild_id,
@@ -1559,8 +2452,125 @@ mod tests {
         assert!(stream.next().await.is_some());
         assert!(stream.next().await.is_some());
         drop(stream);
+        assert!(standby.messages.is_empty());
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+        assert!(standby.messages.is_empty());
+    }
+
+    /// Test that a timed out bystander is removed without processing another
+    /// event.
+    #[tokio::test]
+    async fn test_timeout() {
+        let standby = Standby::new();
+        let channel_id = ChannelId::new(1).expect("non zero");
+        let wait = standby
+            .wait_for_message(channel_id, |_: &MessageCreate| false)
+            .timeout(Duration::from_millis(10));
         assert_eq!(1, standby.messages.len());
+
+        let error = wait.await.unwrap_err();
+        assert!(matches!(error.kind(), WaitErrorType::TimedOut));
+        assert!(standby.messages.is_empty());
+
+        let mut stream = standby
+            .wait_for_event_stream(|_: &Event| true)
+            .timeout(Duration::from_millis(10));
+        standby.process(&Event::Resumed);
+        assert_eq!(Some(Event::Resumed), stream.next().await);
+        assert!(stream.next().await.is_none());
+        assert!(standby.events.is_empty());
+    }
+
+    /// Test that a [`Standby::wait_for_message_any`] call is fulfilled by a
+    /// message in any of its channels and removes its remaining bystanders.
+    #[tokio::test]
+    async fn test_wait_for_message_any() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_message_any(
+            [
+                ChannelId::new(1).expect("non zero"),
+                ChannelId::new(5).expect("non zero"),
+            ],
+            |message: &MessageCreate| message.author.id.get() == 2,
+        );
+        assert_eq!(2, standby.messages.len());
+
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+
+        assert_eq!(3, wait.await.map(|msg| msg.id.get()).unwrap());
+        assert!(standby.messages.is_empty());
+    }
+
+    /// Test that [`Standby::wait_for_message_async`] skips messages until its
+    /// asynchronous predicate matches.
+    #[tokio::test]
+    async fn test_wait_for_message_async() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_message_async(
+            ChannelId::new(1).expect("non zero"),
+            |_: &MessageCreate| true,
+            |message: &MessageCreate| {
+                let content = message.content.clone();
+
+                async move {
+                    tokio::task::yield_now().await;
+
+                    content == "second"
+                }
+            },
+        );
+
+        let mut second = message();
+        second.content = "second".to_owned();
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(second))));
+
+        assert_eq!("second", wait.await.unwrap().content);
+        assert!(standby.messages.is_empty());
+    }
+
+    /// Test that the synchronous filter of an asynchronous wait is applied
+    /// before events are queued for the asynchronous predicate.
+    #[tokio::test]
+    async fn test_wait_for_message_async_filter() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_message_async(
+            ChannelId::new(1).expect("non zero"),
+            filter::content_prefix("!"),
+            |_: &MessageCreate| async { true },
+        );
+
+        let mut command = message();
+        command.content = "!command".to_owned();
         standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(command))));
+
+        assert_eq!("!command", wait.await.unwrap().content);
+        assert!(standby.messages.is_empty());
+    }
+
+    /// Test that dropping a pending future or stream removes its bystander.
+    #[test]
+    fn test_drop_removes_bystander() {
+        let standby = Standby::new();
+        let channel_id = ChannelId::new(1).expect("non zero");
+
+        let wait = standby.wait_for_message(channel_id, |_: &MessageCreate| false);
+        assert_eq!(1, standby.messages.len());
+        drop(wait);
+        assert!(standby.messages.is_empty());
+
+        let stream = standby.wait_for_event_stream(|_: &Event| true);
+        assert_eq!(1, standby.events.len());
+        drop(stream);
+        assert!(standby.events.is_empty());
+
+        let wait = standby.wait_for_message_async(
+            channel_id,
+            |_: &MessageCreate| true,
+            |_: &MessageCreate| async { false },
+        );
+        drop(wait);
         assert!(standby.messages.is_empty());
     }
 
@@ -1597,7 +2607,7 @@ mod tests {
         assert!(stream.next().await.is_some());
         assert!(stream.next().await.is_some());
         drop(stream);
-        assert_eq!(1, standby.reactions.len());
+        assert!(standby.reactions.is_empty());
         standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
         assert!(standby.reactions.is_empty());
     }
@@ -1627,6 +2637,91 @@ mod tests {
         assert!(standby.components.is_empty());
     }
 
+    /// Test that autocomplete bystanders are matched by command and user.
+    #[tokio::test]
+    async fn test_wait_for_autocomplete() {
+        let standby = Standby::new();
+        let command_id = CommandId::new(5).expect("non zero");
+        let wait = standby.wait_for_autocomplete(
+            command_id,
+            UserId::new(2).expect("non zero"),
+            |_: &ApplicationCommand| true,
+        );
+        let _other_user = standby.wait_for_autocomplete(
+            command_id,
+            UserId::new(3).expect("non zero"),
+            |_: &ApplicationCommand| true,
+        );
+
+        let event = Event::InteractionCreate(Box::new(InteractionCreate(
+            Interaction::ApplicationCommandAutocomplete(Box::new(command())),
+        )));
+        let results = standby.process(&event);
+
+        assert_eq!(1, results.fulfilled());
+        assert_eq!(command_id, wait.await.unwrap().data.id);
+        assert_eq!(1, standby.autocompletes.len());
+    }
+
+    /// Test that a follow-up command by a user fulfills a
+    /// [`Standby::wait_for_command`] call.
+    #[tokio::test]
+    async fn test_wait_for_command() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_command(
+            UserId::new(2).expect("non zero"),
+            |command: &ApplicationCommand| command.data.name == "ping",
+        );
+
+        standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
+            Interaction::ApplicationCommand(Box::new(command())),
+        ))));
+
+        assert_eq!("ping", wait.await.unwrap().data.name);
+        assert!(standby.commands.is_empty());
+    }
+
+    /// Test that interaction bystanders are matched by custom ID and by token
+    /// prefix.
+    #[tokio::test]
+    async fn test_wait_for_interaction() {
+        let standby = Standby::new();
+        let by_custom_id = standby.wait_for_interaction(
+            InteractionKey::CustomId("Click".to_owned()),
+            |_: &Interaction| true,
+        );
+        let by_token = standby.wait_for_interaction(
+            InteractionKey::TokenPrefix("tok".to_owned()),
+            |_: &Interaction| true,
+        );
+        let component = standby.wait_for_component(
+            MessageId::new(3).expect("non zero"),
+            |_: &MessageComponentInteraction| true,
+        );
+        let _other_token = standby.wait_for_interaction(
+            InteractionKey::TokenPrefix("other".to_owned()),
+            |_: &Interaction| true,
+        );
+
+        let results = standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
+            Interaction::MessageComponent(Box::new(button())),
+        ))));
+
+        assert_eq!(3, results.fulfilled());
+        assert!(matches!(
+            by_custom_id.await.unwrap(),
+            Interaction::MessageComponent(_)
+        ));
+        assert!(matches!(
+            by_token.await.unwrap(),
+            Interaction::MessageComponent(_)
+        ));
+        assert_eq!("Click", component.await.unwrap().data.custom_id);
+        assert!(standby.components.is_empty());
+        assert!(standby.interaction_custom_ids.is_empty());
+        assert_eq!(1, standby.interaction_token_prefixes.len());
+    }
+
     #[tokio::test]
     async fn test_wait_for_component_stream() {
         let standby = Standby::new();
@@ -1644,7 +2739,7 @@ mod tests {
         assert!(stream.next().await.is_some());
         assert!(stream.next().await.is_some());
         drop(stream);
-        assert_eq!(1, standby.components.len());
+        assert!(standby.components.is_empty());
         standby.process(&Event::InteractionCreate(Box::new(InteractionCreate(
             Interaction::MessageComponent(Box::new(button())),
         ))));
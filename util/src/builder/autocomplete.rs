@@ -0,0 +1,355 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::application::{
+    callback::{Autocomplete, InteractionResponse},
+    command::{CommandOptionChoice, Number},
+};
+
+/// Error when [building] an [`Autocomplete`] response.
+///
+/// [building]: AutocompleteBuilder::build
+#[derive(Debug)]
+pub struct AutocompleteError {
+    kind: AutocompleteErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl AutocompleteError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &AutocompleteErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (AutocompleteErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for AutocompleteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            AutocompleteErrorType::ChoiceCountInvalid { count } => {
+                f.write_str("autocomplete has ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" choices, but at most ")?;
+                Display::fmt(&AutocompleteBuilder::CHOICES_MAX, f)?;
+
+                f.write_str(" are allowed")
+            }
+            AutocompleteErrorType::ChoiceNameInvalid { index } => {
+                f.write_str("name of choice ")?;
+                Display::fmt(index, f)?;
+                f.write_str(" must be between 1 and ")?;
+                Display::fmt(&AutocompleteBuilder::CHOICE_LENGTH_MAX, f)?;
+
+                f.write_str(" characters")
+            }
+            AutocompleteErrorType::ChoiceValueInvalid { index } => {
+                f.write_str("value of choice ")?;
+                Display::fmt(index, f)?;
+                f.write_str(" must be at most ")?;
+                Display::fmt(&AutocompleteBuilder::CHOICE_LENGTH_MAX, f)?;
+
+                f.write_str(" characters")
+            }
+        }
+    }
+}
+
+impl Error for AutocompleteError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`AutocompleteError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AutocompleteErrorType {
+    /// More than [`AutocompleteBuilder::CHOICES_MAX`] choices were provided.
+    ChoiceCountInvalid {
+        /// Number of provided choices.
+        count: usize,
+    },
+    /// Name of a choice is empty or longer than
+    /// [`AutocompleteBuilder::CHOICE_LENGTH_MAX`] characters.
+    ChoiceNameInvalid {
+        /// Index of the invalid choice.
+        index: usize,
+    },
+    /// String value of a choice is longer than
+    /// [`AutocompleteBuilder::CHOICE_LENGTH_MAX`] characters.
+    ChoiceValueInvalid {
+        /// Index of the invalid choice.
+        index: usize,
+    },
+}
+
+/// Create an [`Autocomplete`] response with a builder.
+///
+/// # Examples
+///
+/// Suggest two colors, the response being sent with an
+/// [`InteractionResponse::Autocomplete`]:
+///
+/// ```
+/// use twilight_util::builder::AutocompleteBuilder;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let response = AutocompleteBuilder::new()
+///     .string("Red".to_owned(), "red".to_owned())
+///     .string("Green".to_owned(), "green".to_owned())
+///     .build_response()?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "builders have no effect if unused"]
+pub struct AutocompleteBuilder(Autocomplete);
+
+impl AutocompleteBuilder {
+    /// Maximum number of characters in the name and string value of a choice.
+    pub const CHOICE_LENGTH_MAX: usize = 100;
+
+    /// Maximum number of choices in a response.
+    pub const CHOICES_MAX: usize = 25;
+
+    /// Create a new builder to construct an [`Autocomplete`].
+    pub const fn new() -> Self {
+        Self(Autocomplete {
+            choices: Vec::new(),
+        })
+    }
+
+    /// Consume the builder, returning an [`Autocomplete`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AutocompleteErrorType::ChoiceCountInvalid`] error type if
+    /// there are more than [`CHOICES_MAX`] choices.
+    ///
+    /// Returns an [`AutocompleteErrorType::ChoiceNameInvalid`] error type if
+    /// the name of a choice is empty or longer than [`CHOICE_LENGTH_MAX`]
+    /// characters.
+    ///
+    /// Returns an [`AutocompleteErrorType::ChoiceValueInvalid`] error type if
+    /// the string value of a choice is longer than [`CHOICE_LENGTH_MAX`]
+    /// characters.
+    ///
+    /// [`CHOICE_LENGTH_MAX`]: Self::CHOICE_LENGTH_MAX
+    /// [`CHOICES_MAX`]: Self::CHOICES_MAX
+    #[must_use = "builders have no effect if unused"]
+    pub fn build(self) -> Result<Autocomplete, AutocompleteError> {
+        let count = self.0.choices.len();
+
+        if count > Self::CHOICES_MAX {
+            return Err(AutocompleteError {
+                kind: AutocompleteErrorType::ChoiceCountInvalid { count },
+                source: None,
+            });
+        }
+
+        for (index, choice) in self.0.choices.iter().enumerate() {
+            let (name, value) = match choice {
+                CommandOptionChoice::String { name, value } => (name, Some(value)),
+                CommandOptionChoice::Int { name, .. }
+                | CommandOptionChoice::Number { name, .. } => (name, None),
+            };
+
+            if !(1..=Self::CHOICE_LENGTH_MAX).contains(&name.chars().count()) {
+                return Err(AutocompleteError {
+                    kind: AutocompleteErrorType::ChoiceNameInvalid { index },
+                    source: None,
+                });
+            }
+
+            if value.map_or(false, |value| {
+                value.chars().count() > Self::CHOICE_LENGTH_MAX
+            }) {
+                return Err(AutocompleteError {
+                    kind: AutocompleteErrorType::ChoiceValueInvalid { index },
+                    source: None,
+                });
+            }
+        }
+
+        Ok(self.0)
+    }
+
+    /// Consume the builder, returning an [`InteractionResponse::Autocomplete`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`build`].
+    ///
+    /// [`build`]: Self::build
+    #[must_use = "builders have no effect if unused"]
+    pub fn build_response(self) -> Result<InteractionResponse, AutocompleteError> {
+        self.build().map(InteractionResponse::Autocomplete)
+    }
+
+    /// Add a choice to the response.
+    ///
+    /// Defaults to an empty list.
+    pub fn choice(mut self, choice: CommandOptionChoice) -> Self {
+        self.0.choices.push(choice);
+
+        self
+    }
+
+    /// Add multiple choices to the response.
+    ///
+    /// Defaults to an empty list.
+    pub fn choices(mut self, choices: impl IntoIterator<Item = CommandOptionChoice>) -> Self {
+        self.0.choices.extend(choices);
+
+        self
+    }
+
+    /// Add an integer choice to the response.
+    pub fn integer(self, name: String, value: i64) -> Self {
+        self.choice(CommandOptionChoice::Int { name, value })
+    }
+
+    /// Add a number choice to the response.
+    pub fn number(self, name: String, value: f64) -> Self {
+        self.choice(CommandOptionChoice::Number {
+            name,
+            value: Number(value),
+        })
+    }
+
+    /// Add a string choice to the response.
+    pub fn string(self, name: String, value: String) -> Self {
+        self.choice(CommandOptionChoice::String { name, value })
+    }
+}
+
+impl Default for AutocompleteBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AutocompleteBuilder, AutocompleteError, AutocompleteErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::application::{
+        callback::{Autocomplete, InteractionResponse},
+        command::{CommandOptionChoice, Number},
+    };
+
+    assert_impl_all!(AutocompleteBuilder: Clone, Debug, Default, Send, Sync);
+    assert_impl_all!(AutocompleteError: Debug, Error, Send, Sync);
+    assert_impl_all!(AutocompleteErrorType: Debug, Send, Sync);
+
+    #[test]
+    fn test_builder() {
+        let choice = CommandOptionChoice::Int {
+            name: "one".to_owned(),
+            value: 1,
+        };
+
+        let value = AutocompleteBuilder::new()
+            .choice(choice.clone())
+            .integer("two".to_owned(), 2)
+            .number("half".to_owned(), 0.5)
+            .string("three".to_owned(), "3".to_owned())
+            .build_response()
+            .unwrap();
+
+        let expected = InteractionResponse::Autocomplete(Autocomplete {
+            choices: vec![
+                choice,
+                CommandOptionChoice::Int {
+                    name: "two".to_owned(),
+                    value: 2,
+                },
+                CommandOptionChoice::Number {
+                    name: "half".to_owned(),
+                    value: Number(0.5),
+                },
+                CommandOptionChoice::String {
+                    name: "three".to_owned(),
+                    value: "3".to_owned(),
+                },
+            ],
+        });
+
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn test_choice_count() {
+        let choices = (0..=25)
+            .map(|value| CommandOptionChoice::Int {
+                name: value.to_string(),
+                value,
+            })
+            .collect::<Vec<_>>();
+
+        assert!(AutocompleteBuilder::new()
+            .choices(choices.iter().skip(1).cloned())
+            .build()
+            .is_ok());
+        assert!(matches!(
+            AutocompleteBuilder::new()
+                .choices(choices)
+                .build()
+                .unwrap_err()
+                .kind(),
+            AutocompleteErrorType::ChoiceCountInvalid { count: 26 }
+        ));
+    }
+
+    #[test]
+    fn test_choice_length() {
+        let max = "a".repeat(AutocompleteBuilder::CHOICE_LENGTH_MAX);
+        let long = "a".repeat(AutocompleteBuilder::CHOICE_LENGTH_MAX + 1);
+
+        assert!(AutocompleteBuilder::new()
+            .string(max.clone(), max.clone())
+            .string("empty value".to_owned(), String::new())
+            .build()
+            .is_ok());
+        assert!(matches!(
+            AutocompleteBuilder::new()
+                .integer("valid".to_owned(), 1)
+                .integer(String::new(), 2)
+                .build()
+                .unwrap_err()
+                .kind(),
+            AutocompleteErrorType::ChoiceNameInvalid { index: 1 }
+        ));
+        assert!(matches!(
+            AutocompleteBuilder::new()
+                .number(long.clone(), 1.0)
+                .build()
+                .unwrap_err()
+                .kind(),
+            AutocompleteErrorType::ChoiceNameInvalid { index: 0 }
+        ));
+        assert!(matches!(
+            AutocompleteBuilder::new()
+                .string(max, long)
+                .build()
+                .unwrap_err()
+                .kind(),
+            AutocompleteErrorType::ChoiceValueInvalid { index: 0 }
+        ));
+    }
+}
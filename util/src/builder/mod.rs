@@ -1,7 +1,11 @@
 //! Builders for large structs.
 #![allow(clippy::module_name_repetitions)]
 
+mod autocomplete;
 mod callback_data;
 pub mod command;
 
-pub use self::callback_data::CallbackDataBuilder;
+pub use self::{
+    autocomplete::{AutocompleteBuilder, AutocompleteError, AutocompleteErrorType},
+    callback_data::CallbackDataBuilder,
+};
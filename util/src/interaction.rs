@@ -0,0 +1,196 @@
+//! Utilities for reading the data of interactions.
+//!
+//! # Examples
+//!
+//! Respond to an autocomplete interaction with the colors starting with what
+//! the user typed so far:
+//!
+//! ```
+//! use twilight_model::application::{
+//!     callback::InteractionResponse,
+//!     interaction::ApplicationCommand,
+//! };
+//! use twilight_util::{builder::AutocompleteBuilder, interaction};
+//!
+//! const COLORS: &[&str] = &["blue", "green", "red"];
+//!
+//! fn autocomplete(command: &ApplicationCommand) -> Option<InteractionResponse> {
+//!     let focused = interaction::focused_option(&command.data.options)?;
+//!     let partial = focused.as_str()?;
+//!
+//!     let colors = COLORS.iter().filter(|color| color.starts_with(partial));
+//!
+//!     colors
+//!         .fold(AutocompleteBuilder::new(), |builder, color| {
+//!             builder.string(color.to_string(), color.to_string())
+//!         })
+//!         .build_response()
+//!         .ok()
+//! }
+//! ```
+
+use twilight_model::application::{
+    command::CommandOptionType,
+    interaction::application_command::{CommandDataOption, CommandOptionValue},
+};
+
+/// Option of an autocomplete interaction that is currently focused.
+///
+/// Use [`focused_option`] to find the focused option.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FocusedOption<'a> {
+    /// Name of the option.
+    pub name: &'a str,
+    /// Names of the subcommand group and subcommand the option belongs to,
+    /// outermost first.
+    ///
+    /// Empty if the option belongs to the command itself.
+    pub path: Vec<&'a str>,
+    /// Partial value the user has entered so far.
+    pub value: &'a CommandOptionValue,
+}
+
+impl<'a> FocusedOption<'a> {
+    /// Type of the option.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> CommandOptionType {
+        self.value.kind()
+    }
+
+    /// Partial value of the option if it's a string option.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "retrieving the value has no effect if left unused"]
+    pub fn as_str(&self) -> Option<&'a str> {
+        match self.value {
+            CommandOptionValue::String(value) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Partial value of the option if it's an integer option.
+    #[must_use = "retrieving the value has no effect if left unused"]
+    pub const fn as_integer(&self) -> Option<i64> {
+        match self.value {
+            CommandOptionValue::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Partial value of the option if it's a number option.
+    #[must_use = "retrieving the value has no effect if left unused"]
+    pub const fn as_number(&self) -> Option<f64> {
+        match self.value {
+            CommandOptionValue::Number(value) => Some(value.0),
+            _ => None,
+        }
+    }
+}
+
+/// Find the focused option of an autocomplete interaction.
+///
+/// The options of subcommand groups and subcommands are searched, with the
+/// names of the subcommands leading to the option being recorded in
+/// [`FocusedOption::path`].
+///
+/// Returns [`None`] if none of the options are focused, such as when the
+/// options are of a regular command interaction.
+#[must_use = "finding the focused option has no effect if left unused"]
+pub fn focused_option(options: &[CommandDataOption]) -> Option<FocusedOption<'_>> {
+    let mut path = Vec::new();
+    let mut options = options;
+
+    loop {
+        if let Some(option) = options.iter().find(|option| option.focused) {
+            return Some(FocusedOption {
+                name: &option.name,
+                path,
+                value: &option.value,
+            });
+        }
+
+        let (name, inner) = options.iter().find_map(|option| match &option.value {
+            CommandOptionValue::SubCommand(inner) | CommandOptionValue::SubCommandGroup(inner) => {
+                Some((option.name.as_str(), inner))
+            }
+            _ => None,
+        })?;
+
+        path.push(name);
+        options = inner;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{focused_option, FocusedOption};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::application::{
+        command::{CommandOptionType, Number},
+        interaction::application_command::{CommandDataOption, CommandOptionValue},
+    };
+
+    assert_impl_all!(FocusedOption<'_>: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    fn option(name: &str, focused: bool, value: CommandOptionValue) -> CommandDataOption {
+        CommandDataOption {
+            focused,
+            name: name.to_owned(),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_top_level() {
+        let options = [
+            option("count", false, CommandOptionValue::Integer(3)),
+            option("color", true, CommandOptionValue::String("re".to_owned())),
+        ];
+
+        let focused = focused_option(&options).unwrap();
+        assert_eq!("color", focused.name);
+        assert!(focused.path.is_empty());
+        assert_eq!(CommandOptionType::String, focused.kind());
+        assert_eq!(Some("re"), focused.as_str());
+        assert!(focused.as_integer().is_none());
+    }
+
+    #[test]
+    fn test_nested() {
+        let options = [option(
+            "config",
+            false,
+            CommandOptionValue::SubCommandGroup(vec![option(
+                "scale",
+                false,
+                CommandOptionValue::SubCommand(vec![
+                    option("name", false, CommandOptionValue::String("x".to_owned())),
+                    option("factor", true, CommandOptionValue::Number(Number(1.5))),
+                ]),
+            )]),
+        )];
+
+        let focused = focused_option(&options).unwrap();
+        assert_eq!("factor", focused.name);
+        assert_eq!(vec!["config", "scale"], focused.path);
+        assert_eq!(CommandOptionType::Number, focused.kind());
+        assert_eq!(Some(1.5), focused.as_number());
+        assert!(focused.as_str().is_none());
+    }
+
+    #[test]
+    fn test_none_focused() {
+        let options = [option(
+            "add",
+            false,
+            CommandOptionValue::SubCommand(vec![option(
+                "count",
+                false,
+                CommandOptionValue::Integer(1),
+            )]),
+        )];
+
+        assert!(focused_option(&options).is_none());
+        assert!(focused_option(&[]).is_none());
+    }
+}
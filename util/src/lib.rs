@@ -11,6 +11,11 @@
 //!
 //! Provides builders for large structs.
 //!
+//! ### `interaction`
+//!
+//! Provides helpers for reading the data of interactions, such as finding the
+//! focused option of an autocomplete interaction.
+//!
 //! ### `link`
 //!
 //! Provides implementations for parsing and formatting entities' URLs, such as
@@ -57,6 +62,10 @@
 #[cfg_attr(docsrs, doc(cfg(feature = "builder")))]
 pub mod builder;
 
+#[cfg(feature = "interaction")]
+#[cfg_attr(docsrs, doc(cfg(feature = "interaction")))]
+pub mod interaction;
+
 #[cfg(feature = "link")]
 #[cfg_attr(docsrs, doc(cfg(feature = "link")))]
 pub mod link;